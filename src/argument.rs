@@ -15,7 +15,7 @@ ast_types! {
             attributes: Option<ExtendedAttributeList<'a>>,
             optional: Option<term!(optional)>,
             type_: AttributedType<'a>,
-            identifier: Identifier<'a>,
+            identifier: ArgumentName<'a>,
             default: Option<Default<'a>> = map!(
                 cond!(optional.is_some(), weedle!(Option<Default<'a>>)),
                 |default| default.unwrap_or(None)
@@ -26,9 +26,88 @@ ast_types! {
             attributes: Option<ExtendedAttributeList<'a>>,
             type_: Type<'a>,
             ellipsis: term!(...),
-            identifier: Identifier<'a>,
+            identifier: ArgumentName<'a>,
         }),
     }
+
+    /// Parses the name of an argument, which may be one of some keywords
+    enum ArgumentName<'a> {
+        Keyword(ArgumentNameKeyword),
+        Identifier(Identifier<'a>),
+    }
+
+    /// Parses a keyword an argument may be named after. Ex: `interface|required`
+    #[derive(Copy)]
+    enum ArgumentNameKeyword {
+        Async(term!(async)),
+        Attribute(term!(attribute)),
+        Callback(term!(callback)),
+        Const(term!(const)),
+        Constructor(term!(constructor)),
+        Deleter(term!(deleter)),
+        Dictionary(term!(dictionary)),
+        Enum(term!(enum)),
+        Getter(term!(getter)),
+        Includes(term!(includes)),
+        Inherit(term!(inherit)),
+        Interface(term!(interface)),
+        Iterable(term!(iterable)),
+        Maplike(term!(maplike)),
+        Mixin(term!(mixin)),
+        Namespace(term!(namespace)),
+        Partial(term!(partial)),
+        ReadOnly(term!(readonly)),
+        Required(term!(required)),
+        Setlike(term!(setlike)),
+        Setter(term!(setter)),
+        Static(term!(static)),
+        Stringifier(term!(stringifier)),
+        Typedef(term!(typedef)),
+        Unrestricted(term!(unrestricted)),
+    }
+}
+
+impl<'a> ArgumentName<'a> {
+    /// Returns the name of the argument, without any leading `_`
+    pub fn name(&self) -> &'a str {
+        match self {
+            ArgumentName::Keyword(keyword) => keyword.name(),
+            ArgumentName::Identifier(identifier) => identifier.0,
+        }
+    }
+}
+
+impl ArgumentNameKeyword {
+    /// Returns the keyword naming the argument
+    pub fn name(&self) -> &'static str {
+        match self {
+            ArgumentNameKeyword::Async(_) => "async",
+            ArgumentNameKeyword::Attribute(_) => "attribute",
+            ArgumentNameKeyword::Callback(_) => "callback",
+            ArgumentNameKeyword::Const(_) => "const",
+            ArgumentNameKeyword::Constructor(_) => "constructor",
+            ArgumentNameKeyword::Deleter(_) => "deleter",
+            ArgumentNameKeyword::Dictionary(_) => "dictionary",
+            ArgumentNameKeyword::Enum(_) => "enum",
+            ArgumentNameKeyword::Getter(_) => "getter",
+            ArgumentNameKeyword::Includes(_) => "includes",
+            ArgumentNameKeyword::Inherit(_) => "inherit",
+            ArgumentNameKeyword::Interface(_) => "interface",
+            ArgumentNameKeyword::Iterable(_) => "iterable",
+            ArgumentNameKeyword::Maplike(_) => "maplike",
+            ArgumentNameKeyword::Mixin(_) => "mixin",
+            ArgumentNameKeyword::Namespace(_) => "namespace",
+            ArgumentNameKeyword::Partial(_) => "partial",
+            ArgumentNameKeyword::ReadOnly(_) => "readonly",
+            ArgumentNameKeyword::Required(_) => "required",
+            ArgumentNameKeyword::Setlike(_) => "setlike",
+            ArgumentNameKeyword::Setter(_) => "setter",
+            ArgumentNameKeyword::Static(_) => "static",
+            ArgumentNameKeyword::Stringifier(_) => "stringifier",
+            ArgumentNameKeyword::Typedef(_) => "typedef",
+            ArgumentNameKeyword::Unrestricted(_) => "unrestricted",
+        }
+    }
 }
 
 #[cfg(test)]
@@ -42,7 +121,7 @@ mod test {
         SingleArgument;
         attributes.is_none();
         optional.is_none();
-        identifier.0 == "a";
+        identifier.name() == "a";
         default.is_none();
     });
 
@@ -50,15 +129,51 @@ mod test {
         "";
         VariadicArgument;
         attributes.is_none();
-        identifier.0 == "a";
+        identifier.name() == "a";
+    });
+
+    test!(should_parse_keyword_argument_name { "optional DOMString interface" =>
+        "";
+        SingleArgument;
+        identifier == ArgumentName::Keyword(ArgumentNameKeyword::Interface(term!(interface)));
+    });
+
+    test!(should_parse_keyword_variadic_argument_name { "any... required" =>
+        "";
+        VariadicArgument;
+        identifier == ArgumentName::Keyword(ArgumentNameKeyword::Required(term!(required)));
     });
 
+    test!(should_parse_escaped_keyword_argument_name { "long _async" =>
+        "";
+        SingleArgument;
+        identifier == ArgumentName::Identifier(Identifier::new("async"));
+    });
+
+    #[test]
+    fn should_parse_only_argument_name_keywords_as_keywords() {
+        for (argument, keyword) in &[
+            ("long a", false),
+            ("long asyncCallback", false),
+            ("long sequence", false),
+            ("long Promise", false),
+            ("long async", true),
+            ("long unrestricted", true),
+        ] {
+            let (_, parsed) = SingleArgument::parse(argument).unwrap();
+            match parsed.identifier {
+                ArgumentName::Keyword(_) => assert!(*keyword, "{}", argument),
+                ArgumentName::Identifier(_) => assert!(!*keyword, "{}", argument),
+            }
+        }
+    }
+
     test!(should_parse_optional_single_argument { "optional short a" =>
         "";
         SingleArgument;
         attributes.is_none();
         optional.is_some();
-        identifier.0 == "a";
+        identifier.name() == "a";
         default.is_none();
     });
 
@@ -67,10 +182,10 @@ mod test {
         SingleArgument;
         attributes.is_none();
         optional.is_some();
-        identifier.0 == "a";
+        identifier.name() == "a";
         default == Some(Default {
             assign: term!(=),
-            value: DefaultValue::Integer(IntegerLit::Dec(DecLit("5"))),
+            value: DefaultValue::Integer(IntegerLit::Dec(DecLit::new("5"))),
         });
     });
 }
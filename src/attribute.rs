@@ -72,7 +72,7 @@ mod test {
 
     test!(should_parse_attribute_no_args { "Replaceable" =>
         "";
-        ExtendedAttributeNoArgs => ExtendedAttributeNoArgs(Identifier("Replaceable"))
+        ExtendedAttributeNoArgs => ExtendedAttributeNoArgs(Identifier::new("Replaceable"))
     });
 
     test!(should_parse_attribute_arg_list { "Constructor(double x, double y)" =>
@@ -86,7 +86,7 @@ mod test {
         "";
         ExtendedAttributeIdent;
         lhs_identifier.0 == "PutForwards";
-        rhs == IdentifierOrString::Identifier(Identifier("name"));
+        rhs == IdentifierOrString::Identifier(Identifier::new("name"));
     });
 
     test!(should_parse_ident_list { "Exposed=(Window,Worker)" =>
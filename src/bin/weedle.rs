@@ -0,0 +1,149 @@
+//! Command line tools for WebIDL files
+//!
+//! ```text
+//! weedle fmt [options] [files...]
+//! ```
+//!
+//! Formats the given files in place, or the standard input to the standard
+//! output when no file is given.
+
+use std::fs;
+use std::io::{self, Read, Write};
+use std::process;
+
+use weedle::print::{self, Options};
+
+const USAGE: &str = "\
+Usage: weedle fmt [options] [files...]
+
+Formats WebIDL files in place, or the standard input to the standard output
+when no file is given.
+
+Options:
+    --check            Only check the files are formatted, exiting with 1 if
+                       any is not
+    --indent <n>       Indent by <n> spaces, 4 by default
+    --tabs             Indent with tabs
+    --width <n>        Put extended attributes one per line when their list
+                       would go past <n> columns, 100 by default, 0 to never
+    --strip-comments   Remove comments
+    -h, --help         Print this message
+";
+
+struct Args {
+    check: bool,
+    options: Options,
+    files: Vec<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut args = args.iter();
+    match args.next().map(String::as_str) {
+        Some("fmt") => {}
+        Some("-h") | Some("--help") => {
+            print!("{}", USAGE);
+            process::exit(0);
+        }
+        Some(command) => return Err(format!("unknown command `{}`", command)),
+        None => return Err("missing command".to_string()),
+    }
+
+    let mut parsed = Args {
+        check: false,
+        options: Options {
+            wrap_width: Some(100),
+            ..Options::default()
+        },
+        files: Vec::new(),
+    };
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--check" => parsed.check = true,
+            "--indent" => parsed.options.indent = " ".repeat(number(arg, args.next())?),
+            "--tabs" => parsed.options.indent = "\t".to_string(),
+            "--width" => {
+                parsed.options.wrap_width = match number(arg, args.next())? {
+                    0 => None,
+                    width => Some(width),
+                }
+            }
+            "--strip-comments" => parsed.options.comments = false,
+            "-h" | "--help" => {
+                print!("{}", USAGE);
+                process::exit(0);
+            }
+            _ if arg.starts_with('-') => return Err(format!("unknown option `{}`", arg)),
+            _ => parsed.files.push(arg.clone()),
+        }
+    }
+    Ok(parsed)
+}
+
+fn number(option: &str, value: Option<&String>) -> Result<usize, String> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| format!("`{}` expects a number", option))
+}
+
+/// Formats `source`, reporting any parse error against `name`
+fn format(name: &str, source: &str, options: &Options) -> Option<String> {
+    match print::format(source, options) {
+        Ok(formatted) => Some(formatted),
+        Err(err) => {
+            eprintln!("{}: {}", name, err);
+            None
+        }
+    }
+}
+
+fn run(args: Args) -> io::Result<i32> {
+    let mut code = 0;
+
+    if args.files.is_empty() {
+        let mut source = String::new();
+        io::stdin().read_to_string(&mut source)?;
+        match format("<stdin>", &source, &args.options) {
+            Some(formatted) if args.check => {
+                if formatted != source {
+                    eprintln!("<stdin> is not formatted");
+                    code = 1;
+                }
+            }
+            Some(formatted) => io::stdout().write_all(formatted.as_bytes())?,
+            None => code = 2,
+        }
+        return Ok(code);
+    }
+
+    for file in &args.files {
+        let source = fs::read_to_string(file)?;
+        match format(file, &source, &args.options) {
+            Some(formatted) if formatted == source => {}
+            Some(_) if args.check => {
+                eprintln!("{} is not formatted", file);
+                code = code.max(1);
+            }
+            Some(formatted) => fs::write(file, formatted)?,
+            None => code = 2,
+        }
+    }
+    Ok(code)
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let args = match parse_args(&args) {
+        Ok(args) => args,
+        Err(err) => {
+            eprintln!("error: {}\n\n{}", err, USAGE);
+            process::exit(2);
+        }
+    };
+    match run(args) {
+        Ok(code) => process::exit(code),
+        Err(err) => {
+            eprintln!("error: {}", err);
+            process::exit(2);
+        }
+    }
+}
@@ -1,5 +1,11 @@
+use std::borrow::Cow;
+use std::fmt;
+
 use crate::literal::DefaultValue;
+use crate::print::ToWebIdl;
+use crate::span::{Span, Spanned};
 use crate::term;
+use crate::token::{Token, Tokens};
 use crate::Parse;
 
 impl<'a, T: Parse<'a>> Parse<'a> for Option<T> {
@@ -25,6 +31,185 @@ impl<'a, T: Parse<'a>, U: Parse<'a>, V: Parse<'a>> Parse<'a> for (T, U, V) {
     ));
 }
 
+/// Parses `(item1, item2, item3,...)?`
+///
+/// Lists compare by their items alone.
+#[derive(Clone, Debug)]
+pub struct Punctuated<T, S> {
+    pub list: Vec<T>,
+    /// The separators between the items, as parsed
+    pub separators: Box<[S]>,
+}
+
+impl<'a, T: Parse<'a>, S: Parse<'a> + ::std::default::Default> Parse<'a> for Punctuated<T, S> {
+    parser!(do_parse!(
+        first: opt!(weedle!(T))
+            >> rest: cond!(first.is_some(), many0!(pair!(weedle!(S), weedle!(T))))
+            >> ({
+                let (list, separators) = unzip(first, rest.unwrap_or_default(), None);
+                Punctuated {
+                    list,
+                    separators,
+                }
+            })
+    ));
+}
+
+/// Parses `item1, item2, item3, ...`
+///
+/// Lists compare by their items alone.
+#[derive(Clone, Debug)]
+pub struct PunctuatedNonEmpty<T, S> {
+    pub list: Vec<T>,
+    /// The separators between the items, as parsed, along with the one
+    /// after the last item if any
+    pub separators: Box<[S]>,
+}
+
+impl<'a, T: Parse<'a>, S: Parse<'a> + ::std::default::Default> Parse<'a>
+    for PunctuatedNonEmpty<T, S>
+{
+    parser!(do_parse!(
+        first: weedle!(T)
+            >> rest: many0!(pair!(weedle!(S), weedle!(T)))
+            >> trailing: opt!(weedle!(S))
+            >> ({
+                let (list, separators) = unzip(Some(first), rest, trailing);
+                PunctuatedNonEmpty {
+                    list,
+                    separators,
+                }
+            })
+    ));
+}
+
+// Separators are boxed rather than kept in a `Vec`, as the larger nodes hold
+// many lists
+fn unzip<T, S>(first: Option<T>, rest: Vec<(S, T)>, trailing: Option<S>) -> (Vec<T>, Box<[S]>) {
+    let mut list: Vec<_> = first.into_iter().collect();
+    let mut separators = Vec::with_capacity(rest.len() + 1);
+    for (separator, item) in rest {
+        separators.push(separator);
+        list.push(item);
+    }
+    separators.extend(trailing);
+    (list, separators.into_boxed_slice())
+}
+
+// The separators as parsed are listed between the items, unless the items
+// were changed since, in which case new ones are, with an empty span
+fn punctuated_tokens<'a, T: Tokens<'a>, S: Tokens<'a> + ::std::default::Default>(
+    list: &[T],
+    separators: &[S],
+    trailing: bool,
+    out: &mut Vec<Token<'a>>,
+) {
+    let parsed = separators.len() + 1 == list.len() || trailing && separators.len() == list.len();
+    for (i, item) in list.iter().enumerate() {
+        if i > 0 {
+            match separators.get(i - 1) {
+                Some(separator) if parsed => separator.tokens(out),
+                _ => S::default().tokens(out),
+            }
+        }
+        item.tokens(out);
+    }
+    if parsed && !list.is_empty() {
+        if let Some(trailing) = separators.get(list.len() - 1) {
+            trailing.tokens(out);
+        }
+    }
+}
+
+// Lists compare by their items and only the items are serialized, as a
+// sequence
+macro_rules! punctuated_impls {
+    ($name:ident, $trailing:expr) => {
+        impl<T: PartialEq, S> PartialEq for $name<T, S> {
+            fn eq(&self, other: &Self) -> bool {
+                self.list == other.list
+            }
+        }
+
+        impl<T: Eq, S> Eq for $name<T, S> {}
+
+        impl<T: PartialOrd, S> PartialOrd for $name<T, S> {
+            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                self.list.partial_cmp(&other.list)
+            }
+        }
+
+        impl<T: Ord, S> Ord for $name<T, S> {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                self.list.cmp(&other.list)
+            }
+        }
+
+        impl<T: ::std::hash::Hash, S> ::std::hash::Hash for $name<T, S> {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                self.list.hash(state)
+            }
+        }
+
+        impl<T: Spanned, S: Spanned> Spanned for $name<T, S> {
+            fn span(&self) -> Span {
+                self.separators
+                    .iter()
+                    .fold(self.list.span(), |span, separator| {
+                        span.join(separator.span())
+                    })
+            }
+        }
+
+        impl<'a, T, S> Tokens<'a> for $name<T, S>
+        where
+            T: Tokens<'a>,
+            S: Tokens<'a> + ::std::default::Default,
+        {
+            fn tokens(&self, out: &mut Vec<Token<'a>>) {
+                punctuated_tokens(&self.list, &self.separators, $trailing, out)
+            }
+        }
+
+        impl<'a, T, S> fmt::Display for $name<T, S>
+        where
+            T: Tokens<'a>,
+            S: Tokens<'a> + ::std::default::Default,
+        {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.to_webidl())
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<T: serde::Serialize, S> serde::Serialize for $name<T, S> {
+            fn serialize<Ser: serde::Serializer>(
+                &self,
+                serializer: Ser,
+            ) -> Result<Ser::Ok, Ser::Error> {
+                self.list.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, T, S> serde::Deserialize<'de> for $name<T, S>
+        where
+            T: serde::Deserialize<'de>,
+            S: ::std::default::Default,
+        {
+            fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                Ok($name {
+                    list: Vec::deserialize(deserializer)?,
+                    separators: Box::new([]),
+                })
+            }
+        }
+    };
+}
+
+punctuated_impls!(Punctuated, false);
+punctuated_impls!(PunctuatedNonEmpty, true);
+
 ast_types! {
     /// Parses `( body )`
     #[derive(Copy, Default)]
@@ -58,44 +243,114 @@ ast_types! {
         close_angle: term::GreaterThan,
     }
 
-    /// Parses `(item1, item2, item3,...)?`
-    struct Punctuated<T, S> where [T: Parse<'a>, S: Parse<'a> + ::std::default::Default] {
-        list: Vec<T> = separated_list!(weedle!(S), weedle!(T)),
-        separator: S = marker,
+    /// Parses rhs of an assignment expression. Ex: `= 45`
+    #[derive(Copy)]
+    struct Default<'a> {
+        assign: term!(=),
+        value: DefaultValue<'a>,
     }
+}
+
+/// Represents an identifier
+///
+/// Follows `/_?[A-Za-z][0-9A-Z_a-z-]*/`. The leading underscore is trimmed,
+/// see https://heycam.github.io/webidl/#idl-names, and the last field tells
+/// whether there was one. Identifiers compare by name alone.
+#[derive(Clone, Copy, Debug)]
+pub struct Identifier<'a>(pub &'a str, pub Span, pub bool);
 
-    /// Parses `item1, item2, item3, ...`
-    struct PunctuatedNonEmpty<T, S> where [T: Parse<'a>, S: Parse<'a> + ::std::default::Default] {
-        list: Vec<T> = terminated!(
-            separated_nonempty_list!(weedle!(S), weedle!(T)),
-            opt!(weedle!(S))
-        ),
-        separator: S = marker,
+impl<'a> Identifier<'a> {
+    /// Creates an unescaped identifier with an empty span, as it is not
+    /// parsed from any input
+    pub fn new(name: &'a str) -> Self {
+        Identifier(name, Span::EMPTY, false)
     }
 
-    /// Represents an identifier
-    ///
-    /// Follows `/_?[A-Za-z][0-9A-Z_a-z-]*/`
-    #[derive(Copy)]
-    struct Identifier<'a>(
-        // See https://heycam.github.io/webidl/#idl-names for why the leading
-        // underscore is trimmed
-        &'a str = ws!(do_parse!(
-            opt!(char!('_')) >>
-            id: recognize!(do_parse!(
+    /// Returns `true` if the identifier was written with a leading `_`, as in
+    /// `_interface`
+    pub fn is_escaped(&self) -> bool {
+        self.2
+    }
+
+    /// The identifier as written, with its leading `_` if it was escaped
+    pub fn raw(&self) -> Cow<'a, str> {
+        if self.is_escaped() {
+            Cow::Owned(format!("_{}", self.0))
+        } else {
+            Cow::Borrowed(self.0)
+        }
+    }
+}
+
+impl<'a> Parse<'a> for Identifier<'a> {
+    parser!(do_parse!(
+        parsed: ws!(expect!(do_parse!(
+            escaped: opt!(char!('_')) >>
+            name: recognize!(do_parse!(
                 take_while1!(|c: char| c.is_ascii_alphabetic()) >>
                 take_while!(|c: char| c.is_ascii_alphanumeric() || c == '_' || c == '-') >>
                 (())
             )) >>
-            (id)
-        )),
-    )
+            ((name, escaped.is_some()))
+        ), "identifier")) >>
+        (Identifier((parsed.0).0, parsed.1, (parsed.0).1))
+    ));
+}
 
-    /// Parses rhs of an assignment expression. Ex: `= 45`
-    #[derive(Copy)]
-    struct Default<'a> {
-        assign: term!(=),
-        value: DefaultValue<'a>,
+impl<'a> PartialEq for Identifier<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<'a> Eq for Identifier<'a> {}
+
+impl<'a> PartialOrd for Identifier<'a> {
+    fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<'a> Ord for Identifier<'a> {
+    fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+        self.0.cmp(other.0)
+    }
+}
+
+impl<'a> ::std::hash::Hash for Identifier<'a> {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
+}
+
+impl<'a> Spanned for Identifier<'a> {
+    fn span(&self) -> Span {
+        self.1
+    }
+}
+
+// Serialized as written, so escaped identifiers keep their `_`
+#[cfg(feature = "serde")]
+impl<'a> serde::Serialize for Identifier<'a> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.raw().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de: 'a, 'a> serde::Deserialize<'de> for Identifier<'a> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = <&'a str>::deserialize(deserializer)?;
+        Ok(match raw.strip_prefix('_') {
+            Some(name) => Identifier(name, Span::EMPTY, true),
+            None => Identifier::new(raw),
+        })
+    }
+}
+
+impl<'a> fmt::Display for Identifier<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_webidl())
     }
 }
 
@@ -155,7 +410,7 @@ mod test {
         Generics<(Identifier, term!(,), Identifier)> =>
             Generics {
                 open_angle: term!(<),
-                body: (Identifier("one"), term!(,), Identifier("two")),
+                body: (Identifier::new("one"), term!(,), Identifier::new("two")),
                 close_angle: term!(>),
             }
     });
@@ -164,8 +419,34 @@ mod test {
         "";
         Punctuated<Identifier, term!(,)>;
         list.len() == 3;
+        separators.len() == 2;
+    });
+
+    test!(should_not_parse_trailing_separator { "one, two," =>
+        ",";
+        Punctuated<Identifier, term!(,)>;
+        list.len() == 2;
+        separators.len() == 1;
+    });
+
+    test!(should_parse_trailing_separator { "one, two," =>
+        "";
+        PunctuatedNonEmpty<Identifier, term!(,)>;
+        list.len() == 2;
+        separators.len() == 2;
     });
 
+    #[test]
+    fn should_compare_lists_by_items() {
+        let (_, first) = Punctuated::<Identifier, term!(,)>::parse("one, two").unwrap();
+        let (_, second) = Punctuated::<Identifier, term!(,)>::parse("one ,two").unwrap();
+        assert_eq!(first, second);
+
+        let (_, first) = PunctuatedNonEmpty::<Identifier, term!(,)>::parse("one").unwrap();
+        let (_, second) = PunctuatedNonEmpty::<Identifier, term!(,)>::parse("one,").unwrap();
+        assert_eq!(first, second);
+    }
+
     test!(err should_not_parse_comma_separated_values_empty { "" =>
         PunctuatedNonEmpty<Identifier, term!(,)>
     });
@@ -186,8 +467,31 @@ mod test {
         "";
         Identifier;
         0 == "hello_";
+        is_escaped();
+        raw() == "_hello_";
     });
 
+    #[test]
+    fn should_tell_unescaped_identifiers() {
+        let (_, parsed) = Identifier::parse(" hello_ ").unwrap();
+        assert!(!parsed.is_escaped());
+        assert_eq!(parsed.raw(), "hello_");
+        assert!(!Identifier::new("a").is_escaped());
+    }
+
+    #[test]
+    fn should_keep_escapes_of_renamed_identifiers() {
+        let (_, mut parsed) = Identifier::parse("ab").unwrap();
+        parsed.0 = "a";
+        assert_eq!(parsed.raw(), "a");
+        assert_eq!(parsed.to_string(), "a");
+
+        let (_, mut parsed) = Identifier::parse("_a").unwrap();
+        parsed.0 = "abc";
+        assert_eq!(parsed.raw(), "_abc");
+        assert_eq!(parsed.to_string(), "_abc");
+    }
+
     test!(should_parse_identifier_surrounding_with_spaces { "  hello  " =>
         "";
         Identifier;
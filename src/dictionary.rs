@@ -1,5 +1,10 @@
+use std::fmt;
+
 use crate::attribute::ExtendedAttributeList;
 use crate::common::{Default, Identifier};
+use crate::print::ToWebIdl;
+use crate::span::{Span, Spanned};
+use crate::token::{Token, Tokens};
 use crate::types::Type;
 use crate::Parse;
 
@@ -24,6 +29,8 @@ pub type DictionaryMembers<'a> = Vec<DictionaryMember<'a>>;
 /// - Required members: `[member-attrs]? required [type-attrs]? Type identifier ;`
 /// - Optional members: `[member-attrs]? Type identifier Default? ;`
 #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
 pub struct DictionaryMember<'a> {
     pub attributes: Option<ExtendedAttributeList<'a>>,
     pub required: Option<crate::term::Required>,
@@ -93,6 +100,35 @@ impl<'a> Parse<'a> for DictionaryMember<'a> {
     }
 }
 
+impl<'a> Spanned for DictionaryMember<'a> {
+    fn span(&self) -> Span {
+        self.attributes
+            .span()
+            .join(self.required.span())
+            .join(self.type_.span())
+            .join(self.identifier.span())
+            .join(self.default.span())
+            .join(self.semi_colon.span())
+    }
+}
+
+impl<'a> Tokens<'a> for DictionaryMember<'a> {
+    fn tokens(&self, out: &mut Vec<Token<'a>>) {
+        self.attributes.tokens(out);
+        self.required.tokens(out);
+        self.type_.tokens(out);
+        self.identifier.tokens(out);
+        self.default.tokens(out);
+        self.semi_colon.tokens(out);
+    }
+}
+
+impl<'a> fmt::Display for DictionaryMember<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.to_webidl())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
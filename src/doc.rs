@@ -0,0 +1,228 @@
+//! Documentation comments of definitions and members
+//!
+//! A doc comment is the block of comments written on the lines right above a
+//! node, with no blank line in between. Both `/** ... */` and `//` comments
+//! are accepted.
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::doc::Documented;
+//!
+//! let input = "
+//!     /**
+//!      * The global object
+//!      */
+//!     interface Window {
+//!         // Storage for the session
+//!         readonly attribute Storage sessionStorage;
+//!     };
+//! ";
+//! let parsed = weedle::parse(input).unwrap();
+//!
+//! let doc = parsed[0].doc_comment(input).unwrap();
+//! assert_eq!(doc.text(), "The global object");
+//! ```
+
+use crate::dictionary::DictionaryMember;
+use crate::interface::InterfaceMember;
+use crate::literal::StringLit;
+use crate::mixin::MixinMember;
+use crate::namespace::NamespaceMember;
+use crate::span::{Span, Spanned};
+use crate::Definition;
+
+/// The comments documenting a node
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct DocComment<'a> {
+    /// The comments as written, markers included
+    pub raw: &'a str,
+    pub span: Span,
+}
+
+impl<'a> DocComment<'a> {
+    /// The text of the comments without the comment markers, with a line per
+    /// line of comment
+    pub fn text(&self) -> String {
+        let mut lines: Vec<_> = self.raw.lines().map(strip_markers).collect();
+        while lines.first() == Some(&"") {
+            lines.remove(0);
+        }
+        while lines.last() == Some(&"") {
+            lines.pop();
+        }
+        lines.join("\n")
+    }
+}
+
+/// Implemented by the nodes a doc comment can be written for
+pub trait Documented: Spanned {
+    /// The doc comment right above the node within `input`, the text
+    /// originally parsed
+    fn doc_comment<'a>(&self, input: &'a str) -> Option<DocComment<'a>> {
+        let span = self.span();
+        if span.is_empty() {
+            return None;
+        }
+        find(input, span.range(input)?.start)
+    }
+}
+
+impl<'a> Documented for Definition<'a> {}
+impl<'a> Documented for InterfaceMember<'a> {}
+impl<'a> Documented for MixinMember<'a> {}
+impl<'a> Documented for NamespaceMember<'a> {}
+impl<'a> Documented for DictionaryMember<'a> {}
+/// Enum values
+impl<'a> Documented for StringLit<'a> {}
+
+fn find(input: &str, offset: usize) -> Option<DocComment<'_>> {
+    let mut start = None;
+    let mut end = 0;
+    let mut top = line_start(input, offset);
+
+    // Only a block comment may be written on the line of the node itself
+    let before = &input[top..offset];
+    if !before.trim().is_empty() {
+        let comment = before.trim();
+        if !comment.starts_with("/*") || !comment.ends_with("*/") {
+            return None;
+        }
+        let comment_start = offset - before.trim_start().len();
+        start = Some(comment_start);
+        end = comment_start + comment.len();
+    }
+
+    // Walk up the lines above as long as they are comments
+    while top > 0 {
+        let line_end = top - 1;
+        let line = &input[line_start(input, line_end)..line_end];
+        let comment = line.trim();
+        let comment_start = if comment.starts_with("//") {
+            line_end - line.trim_start().len()
+        } else if comment.ends_with("*/") {
+            match input[..line_end].rfind("/*") {
+                Some(i) if input[line_start(input, i)..i].trim().is_empty() => i,
+                _ => break,
+            }
+        } else {
+            break;
+        };
+        if start.is_none() {
+            end = line_end - line.trim_start().len() + comment.len();
+        }
+        start = Some(comment_start);
+        top = line_start(input, comment_start);
+    }
+
+    let start = start?;
+    Some(DocComment {
+        raw: &input[start..end],
+        span: Span::new(&input[start..], &input[end..]),
+    })
+}
+
+fn line_start(input: &str, offset: usize) -> usize {
+    input[..offset].rfind('\n').map_or(0, |i| i + 1)
+}
+
+fn strip_markers(line: &str) -> &str {
+    let mut line = line.trim();
+    if let Some(rest) = line.strip_suffix("*/") {
+        line = rest;
+    }
+    for prefix in &["///", "//", "/**", "/*", "*"] {
+        if let Some(rest) = line.strip_prefix(prefix) {
+            line = rest;
+            break;
+        }
+    }
+    line.strip_prefix(' ').unwrap_or(line).trim_end()
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::common::Braced;
+    use crate::Parse;
+
+    const INPUT: &str = "// License
+
+/// Something to iterate over
+/// in order
+interface Foo { // Opening
+    /** Counts */
+    attribute long count;
+    attribute long other; // Not documented
+
+    attribute long last;
+    /* Inline */ void bar();
+};
+
+/*
+ * Letters
+ */
+enum Bar {
+    // The first
+    \"a\",
+    \"b\"
+};
+";
+
+    #[test]
+    fn should_find_line_comments() {
+        let parsed = crate::parse(INPUT).unwrap();
+        let doc = parsed[0].doc_comment(INPUT).unwrap();
+        assert_eq!(doc.raw, "/// Something to iterate over\n/// in order");
+        assert_eq!(doc.text(), "Something to iterate over\nin order");
+        assert_eq!(doc.span.as_str(INPUT).unwrap(), doc.raw);
+    }
+
+    #[test]
+    fn should_find_block_comments() {
+        let parsed = crate::parse(INPUT).unwrap();
+        let doc = parsed[1].doc_comment(INPUT).unwrap();
+        assert_eq!(doc.raw, "/*\n * Letters\n */");
+        assert_eq!(doc.text(), "Letters");
+    }
+
+    #[test]
+    fn should_document_members() {
+        let parsed = crate::parse(INPUT).unwrap();
+        let members = match &parsed[0] {
+            Definition::Interface(interface) => &interface.members.body,
+            _ => unreachable!(),
+        };
+        let docs: Vec<_> = members
+            .iter()
+            .map(|member| member.doc_comment(INPUT).map(|doc| doc.text()))
+            .collect();
+        assert_eq!(
+            docs,
+            [
+                Some("Counts".to_string()),
+                None,
+                None,
+                Some("Inline".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn should_document_enum_values() {
+        let parsed = crate::parse(INPUT).unwrap();
+        let values = match &parsed[1] {
+            Definition::Enum(enum_) => &enum_.values.body.list,
+            _ => unreachable!(),
+        };
+        assert_eq!(values[0].doc_comment(INPUT).unwrap().text(), "The first");
+        assert!(values[1].doc_comment(INPUT).is_none());
+    }
+
+    #[test]
+    fn should_not_document_after_code() {
+        let input = "{ long a; /* not */ long b; }";
+        let (_, parsed) = Braced::<Vec<DictionaryMember>>::parse(input).unwrap();
+        assert!(parsed.body[1].doc_comment(input).is_none());
+    }
+}
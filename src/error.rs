@@ -0,0 +1,379 @@
+//! Human readable parse errors
+//!
+//! ### Example
+//!
+//! ```
+//! let input = "interface Window {\n    attribute long foo bar;\n};";
+//!
+//! let error = weedle::parse(input).unwrap_err();
+//! assert_eq!(error.location.line, 2);
+//! assert_eq!(error.location.column, 24);
+//! assert_eq!(error.expected, vec!["`;`"]);
+//!
+//! println!("{}", error);
+//! ```
+
+use std::cell::RefCell;
+use std::fmt;
+
+use crate::attribute::ExtendedAttributeList;
+use crate::common::Identifier;
+use crate::span::{LineColumn, Span};
+use crate::whitespace::sp;
+use crate::IResult;
+
+/// Describes why and where parsing failed
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Error {
+    /// Byte offset in the input at which parsing failed
+    pub offset: usize,
+    /// Line and column at which parsing failed
+    pub location: LineColumn,
+    /// The full line of input on which parsing failed
+    pub line_text: String,
+    /// The tokens that would have been accepted at the point of failure.
+    /// Ex: ``["`;`", "`=`"]``
+    pub expected: Vec<&'static str>,
+    /// The kind of definition which was being parsed, if known
+    pub definition: Option<DefinitionKind>,
+    /// Line and column at which parsing stopped, that is where the
+    /// definition which could not be parsed starts
+    pub stopped_at: LineColumn,
+    /// The input which could not be parsed
+    pub span: Span,
+}
+
+impl Error {
+    /// Creates an error for `input` where parsing stopped with `rest` left
+    ///
+    /// The error points at the furthest position any token failed to parse
+    /// at, as recorded in `expected`, if that is beyond `rest`, since that is
+    /// where the actual mistake usually is.
+    pub(crate) fn new(input: &str, rest: &str, expected: &Expected) -> Self {
+        let (remaining, expected) = if expected.remaining <= rest.len() {
+            (expected.remaining, expected.tokens.clone())
+        } else {
+            (rest.len(), Vec::new())
+        };
+        let offset = input.len() - remaining;
+        let rest = match sp(rest) {
+            Ok((rest, _)) => rest,
+            Err(_) => rest,
+        };
+        let line_start = input[..offset].rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Error {
+            offset,
+            location: LineColumn::at(input, offset),
+            line_text: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+            expected,
+            definition: DefinitionKind::detect(rest),
+            stopped_at: LineColumn::at(input, input.len() - rest.len()),
+            span: Span::new(rest, ""),
+        }
+    }
+
+    pub(crate) fn from_nom(
+        input: &str,
+        err: crate::Err<(&str, crate::ErrorKind)>,
+        expected: &Expected,
+    ) -> Self {
+        match err {
+            crate::Err::Error((rest, _)) | crate::Err::Failure((rest, _)) => {
+                Error::new(input, rest, expected)
+            }
+            crate::Err::Incomplete(_) => Error::new(input, "", expected),
+        }
+    }
+
+    fn message(&self) -> String {
+        let mut message = match self.expected.split_last() {
+            None => "unexpected input".to_owned(),
+            Some((last, [])) => format!("expected {}", last),
+            Some((last, rest)) => format!("expected {} or {}", rest.join(", "), last),
+        };
+        if let Some(definition) = self.definition {
+            message.push_str(&format!(" while parsing {}", definition));
+        }
+        if self.stopped_at != self.location {
+            message.push_str(&format!(
+                " starting at line {}, column {}",
+                self.stopped_at.line, self.stopped_at.column
+            ));
+        }
+        message
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let line = self.location.line.to_string();
+        let gutter = " ".repeat(line.len());
+        let caret: String = self
+            .line_text
+            .chars()
+            .take(self.location.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        writeln!(f, "error: {}", self.message())?;
+        writeln!(
+            f,
+            "{}--> line {}, column {}",
+            gutter, self.location.line, self.location.column
+        )?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", line, self.line_text)?;
+        write!(f, "{} | {}^", gutter, caret)
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// The kinds of definitions a WebIDL file is made of
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum DefinitionKind {
+    Callback,
+    CallbackInterface,
+    Interface,
+    InterfaceMixin,
+    Namespace,
+    Dictionary,
+    PartialInterface,
+    PartialInterfaceMixin,
+    PartialDictionary,
+    PartialNamespace,
+    Enum,
+    Typedef,
+    IncludesStatement,
+    Implements,
+}
+
+impl DefinitionKind {
+    /// Guesses the kind of the definition at the start of `input` from its
+    /// leading keywords, whether or not the rest of it is valid
+    pub fn detect(input: &str) -> Option<Self> {
+        fn keywords<'a>(input: &'a str) -> IResult<&'a str, DefinitionKind> {
+            do_parse!(
+                input,
+                weedle!(Option<ExtendedAttributeList>)
+                    >> kind: alt!(
+                        do_parse!(weedle!(term!(callback)) >> weedle!(term!(interface)) >> (DefinitionKind::CallbackInterface)) |
+                        do_parse!(weedle!(term!(callback)) >> (DefinitionKind::Callback)) |
+                        do_parse!(weedle!(term!(interface)) >> weedle!(term!(mixin)) >> (DefinitionKind::InterfaceMixin)) |
+                        do_parse!(weedle!(term!(interface)) >> (DefinitionKind::Interface)) |
+                        do_parse!(weedle!(term!(namespace)) >> (DefinitionKind::Namespace)) |
+                        do_parse!(weedle!(term!(dictionary)) >> (DefinitionKind::Dictionary)) |
+                        do_parse!(weedle!(term!(partial)) >> weedle!(term!(interface)) >> weedle!(term!(mixin)) >> (DefinitionKind::PartialInterfaceMixin)) |
+                        do_parse!(weedle!(term!(partial)) >> weedle!(term!(interface)) >> (DefinitionKind::PartialInterface)) |
+                        do_parse!(weedle!(term!(partial)) >> weedle!(term!(dictionary)) >> (DefinitionKind::PartialDictionary)) |
+                        do_parse!(weedle!(term!(partial)) >> weedle!(term!(namespace)) >> (DefinitionKind::PartialNamespace)) |
+                        do_parse!(weedle!(term!(enum)) >> (DefinitionKind::Enum)) |
+                        do_parse!(weedle!(term!(typedef)) >> (DefinitionKind::Typedef)) |
+                        do_parse!(weedle!(Identifier) >> weedle!(term!(includes)) >> (DefinitionKind::IncludesStatement)) |
+                        do_parse!(weedle!(Identifier) >> weedle!(term!(implements)) >> (DefinitionKind::Implements))
+                    )
+                    >> (kind)
+            )
+        }
+
+        // Peeking must not disturb what was recorded for the actual failure
+        let (kind, _) = tracking(|| keywords(input));
+        kind.ok().map(|(_, kind)| kind)
+    }
+}
+
+impl fmt::Display for DefinitionKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            DefinitionKind::Callback => "callback",
+            DefinitionKind::CallbackInterface => "callback interface",
+            DefinitionKind::Interface => "interface",
+            DefinitionKind::InterfaceMixin => "interface mixin",
+            DefinitionKind::Namespace => "namespace",
+            DefinitionKind::Dictionary => "dictionary",
+            DefinitionKind::PartialInterface => "partial interface",
+            DefinitionKind::PartialInterfaceMixin => "partial interface mixin",
+            DefinitionKind::PartialDictionary => "partial dictionary",
+            DefinitionKind::PartialNamespace => "partial namespace",
+            DefinitionKind::Enum => "enum",
+            DefinitionKind::Typedef => "typedef",
+            DefinitionKind::IncludesStatement => "includes statement",
+            DefinitionKind::Implements => "implements statement",
+        })
+    }
+}
+
+/// Skips the definition at the start of `input`, up to and including the next
+/// `;` which is not nested in braces, and returns what follows it
+pub(crate) fn skip_definition(input: &str) -> &str {
+    let mut depth = 0usize;
+    let mut i = 0;
+    while let Some(c) = input[i..].chars().next() {
+        let rest = &input[i..];
+        let skip = if c == '"' {
+            rest[1..].find('"').map(|end| end + 2)
+        } else if rest.starts_with("//") {
+            rest.find('\n').map(|end| end + 1)
+        } else if rest.starts_with("/*") {
+            rest.find("*/").map(|end| end + 2)
+        } else {
+            match c {
+                '{' => depth += 1,
+                '}' => depth = depth.saturating_sub(1),
+                ';' if depth == 0 => return &rest[1..],
+                _ => {}
+            }
+            Some(c.len_utf8())
+        };
+        match skip {
+            Some(skip) => i += skip,
+            None => break,
+        }
+    }
+    ""
+}
+
+/// The tokens which failed to parse furthest into the input
+#[derive(Clone, Debug)]
+pub(crate) struct Expected {
+    remaining: usize,
+    tokens: Vec<&'static str>,
+}
+
+impl Default for Expected {
+    fn default() -> Self {
+        Expected {
+            remaining: usize::MAX,
+            tokens: Vec::new(),
+        }
+    }
+}
+
+thread_local! {
+    // Only set while `tracking` runs, so parsing outside of it records nothing
+    static EXPECTED: RefCell<Option<Expected>> = const { RefCell::new(None) };
+}
+
+/// Runs `parse`, recording the tokens which fail to parse furthest into its
+/// input from scratch
+pub(crate) fn tracking<T, F: FnOnce() -> T>(parse: F) -> (T, Expected) {
+    let outer = EXPECTED.with(|expected| expected.replace(Some(Expected::default())));
+    let result = parse();
+    let expected = EXPECTED.with(|expected| expected.replace(outer));
+    (result, expected.unwrap_or_default())
+}
+
+/// Records that `token` was expected but not found at the start of `input`
+pub(crate) fn expected(input: &str, token: &'static str) {
+    EXPECTED.with(|expected| {
+        if let Some(expected) = expected.borrow_mut().as_mut() {
+            if input.len() < expected.remaining {
+                expected.remaining = input.len();
+                expected.tokens.clear();
+            }
+            if input.len() == expected.remaining && !expected.tokens.contains(&token) {
+                expected.tokens.push(token);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{Definition, Parse};
+
+    fn parse_definition(input: &str) -> Error {
+        let (result, expected) = tracking(|| Definition::parse(input));
+        Error::from_nom(input, result.unwrap_err(), &expected)
+    }
+
+    #[test]
+    fn should_point_at_furthest_failure() {
+        let input = "interface Foo {\n  attribute long x y;\n};";
+        let error = parse_definition(input);
+
+        assert_eq!(error.offset, input.find('y').unwrap());
+        assert_eq!(
+            error.location,
+            LineColumn {
+                line: 2,
+                column: 20
+            }
+        );
+        assert_eq!(error.line_text, "  attribute long x y;");
+        assert_eq!(error.expected, vec!["`;`"]);
+        assert_eq!(error.definition, Some(DefinitionKind::Interface));
+        assert_eq!(error.stopped_at, LineColumn { line: 1, column: 1 });
+    }
+
+    #[test]
+    fn should_list_every_expected_token() {
+        let error = parse_definition("dictionary Foo { long x };");
+
+        assert_eq!(error.expected, vec!["`=`", "`;`"]);
+        assert_eq!(error.definition, Some(DefinitionKind::Dictionary));
+    }
+
+    #[test]
+    fn should_render_caret_diagnostic() {
+        let error = parse_definition("typedef long;");
+
+        assert_eq!(
+            error.to_string(),
+            "error: expected `long`, `?` or identifier while parsing typedef \
+             starting at line 1, column 1\n \
+             --> line 1, column 13\n  \
+             |\n\
+             1 | typedef long;\n  \
+             |             ^"
+        );
+    }
+
+    #[test]
+    fn should_skip_to_top_level_semi_colon() {
+        let input = r#"interface A { attribute "};" x; /* }; */ // };
+            const long y = 1; }; rest"#;
+        assert_eq!(skip_definition(input), " rest");
+        assert_eq!(skip_definition("typedef long"), "");
+        assert_eq!(skip_definition("a }}; b"), " b");
+    }
+
+    #[test]
+    fn should_detect_definition_kinds() {
+        assert_eq!(
+            DefinitionKind::detect("[Exposed=Window] partial interface mixin Foo {"),
+            Some(DefinitionKind::PartialInterfaceMixin)
+        );
+        assert_eq!(
+            DefinitionKind::detect("callback interface Foo"),
+            Some(DefinitionKind::CallbackInterface)
+        );
+        assert_eq!(
+            DefinitionKind::detect("Foo includes"),
+            Some(DefinitionKind::IncludesStatement)
+        );
+        assert_eq!(DefinitionKind::detect("attribute long x;"), None);
+    }
+
+    #[test]
+    fn should_track_failures_of_each_input_apart() {
+        // Parsing outside of an entry point leaves nothing behind
+        let _ = Definition::parse("interface Foo {\n  attribute long x y;\n};");
+        let input = "typedef long;";
+        assert_eq!(crate::parse(input).unwrap_err().offset, input.len() - 1);
+
+        let ((_, inner), outer) = tracking(|| {
+            expected("a", "`a`");
+            tracking(|| expected("", "`b`"))
+        });
+        assert_eq!(inner.tokens, ["`b`"]);
+        assert_eq!(outer.tokens, ["`a`"]);
+    }
+}
@@ -0,0 +1,1943 @@
+//! Rebuilding the syntax tree by value
+//!
+//! Each method of [`Fold`](trait.Fold.html) takes a node and returns the node
+//! to put in its place, folding its children by default through the function
+//! of the same name.
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::fold::{self, Fold};
+//! use weedle::print::ToWebIdl;
+//! use weedle::types::{NonAnyType, SingleType, Type};
+//! use weedle::{Definition, Parse};
+//!
+//! // Replaces `BufferSource` with the union it stands for
+//! struct ExpandBufferSource;
+//!
+//! impl<'a> Fold<'a> for ExpandBufferSource {
+//!     fn fold_type(&mut self, node: Type<'a>) -> Type<'a> {
+//!         match node {
+//!             Type::Single(SingleType::NonAny(NonAnyType::BufferSource(buffer_source))) => {
+//!                 let (_, mut union) = Type::parse("(ArrayBufferView or ArrayBuffer)").unwrap();
+//!                 if let Type::Union(union) = &mut union {
+//!                     union.q_mark = buffer_source.q_mark;
+//!                 }
+//!                 union
+//!             }
+//!             node => fold::fold_type(self, node),
+//!         }
+//!     }
+//! }
+//!
+//! let (_, parsed) = Definition::parse("typedef sequence<BufferSource?> Buffers;").unwrap();
+//! assert_eq!(
+//!     ExpandBufferSource.fold_definition(parsed).to_webidl(),
+//!     "typedef sequence<(ArrayBufferView or ArrayBuffer)?> Buffers;",
+//! );
+//! ```
+
+use crate::argument::{
+    Argument, ArgumentList, ArgumentName, ArgumentNameKeyword, SingleArgument, VariadicArgument,
+};
+use crate::attribute::{
+    ExtendedAttribute, ExtendedAttributeArgList, ExtendedAttributeIdent,
+    ExtendedAttributeIdentList, ExtendedAttributeList, ExtendedAttributeNamedArgList,
+    ExtendedAttributeNoArgs, ExtendedAttributeWildCard, IdentifierList, IdentifierOrString,
+};
+use crate::common::{
+    Braced, Bracketed, Default, Generics, Identifier, Parenthesized, Punctuated, PunctuatedNonEmpty,
+};
+use crate::dictionary::DictionaryMember;
+use crate::interface::{
+    AsyncIterableInterfaceMember, AsyncIterableKeyword, AttributeInterfaceMember, ConstMember,
+    ConstructorInterfaceMember, DoubleTypedAsyncIterable, DoubleTypedIterable, Inheritance,
+    InterfaceMember, IterableInterfaceMember, MaplikeInterfaceMember, OperationInterfaceMember,
+    SeparateAsyncIterable, SetlikeInterfaceMember, SingleTypedAsyncIterable, SingleTypedIterable,
+    Special, StringifierMember, StringifierOrInheritOrStatic, StringifierOrStatic,
+};
+use crate::literal::{
+    BooleanLit, ConstValue, DecLit, DefaultValue, EmptyArrayLit, EmptyDictionaryLit, FloatLit,
+    FloatValueLit, HexLit, IntegerLit, OctLit, StringLit,
+};
+use crate::mixin::{AttributeMixinMember, MixinMember, OperationMixinMember};
+use crate::namespace::{
+    AttributeNamespaceMember, ConstNamespaceMember, NamespaceMember, OperationNamespaceMember,
+};
+use crate::types::{
+    AttributedNonAnyType, AttributedType, ConstType, DoubleType, FloatType, FloatingPointType,
+    FrozenArrayType, IntegerType, LongLongType, LongType, MayBeNull, NonAnyType,
+    ObservableArrayType, PromiseType, RecordKeyType, RecordType, ReturnType, SequenceType,
+    ShortType, SingleType, Type, UnionMemberType, UnionType,
+};
+use crate::{
+    CallbackDefinition, CallbackInterfaceDefinition, Definition, DictionaryDefinition,
+    EnumDefinition, EnumValueList, ImplementsDefinition, IncludesStatementDefinition,
+    InterfaceDefinition, InterfaceMixinDefinition, NamespaceDefinition,
+    PartialDictionaryDefinition, PartialInterfaceDefinition, PartialInterfaceMixinDefinition,
+    PartialNamespaceDefinition, TypedefDefinition,
+};
+
+/// Folds the nodes of a syntax tree into new ones
+pub trait Fold<'a> {
+    fn fold_argument(&mut self, node: Argument<'a>) -> Argument<'a> {
+        fold_argument(self, node)
+    }
+
+    fn fold_argument_list(&mut self, node: ArgumentList<'a>) -> ArgumentList<'a> {
+        fold_argument_list(self, node)
+    }
+
+    fn fold_argument_name(&mut self, node: ArgumentName<'a>) -> ArgumentName<'a> {
+        fold_argument_name(self, node)
+    }
+
+    fn fold_argument_name_keyword(&mut self, node: ArgumentNameKeyword) -> ArgumentNameKeyword {
+        fold_argument_name_keyword(self, node)
+    }
+
+    fn fold_async_iterable_interface_member(
+        &mut self,
+        node: AsyncIterableInterfaceMember<'a>,
+    ) -> AsyncIterableInterfaceMember<'a> {
+        fold_async_iterable_interface_member(self, node)
+    }
+
+    fn fold_async_iterable_keyword(&mut self, node: AsyncIterableKeyword) -> AsyncIterableKeyword {
+        fold_async_iterable_keyword(self, node)
+    }
+
+    fn fold_attribute_interface_member(
+        &mut self,
+        node: AttributeInterfaceMember<'a>,
+    ) -> AttributeInterfaceMember<'a> {
+        fold_attribute_interface_member(self, node)
+    }
+
+    fn fold_attribute_mixin_member(
+        &mut self,
+        node: AttributeMixinMember<'a>,
+    ) -> AttributeMixinMember<'a> {
+        fold_attribute_mixin_member(self, node)
+    }
+
+    fn fold_attribute_namespace_member(
+        &mut self,
+        node: AttributeNamespaceMember<'a>,
+    ) -> AttributeNamespaceMember<'a> {
+        fold_attribute_namespace_member(self, node)
+    }
+
+    fn fold_attributed_non_any_type(
+        &mut self,
+        node: AttributedNonAnyType<'a>,
+    ) -> AttributedNonAnyType<'a> {
+        fold_attributed_non_any_type(self, node)
+    }
+
+    fn fold_attributed_type(&mut self, node: AttributedType<'a>) -> AttributedType<'a> {
+        fold_attributed_type(self, node)
+    }
+
+    fn fold_boolean_lit(&mut self, node: BooleanLit) -> BooleanLit {
+        fold_boolean_lit(self, node)
+    }
+
+    fn fold_callback_definition(&mut self, node: CallbackDefinition<'a>) -> CallbackDefinition<'a> {
+        fold_callback_definition(self, node)
+    }
+
+    fn fold_callback_interface_definition(
+        &mut self,
+        node: CallbackInterfaceDefinition<'a>,
+    ) -> CallbackInterfaceDefinition<'a> {
+        fold_callback_interface_definition(self, node)
+    }
+
+    fn fold_const_member(&mut self, node: ConstMember<'a>) -> ConstMember<'a> {
+        fold_const_member(self, node)
+    }
+
+    fn fold_const_namespace_member(
+        &mut self,
+        node: ConstNamespaceMember<'a>,
+    ) -> ConstNamespaceMember<'a> {
+        fold_const_namespace_member(self, node)
+    }
+
+    fn fold_const_type(&mut self, node: ConstType<'a>) -> ConstType<'a> {
+        fold_const_type(self, node)
+    }
+
+    fn fold_const_value(&mut self, node: ConstValue<'a>) -> ConstValue<'a> {
+        fold_const_value(self, node)
+    }
+
+    fn fold_constructor_interface_member(
+        &mut self,
+        node: ConstructorInterfaceMember<'a>,
+    ) -> ConstructorInterfaceMember<'a> {
+        fold_constructor_interface_member(self, node)
+    }
+
+    fn fold_dec_lit(&mut self, node: DecLit<'a>) -> DecLit<'a> {
+        fold_dec_lit(self, node)
+    }
+
+    fn fold_default(&mut self, node: Default<'a>) -> Default<'a> {
+        fold_default(self, node)
+    }
+
+    fn fold_default_value(&mut self, node: DefaultValue<'a>) -> DefaultValue<'a> {
+        fold_default_value(self, node)
+    }
+
+    fn fold_definition(&mut self, node: Definition<'a>) -> Definition<'a> {
+        fold_definition(self, node)
+    }
+
+    fn fold_dictionary_definition(
+        &mut self,
+        node: DictionaryDefinition<'a>,
+    ) -> DictionaryDefinition<'a> {
+        fold_dictionary_definition(self, node)
+    }
+
+    fn fold_dictionary_member(&mut self, node: DictionaryMember<'a>) -> DictionaryMember<'a> {
+        fold_dictionary_member(self, node)
+    }
+
+    fn fold_double_type(&mut self, node: DoubleType) -> DoubleType {
+        fold_double_type(self, node)
+    }
+
+    fn fold_double_typed_async_iterable(
+        &mut self,
+        node: DoubleTypedAsyncIterable<'a>,
+    ) -> DoubleTypedAsyncIterable<'a> {
+        fold_double_typed_async_iterable(self, node)
+    }
+
+    fn fold_double_typed_iterable(
+        &mut self,
+        node: DoubleTypedIterable<'a>,
+    ) -> DoubleTypedIterable<'a> {
+        fold_double_typed_iterable(self, node)
+    }
+
+    fn fold_empty_array_lit(&mut self, node: EmptyArrayLit) -> EmptyArrayLit {
+        fold_empty_array_lit(self, node)
+    }
+
+    fn fold_empty_dictionary_lit(&mut self, node: EmptyDictionaryLit) -> EmptyDictionaryLit {
+        fold_empty_dictionary_lit(self, node)
+    }
+
+    fn fold_enum_definition(&mut self, node: EnumDefinition<'a>) -> EnumDefinition<'a> {
+        fold_enum_definition(self, node)
+    }
+
+    fn fold_enum_value_list(&mut self, node: EnumValueList<'a>) -> EnumValueList<'a> {
+        fold_enum_value_list(self, node)
+    }
+
+    fn fold_extended_attribute(&mut self, node: ExtendedAttribute<'a>) -> ExtendedAttribute<'a> {
+        fold_extended_attribute(self, node)
+    }
+
+    fn fold_extended_attribute_arg_list(
+        &mut self,
+        node: ExtendedAttributeArgList<'a>,
+    ) -> ExtendedAttributeArgList<'a> {
+        fold_extended_attribute_arg_list(self, node)
+    }
+
+    fn fold_extended_attribute_ident(
+        &mut self,
+        node: ExtendedAttributeIdent<'a>,
+    ) -> ExtendedAttributeIdent<'a> {
+        fold_extended_attribute_ident(self, node)
+    }
+
+    fn fold_extended_attribute_ident_list(
+        &mut self,
+        node: ExtendedAttributeIdentList<'a>,
+    ) -> ExtendedAttributeIdentList<'a> {
+        fold_extended_attribute_ident_list(self, node)
+    }
+
+    fn fold_extended_attribute_list(
+        &mut self,
+        node: ExtendedAttributeList<'a>,
+    ) -> ExtendedAttributeList<'a> {
+        fold_extended_attribute_list(self, node)
+    }
+
+    fn fold_extended_attribute_named_arg_list(
+        &mut self,
+        node: ExtendedAttributeNamedArgList<'a>,
+    ) -> ExtendedAttributeNamedArgList<'a> {
+        fold_extended_attribute_named_arg_list(self, node)
+    }
+
+    fn fold_extended_attribute_no_args(
+        &mut self,
+        node: ExtendedAttributeNoArgs<'a>,
+    ) -> ExtendedAttributeNoArgs<'a> {
+        fold_extended_attribute_no_args(self, node)
+    }
+
+    fn fold_extended_attribute_wild_card(
+        &mut self,
+        node: ExtendedAttributeWildCard<'a>,
+    ) -> ExtendedAttributeWildCard<'a> {
+        fold_extended_attribute_wild_card(self, node)
+    }
+
+    fn fold_float_lit(&mut self, node: FloatLit<'a>) -> FloatLit<'a> {
+        fold_float_lit(self, node)
+    }
+
+    fn fold_float_type(&mut self, node: FloatType) -> FloatType {
+        fold_float_type(self, node)
+    }
+
+    fn fold_float_value_lit(&mut self, node: FloatValueLit<'a>) -> FloatValueLit<'a> {
+        fold_float_value_lit(self, node)
+    }
+
+    fn fold_floating_point_type(&mut self, node: FloatingPointType) -> FloatingPointType {
+        fold_floating_point_type(self, node)
+    }
+
+    fn fold_frozen_array_type(&mut self, node: FrozenArrayType<'a>) -> FrozenArrayType<'a> {
+        fold_frozen_array_type(self, node)
+    }
+
+    fn fold_hex_lit(&mut self, node: HexLit<'a>) -> HexLit<'a> {
+        fold_hex_lit(self, node)
+    }
+
+    fn fold_identifier(&mut self, node: Identifier<'a>) -> Identifier<'a> {
+        fold_identifier(self, node)
+    }
+
+    fn fold_identifier_list(&mut self, node: IdentifierList<'a>) -> IdentifierList<'a> {
+        fold_identifier_list(self, node)
+    }
+
+    fn fold_identifier_or_string(
+        &mut self,
+        node: IdentifierOrString<'a>,
+    ) -> IdentifierOrString<'a> {
+        fold_identifier_or_string(self, node)
+    }
+
+    fn fold_implements_definition(
+        &mut self,
+        node: ImplementsDefinition<'a>,
+    ) -> ImplementsDefinition<'a> {
+        fold_implements_definition(self, node)
+    }
+
+    fn fold_includes_statement_definition(
+        &mut self,
+        node: IncludesStatementDefinition<'a>,
+    ) -> IncludesStatementDefinition<'a> {
+        fold_includes_statement_definition(self, node)
+    }
+
+    fn fold_inheritance(&mut self, node: Inheritance<'a>) -> Inheritance<'a> {
+        fold_inheritance(self, node)
+    }
+
+    fn fold_integer_lit(&mut self, node: IntegerLit<'a>) -> IntegerLit<'a> {
+        fold_integer_lit(self, node)
+    }
+
+    fn fold_integer_type(&mut self, node: IntegerType) -> IntegerType {
+        fold_integer_type(self, node)
+    }
+
+    fn fold_interface_definition(
+        &mut self,
+        node: InterfaceDefinition<'a>,
+    ) -> InterfaceDefinition<'a> {
+        fold_interface_definition(self, node)
+    }
+
+    fn fold_interface_member(&mut self, node: InterfaceMember<'a>) -> InterfaceMember<'a> {
+        fold_interface_member(self, node)
+    }
+
+    fn fold_interface_mixin_definition(
+        &mut self,
+        node: InterfaceMixinDefinition<'a>,
+    ) -> InterfaceMixinDefinition<'a> {
+        fold_interface_mixin_definition(self, node)
+    }
+
+    fn fold_iterable_interface_member(
+        &mut self,
+        node: IterableInterfaceMember<'a>,
+    ) -> IterableInterfaceMember<'a> {
+        fold_iterable_interface_member(self, node)
+    }
+
+    fn fold_long_long_type(&mut self, node: LongLongType) -> LongLongType {
+        fold_long_long_type(self, node)
+    }
+
+    fn fold_long_type(&mut self, node: LongType) -> LongType {
+        fold_long_type(self, node)
+    }
+
+    fn fold_maplike_interface_member(
+        &mut self,
+        node: MaplikeInterfaceMember<'a>,
+    ) -> MaplikeInterfaceMember<'a> {
+        fold_maplike_interface_member(self, node)
+    }
+
+    fn fold_mixin_member(&mut self, node: MixinMember<'a>) -> MixinMember<'a> {
+        fold_mixin_member(self, node)
+    }
+
+    fn fold_namespace_definition(
+        &mut self,
+        node: NamespaceDefinition<'a>,
+    ) -> NamespaceDefinition<'a> {
+        fold_namespace_definition(self, node)
+    }
+
+    fn fold_namespace_member(&mut self, node: NamespaceMember<'a>) -> NamespaceMember<'a> {
+        fold_namespace_member(self, node)
+    }
+
+    fn fold_non_any_type(&mut self, node: NonAnyType<'a>) -> NonAnyType<'a> {
+        fold_non_any_type(self, node)
+    }
+
+    fn fold_observable_array_type(
+        &mut self,
+        node: ObservableArrayType<'a>,
+    ) -> ObservableArrayType<'a> {
+        fold_observable_array_type(self, node)
+    }
+
+    fn fold_oct_lit(&mut self, node: OctLit<'a>) -> OctLit<'a> {
+        fold_oct_lit(self, node)
+    }
+
+    fn fold_operation_interface_member(
+        &mut self,
+        node: OperationInterfaceMember<'a>,
+    ) -> OperationInterfaceMember<'a> {
+        fold_operation_interface_member(self, node)
+    }
+
+    fn fold_operation_mixin_member(
+        &mut self,
+        node: OperationMixinMember<'a>,
+    ) -> OperationMixinMember<'a> {
+        fold_operation_mixin_member(self, node)
+    }
+
+    fn fold_operation_namespace_member(
+        &mut self,
+        node: OperationNamespaceMember<'a>,
+    ) -> OperationNamespaceMember<'a> {
+        fold_operation_namespace_member(self, node)
+    }
+
+    fn fold_partial_dictionary_definition(
+        &mut self,
+        node: PartialDictionaryDefinition<'a>,
+    ) -> PartialDictionaryDefinition<'a> {
+        fold_partial_dictionary_definition(self, node)
+    }
+
+    fn fold_partial_interface_definition(
+        &mut self,
+        node: PartialInterfaceDefinition<'a>,
+    ) -> PartialInterfaceDefinition<'a> {
+        fold_partial_interface_definition(self, node)
+    }
+
+    fn fold_partial_interface_mixin_definition(
+        &mut self,
+        node: PartialInterfaceMixinDefinition<'a>,
+    ) -> PartialInterfaceMixinDefinition<'a> {
+        fold_partial_interface_mixin_definition(self, node)
+    }
+
+    fn fold_partial_namespace_definition(
+        &mut self,
+        node: PartialNamespaceDefinition<'a>,
+    ) -> PartialNamespaceDefinition<'a> {
+        fold_partial_namespace_definition(self, node)
+    }
+
+    fn fold_promise_type(&mut self, node: PromiseType<'a>) -> PromiseType<'a> {
+        fold_promise_type(self, node)
+    }
+
+    fn fold_record_key_type(&mut self, node: RecordKeyType<'a>) -> RecordKeyType<'a> {
+        fold_record_key_type(self, node)
+    }
+
+    fn fold_record_type(&mut self, node: RecordType<'a>) -> RecordType<'a> {
+        fold_record_type(self, node)
+    }
+
+    fn fold_return_type(&mut self, node: ReturnType<'a>) -> ReturnType<'a> {
+        fold_return_type(self, node)
+    }
+
+    fn fold_separate_async_iterable(
+        &mut self,
+        node: SeparateAsyncIterable,
+    ) -> SeparateAsyncIterable {
+        fold_separate_async_iterable(self, node)
+    }
+
+    fn fold_sequence_type(&mut self, node: SequenceType<'a>) -> SequenceType<'a> {
+        fold_sequence_type(self, node)
+    }
+
+    fn fold_setlike_interface_member(
+        &mut self,
+        node: SetlikeInterfaceMember<'a>,
+    ) -> SetlikeInterfaceMember<'a> {
+        fold_setlike_interface_member(self, node)
+    }
+
+    fn fold_short_type(&mut self, node: ShortType) -> ShortType {
+        fold_short_type(self, node)
+    }
+
+    fn fold_single_argument(&mut self, node: SingleArgument<'a>) -> SingleArgument<'a> {
+        fold_single_argument(self, node)
+    }
+
+    fn fold_single_type(&mut self, node: SingleType<'a>) -> SingleType<'a> {
+        fold_single_type(self, node)
+    }
+
+    fn fold_single_typed_async_iterable(
+        &mut self,
+        node: SingleTypedAsyncIterable<'a>,
+    ) -> SingleTypedAsyncIterable<'a> {
+        fold_single_typed_async_iterable(self, node)
+    }
+
+    fn fold_single_typed_iterable(
+        &mut self,
+        node: SingleTypedIterable<'a>,
+    ) -> SingleTypedIterable<'a> {
+        fold_single_typed_iterable(self, node)
+    }
+
+    fn fold_special(&mut self, node: Special) -> Special {
+        fold_special(self, node)
+    }
+
+    fn fold_string_lit(&mut self, node: StringLit<'a>) -> StringLit<'a> {
+        fold_string_lit(self, node)
+    }
+
+    fn fold_stringifier_member(&mut self, node: StringifierMember<'a>) -> StringifierMember<'a> {
+        fold_stringifier_member(self, node)
+    }
+
+    fn fold_stringifier_or_inherit_or_static(
+        &mut self,
+        node: StringifierOrInheritOrStatic,
+    ) -> StringifierOrInheritOrStatic {
+        fold_stringifier_or_inherit_or_static(self, node)
+    }
+
+    fn fold_stringifier_or_static(&mut self, node: StringifierOrStatic) -> StringifierOrStatic {
+        fold_stringifier_or_static(self, node)
+    }
+
+    fn fold_type(&mut self, node: Type<'a>) -> Type<'a> {
+        fold_type(self, node)
+    }
+
+    fn fold_typedef_definition(&mut self, node: TypedefDefinition<'a>) -> TypedefDefinition<'a> {
+        fold_typedef_definition(self, node)
+    }
+
+    fn fold_union_member_type(&mut self, node: UnionMemberType<'a>) -> UnionMemberType<'a> {
+        fold_union_member_type(self, node)
+    }
+
+    fn fold_union_type(&mut self, node: UnionType<'a>) -> UnionType<'a> {
+        fold_union_type(self, node)
+    }
+
+    fn fold_variadic_argument(&mut self, node: VariadicArgument<'a>) -> VariadicArgument<'a> {
+        fold_variadic_argument(self, node)
+    }
+}
+
+pub fn fold_argument<'a, F: Fold<'a> + ?Sized>(f: &mut F, node: Argument<'a>) -> Argument<'a> {
+    match node {
+        Argument::Single(it) => Argument::Single(f.fold_single_argument(it)),
+        Argument::Variadic(it) => Argument::Variadic(f.fold_variadic_argument(it)),
+    }
+}
+
+pub fn fold_argument_list<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ArgumentList<'a>,
+) -> ArgumentList<'a> {
+    Punctuated {
+        list: node
+            .list
+            .into_iter()
+            .map(|it| f.fold_argument(it))
+            .collect(),
+        ..node
+    }
+}
+
+pub fn fold_argument_name<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ArgumentName<'a>,
+) -> ArgumentName<'a> {
+    match node {
+        ArgumentName::Keyword(it) => ArgumentName::Keyword(f.fold_argument_name_keyword(it)),
+        ArgumentName::Identifier(it) => ArgumentName::Identifier(f.fold_identifier(it)),
+    }
+}
+
+pub fn fold_argument_name_keyword<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    node: ArgumentNameKeyword,
+) -> ArgumentNameKeyword {
+    node
+}
+
+pub fn fold_async_iterable_interface_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: AsyncIterableInterfaceMember<'a>,
+) -> AsyncIterableInterfaceMember<'a> {
+    match node {
+        AsyncIterableInterfaceMember::Single(it) => {
+            AsyncIterableInterfaceMember::Single(f.fold_single_typed_async_iterable(it))
+        }
+        AsyncIterableInterfaceMember::Double(it) => {
+            AsyncIterableInterfaceMember::Double(f.fold_double_typed_async_iterable(it))
+        }
+    }
+}
+
+pub fn fold_async_iterable_keyword<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: AsyncIterableKeyword,
+) -> AsyncIterableKeyword {
+    match node {
+        AsyncIterableKeyword::Separate(it) => {
+            AsyncIterableKeyword::Separate(f.fold_separate_async_iterable(it))
+        }
+        node => node,
+    }
+}
+
+pub fn fold_attribute_interface_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: AttributeInterfaceMember<'a>,
+) -> AttributeInterfaceMember<'a> {
+    AttributeInterfaceMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        modifier: node
+            .modifier
+            .map(|it| f.fold_stringifier_or_inherit_or_static(it)),
+        readonly: node.readonly,
+        attribute: node.attribute,
+        type_: f.fold_attributed_type(node.type_),
+        identifier: f.fold_identifier(node.identifier),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_attribute_mixin_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: AttributeMixinMember<'a>,
+) -> AttributeMixinMember<'a> {
+    AttributeMixinMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        stringifier: node.stringifier,
+        readonly: node.readonly,
+        attribute: node.attribute,
+        type_: f.fold_attributed_type(node.type_),
+        identifier: f.fold_identifier(node.identifier),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_attribute_namespace_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: AttributeNamespaceMember<'a>,
+) -> AttributeNamespaceMember<'a> {
+    AttributeNamespaceMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        readonly: node.readonly,
+        attribute: node.attribute,
+        type_: f.fold_attributed_type(node.type_),
+        identifier: f.fold_identifier(node.identifier),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_attributed_non_any_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: AttributedNonAnyType<'a>,
+) -> AttributedNonAnyType<'a> {
+    AttributedNonAnyType {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        type_: f.fold_non_any_type(node.type_),
+    }
+}
+
+pub fn fold_attributed_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: AttributedType<'a>,
+) -> AttributedType<'a> {
+    AttributedType {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        type_: f.fold_type(node.type_),
+    }
+}
+
+pub fn fold_boolean_lit<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: BooleanLit) -> BooleanLit {
+    node
+}
+
+pub fn fold_callback_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: CallbackDefinition<'a>,
+) -> CallbackDefinition<'a> {
+    CallbackDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        callback: node.callback,
+        identifier: f.fold_identifier(node.identifier),
+        assign: node.assign,
+        return_type: f.fold_return_type(node.return_type),
+        arguments: Parenthesized {
+            body: f.fold_argument_list(node.arguments.body),
+            ..node.arguments
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_callback_interface_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: CallbackInterfaceDefinition<'a>,
+) -> CallbackInterfaceDefinition<'a> {
+    CallbackInterfaceDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        callback: node.callback,
+        interface: node.interface,
+        identifier: f.fold_identifier(node.identifier),
+        inheritance: node.inheritance.map(|it| f.fold_inheritance(it)),
+        members: Braced {
+            body: node
+                .members
+                .body
+                .into_iter()
+                .map(|it| f.fold_interface_member(it))
+                .collect(),
+            ..node.members
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_const_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ConstMember<'a>,
+) -> ConstMember<'a> {
+    ConstMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        const_: node.const_,
+        const_type: f.fold_const_type(node.const_type),
+        identifier: f.fold_identifier(node.identifier),
+        assign: node.assign,
+        const_value: f.fold_const_value(node.const_value),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_const_namespace_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ConstNamespaceMember<'a>,
+) -> ConstNamespaceMember<'a> {
+    ConstNamespaceMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        const_: node.const_,
+        const_type: f.fold_const_type(node.const_type),
+        identifier: f.fold_identifier(node.identifier),
+        assign: node.assign,
+        const_value: f.fold_const_value(node.const_value),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_const_type<'a, F: Fold<'a> + ?Sized>(f: &mut F, node: ConstType<'a>) -> ConstType<'a> {
+    match node {
+        ConstType::Integer(it) => ConstType::Integer(MayBeNull {
+            type_: f.fold_integer_type(it.type_),
+            ..it
+        }),
+        ConstType::FloatingPoint(it) => ConstType::FloatingPoint(MayBeNull {
+            type_: f.fold_floating_point_type(it.type_),
+            ..it
+        }),
+        ConstType::Identifier(it) => ConstType::Identifier(MayBeNull {
+            type_: f.fold_identifier(it.type_),
+            ..it
+        }),
+        node => node,
+    }
+}
+
+pub fn fold_const_value<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ConstValue<'a>,
+) -> ConstValue<'a> {
+    match node {
+        ConstValue::Boolean(it) => ConstValue::Boolean(f.fold_boolean_lit(it)),
+        ConstValue::Float(it) => ConstValue::Float(f.fold_float_lit(it)),
+        ConstValue::Integer(it) => ConstValue::Integer(f.fold_integer_lit(it)),
+        node => node,
+    }
+}
+
+pub fn fold_constructor_interface_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ConstructorInterfaceMember<'a>,
+) -> ConstructorInterfaceMember<'a> {
+    ConstructorInterfaceMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        constructor: node.constructor,
+        args: Parenthesized {
+            body: f.fold_argument_list(node.args.body),
+            ..node.args
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_dec_lit<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: DecLit<'a>) -> DecLit<'a> {
+    node
+}
+
+pub fn fold_default<'a, F: Fold<'a> + ?Sized>(f: &mut F, node: Default<'a>) -> Default<'a> {
+    Default {
+        assign: node.assign,
+        value: f.fold_default_value(node.value),
+    }
+}
+
+pub fn fold_default_value<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: DefaultValue<'a>,
+) -> DefaultValue<'a> {
+    match node {
+        DefaultValue::Boolean(it) => DefaultValue::Boolean(f.fold_boolean_lit(it)),
+        DefaultValue::EmptyArray(it) => DefaultValue::EmptyArray(f.fold_empty_array_lit(it)),
+        DefaultValue::EmptyDictionary(it) => {
+            DefaultValue::EmptyDictionary(f.fold_empty_dictionary_lit(it))
+        }
+        DefaultValue::Float(it) => DefaultValue::Float(f.fold_float_lit(it)),
+        DefaultValue::Integer(it) => DefaultValue::Integer(f.fold_integer_lit(it)),
+        DefaultValue::String(it) => DefaultValue::String(f.fold_string_lit(it)),
+        node => node,
+    }
+}
+
+pub fn fold_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: Definition<'a>,
+) -> Definition<'a> {
+    match node {
+        Definition::Callback(it) => Definition::Callback(f.fold_callback_definition(it)),
+        Definition::CallbackInterface(it) => {
+            Definition::CallbackInterface(f.fold_callback_interface_definition(it))
+        }
+        Definition::Interface(it) => Definition::Interface(f.fold_interface_definition(it)),
+        Definition::InterfaceMixin(it) => {
+            Definition::InterfaceMixin(f.fold_interface_mixin_definition(it))
+        }
+        Definition::Namespace(it) => Definition::Namespace(f.fold_namespace_definition(it)),
+        Definition::Dictionary(it) => Definition::Dictionary(f.fold_dictionary_definition(it)),
+        Definition::PartialInterface(it) => {
+            Definition::PartialInterface(f.fold_partial_interface_definition(it))
+        }
+        Definition::PartialInterfaceMixin(it) => {
+            Definition::PartialInterfaceMixin(f.fold_partial_interface_mixin_definition(it))
+        }
+        Definition::PartialDictionary(it) => {
+            Definition::PartialDictionary(f.fold_partial_dictionary_definition(it))
+        }
+        Definition::PartialNamespace(it) => {
+            Definition::PartialNamespace(f.fold_partial_namespace_definition(it))
+        }
+        Definition::Enum(it) => Definition::Enum(f.fold_enum_definition(it)),
+        Definition::Typedef(it) => Definition::Typedef(f.fold_typedef_definition(it)),
+        Definition::IncludesStatement(it) => {
+            Definition::IncludesStatement(f.fold_includes_statement_definition(it))
+        }
+        Definition::Implements(it) => Definition::Implements(f.fold_implements_definition(it)),
+    }
+}
+
+pub fn fold_dictionary_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: DictionaryDefinition<'a>,
+) -> DictionaryDefinition<'a> {
+    DictionaryDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        dictionary: node.dictionary,
+        identifier: f.fold_identifier(node.identifier),
+        inheritance: node.inheritance.map(|it| f.fold_inheritance(it)),
+        members: Braced {
+            body: node
+                .members
+                .body
+                .into_iter()
+                .map(|it| f.fold_dictionary_member(it))
+                .collect(),
+            ..node.members
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_dictionary_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: DictionaryMember<'a>,
+) -> DictionaryMember<'a> {
+    DictionaryMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        required: node.required,
+        type_: f.fold_type(node.type_),
+        identifier: f.fold_identifier(node.identifier),
+        default: node.default.map(|it| f.fold_default(it)),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_double_type<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: DoubleType) -> DoubleType {
+    node
+}
+
+pub fn fold_double_typed_async_iterable<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: DoubleTypedAsyncIterable<'a>,
+) -> DoubleTypedAsyncIterable<'a> {
+    DoubleTypedAsyncIterable {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        async_iterable: f.fold_async_iterable_keyword(node.async_iterable),
+        generics: Generics {
+            body: (
+                f.fold_attributed_type(node.generics.body.0),
+                node.generics.body.1,
+                f.fold_attributed_type(node.generics.body.2),
+            ),
+            ..node.generics
+        },
+        args: node.args.map(|it| Parenthesized {
+            body: f.fold_argument_list(it.body),
+            ..it
+        }),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_double_typed_iterable<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: DoubleTypedIterable<'a>,
+) -> DoubleTypedIterable<'a> {
+    DoubleTypedIterable {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        iterable: node.iterable,
+        generics: Generics {
+            body: (
+                f.fold_attributed_type(node.generics.body.0),
+                node.generics.body.1,
+                f.fold_attributed_type(node.generics.body.2),
+            ),
+            ..node.generics
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_empty_array_lit<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    node: EmptyArrayLit,
+) -> EmptyArrayLit {
+    node
+}
+
+pub fn fold_empty_dictionary_lit<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    node: EmptyDictionaryLit,
+) -> EmptyDictionaryLit {
+    node
+}
+
+pub fn fold_enum_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: EnumDefinition<'a>,
+) -> EnumDefinition<'a> {
+    EnumDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        enum_: node.enum_,
+        identifier: f.fold_identifier(node.identifier),
+        values: Braced {
+            body: f.fold_enum_value_list(node.values.body),
+            ..node.values
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_enum_value_list<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: EnumValueList<'a>,
+) -> EnumValueList<'a> {
+    PunctuatedNonEmpty {
+        list: node
+            .list
+            .into_iter()
+            .map(|it| f.fold_string_lit(it))
+            .collect(),
+        ..node
+    }
+}
+
+pub fn fold_extended_attribute<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ExtendedAttribute<'a>,
+) -> ExtendedAttribute<'a> {
+    match node {
+        ExtendedAttribute::ArgList(it) => {
+            ExtendedAttribute::ArgList(f.fold_extended_attribute_arg_list(it))
+        }
+        ExtendedAttribute::NamedArgList(it) => {
+            ExtendedAttribute::NamedArgList(f.fold_extended_attribute_named_arg_list(it))
+        }
+        ExtendedAttribute::IdentList(it) => {
+            ExtendedAttribute::IdentList(f.fold_extended_attribute_ident_list(it))
+        }
+        ExtendedAttribute::Ident(it) => {
+            ExtendedAttribute::Ident(f.fold_extended_attribute_ident(it))
+        }
+        ExtendedAttribute::Wildcard(it) => {
+            ExtendedAttribute::Wildcard(f.fold_extended_attribute_wild_card(it))
+        }
+        ExtendedAttribute::NoArgs(it) => {
+            ExtendedAttribute::NoArgs(f.fold_extended_attribute_no_args(it))
+        }
+    }
+}
+
+pub fn fold_extended_attribute_arg_list<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ExtendedAttributeArgList<'a>,
+) -> ExtendedAttributeArgList<'a> {
+    ExtendedAttributeArgList {
+        identifier: f.fold_identifier(node.identifier),
+        args: Parenthesized {
+            body: f.fold_argument_list(node.args.body),
+            ..node.args
+        },
+    }
+}
+
+pub fn fold_extended_attribute_ident<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ExtendedAttributeIdent<'a>,
+) -> ExtendedAttributeIdent<'a> {
+    ExtendedAttributeIdent {
+        lhs_identifier: f.fold_identifier(node.lhs_identifier),
+        assign: node.assign,
+        rhs: f.fold_identifier_or_string(node.rhs),
+    }
+}
+
+pub fn fold_extended_attribute_ident_list<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ExtendedAttributeIdentList<'a>,
+) -> ExtendedAttributeIdentList<'a> {
+    ExtendedAttributeIdentList {
+        identifier: f.fold_identifier(node.identifier),
+        assign: node.assign,
+        list: Parenthesized {
+            body: f.fold_identifier_list(node.list.body),
+            ..node.list
+        },
+    }
+}
+
+pub fn fold_extended_attribute_list<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ExtendedAttributeList<'a>,
+) -> ExtendedAttributeList<'a> {
+    Bracketed {
+        body: Punctuated {
+            list: node
+                .body
+                .list
+                .into_iter()
+                .map(|it| f.fold_extended_attribute(it))
+                .collect(),
+            ..node.body
+        },
+        ..node
+    }
+}
+
+pub fn fold_extended_attribute_named_arg_list<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ExtendedAttributeNamedArgList<'a>,
+) -> ExtendedAttributeNamedArgList<'a> {
+    ExtendedAttributeNamedArgList {
+        lhs_identifier: f.fold_identifier(node.lhs_identifier),
+        assign: node.assign,
+        rhs_identifier: f.fold_identifier(node.rhs_identifier),
+        args: Parenthesized {
+            body: f.fold_argument_list(node.args.body),
+            ..node.args
+        },
+    }
+}
+
+pub fn fold_extended_attribute_no_args<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ExtendedAttributeNoArgs<'a>,
+) -> ExtendedAttributeNoArgs<'a> {
+    ExtendedAttributeNoArgs(f.fold_identifier(node.0))
+}
+
+pub fn fold_extended_attribute_wild_card<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ExtendedAttributeWildCard<'a>,
+) -> ExtendedAttributeWildCard<'a> {
+    ExtendedAttributeWildCard {
+        lhs_identifier: f.fold_identifier(node.lhs_identifier),
+        assign: node.assign,
+        rhs: node.rhs,
+    }
+}
+
+pub fn fold_float_lit<'a, F: Fold<'a> + ?Sized>(f: &mut F, node: FloatLit<'a>) -> FloatLit<'a> {
+    match node {
+        FloatLit::Value(it) => FloatLit::Value(f.fold_float_value_lit(it)),
+        node => node,
+    }
+}
+
+pub fn fold_float_type<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: FloatType) -> FloatType {
+    node
+}
+
+pub fn fold_float_value_lit<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    node: FloatValueLit<'a>,
+) -> FloatValueLit<'a> {
+    node
+}
+
+pub fn fold_floating_point_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: FloatingPointType,
+) -> FloatingPointType {
+    match node {
+        FloatingPointType::Float(it) => FloatingPointType::Float(f.fold_float_type(it)),
+        FloatingPointType::Double(it) => FloatingPointType::Double(f.fold_double_type(it)),
+    }
+}
+
+pub fn fold_frozen_array_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: FrozenArrayType<'a>,
+) -> FrozenArrayType<'a> {
+    FrozenArrayType {
+        frozen_array: node.frozen_array,
+        generics: Generics {
+            body: Box::new(f.fold_type(*node.generics.body)),
+            ..node.generics
+        },
+    }
+}
+
+pub fn fold_hex_lit<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: HexLit<'a>) -> HexLit<'a> {
+    node
+}
+
+pub fn fold_identifier<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    node: Identifier<'a>,
+) -> Identifier<'a> {
+    node
+}
+
+pub fn fold_identifier_list<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: IdentifierList<'a>,
+) -> IdentifierList<'a> {
+    Punctuated {
+        list: node
+            .list
+            .into_iter()
+            .map(|it| f.fold_identifier(it))
+            .collect(),
+        ..node
+    }
+}
+
+pub fn fold_identifier_or_string<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: IdentifierOrString<'a>,
+) -> IdentifierOrString<'a> {
+    match node {
+        IdentifierOrString::Identifier(it) => IdentifierOrString::Identifier(f.fold_identifier(it)),
+        IdentifierOrString::String(it) => IdentifierOrString::String(f.fold_string_lit(it)),
+    }
+}
+
+pub fn fold_implements_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ImplementsDefinition<'a>,
+) -> ImplementsDefinition<'a> {
+    ImplementsDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        lhs_identifier: f.fold_identifier(node.lhs_identifier),
+        includes: node.includes,
+        rhs_identifier: f.fold_identifier(node.rhs_identifier),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_includes_statement_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: IncludesStatementDefinition<'a>,
+) -> IncludesStatementDefinition<'a> {
+    IncludesStatementDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        lhs_identifier: f.fold_identifier(node.lhs_identifier),
+        includes: node.includes,
+        rhs_identifier: f.fold_identifier(node.rhs_identifier),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_inheritance<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: Inheritance<'a>,
+) -> Inheritance<'a> {
+    Inheritance {
+        colon: node.colon,
+        identifier: f.fold_identifier(node.identifier),
+    }
+}
+
+pub fn fold_integer_lit<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: IntegerLit<'a>,
+) -> IntegerLit<'a> {
+    match node {
+        IntegerLit::Dec(it) => IntegerLit::Dec(f.fold_dec_lit(it)),
+        IntegerLit::Hex(it) => IntegerLit::Hex(f.fold_hex_lit(it)),
+        IntegerLit::Oct(it) => IntegerLit::Oct(f.fold_oct_lit(it)),
+    }
+}
+
+pub fn fold_integer_type<'a, F: Fold<'a> + ?Sized>(f: &mut F, node: IntegerType) -> IntegerType {
+    match node {
+        IntegerType::LongLong(it) => IntegerType::LongLong(f.fold_long_long_type(it)),
+        IntegerType::Long(it) => IntegerType::Long(f.fold_long_type(it)),
+        IntegerType::Short(it) => IntegerType::Short(f.fold_short_type(it)),
+    }
+}
+
+pub fn fold_interface_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: InterfaceDefinition<'a>,
+) -> InterfaceDefinition<'a> {
+    InterfaceDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        interface: node.interface,
+        identifier: f.fold_identifier(node.identifier),
+        inheritance: node.inheritance.map(|it| f.fold_inheritance(it)),
+        members: Braced {
+            body: node
+                .members
+                .body
+                .into_iter()
+                .map(|it| f.fold_interface_member(it))
+                .collect(),
+            ..node.members
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_interface_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: InterfaceMember<'a>,
+) -> InterfaceMember<'a> {
+    match node {
+        InterfaceMember::Const(it) => InterfaceMember::Const(f.fold_const_member(it)),
+        InterfaceMember::Attribute(it) => {
+            InterfaceMember::Attribute(f.fold_attribute_interface_member(it))
+        }
+        InterfaceMember::Constructor(it) => {
+            InterfaceMember::Constructor(f.fold_constructor_interface_member(it))
+        }
+        InterfaceMember::Operation(it) => {
+            InterfaceMember::Operation(f.fold_operation_interface_member(it))
+        }
+        InterfaceMember::Iterable(it) => {
+            InterfaceMember::Iterable(f.fold_iterable_interface_member(it))
+        }
+        InterfaceMember::AsyncIterable(it) => {
+            InterfaceMember::AsyncIterable(f.fold_async_iterable_interface_member(it))
+        }
+        InterfaceMember::Maplike(it) => {
+            InterfaceMember::Maplike(f.fold_maplike_interface_member(it))
+        }
+        InterfaceMember::Setlike(it) => {
+            InterfaceMember::Setlike(f.fold_setlike_interface_member(it))
+        }
+        InterfaceMember::Stringifier(it) => {
+            InterfaceMember::Stringifier(f.fold_stringifier_member(it))
+        }
+    }
+}
+
+pub fn fold_interface_mixin_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: InterfaceMixinDefinition<'a>,
+) -> InterfaceMixinDefinition<'a> {
+    InterfaceMixinDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        interface: node.interface,
+        mixin: node.mixin,
+        identifier: f.fold_identifier(node.identifier),
+        members: Braced {
+            body: node
+                .members
+                .body
+                .into_iter()
+                .map(|it| f.fold_mixin_member(it))
+                .collect(),
+            ..node.members
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_iterable_interface_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: IterableInterfaceMember<'a>,
+) -> IterableInterfaceMember<'a> {
+    match node {
+        IterableInterfaceMember::Single(it) => {
+            IterableInterfaceMember::Single(f.fold_single_typed_iterable(it))
+        }
+        IterableInterfaceMember::Double(it) => {
+            IterableInterfaceMember::Double(f.fold_double_typed_iterable(it))
+        }
+    }
+}
+
+pub fn fold_long_long_type<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    node: LongLongType,
+) -> LongLongType {
+    node
+}
+
+pub fn fold_long_type<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: LongType) -> LongType {
+    node
+}
+
+pub fn fold_maplike_interface_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: MaplikeInterfaceMember<'a>,
+) -> MaplikeInterfaceMember<'a> {
+    MaplikeInterfaceMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        readonly: node.readonly,
+        maplike: node.maplike,
+        generics: Generics {
+            body: (
+                f.fold_attributed_type(node.generics.body.0),
+                node.generics.body.1,
+                f.fold_attributed_type(node.generics.body.2),
+            ),
+            ..node.generics
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_mixin_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: MixinMember<'a>,
+) -> MixinMember<'a> {
+    match node {
+        MixinMember::Const(it) => MixinMember::Const(f.fold_const_member(it)),
+        MixinMember::Operation(it) => MixinMember::Operation(f.fold_operation_mixin_member(it)),
+        MixinMember::Attribute(it) => MixinMember::Attribute(f.fold_attribute_mixin_member(it)),
+        MixinMember::Stringifier(it) => MixinMember::Stringifier(f.fold_stringifier_member(it)),
+    }
+}
+
+pub fn fold_namespace_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: NamespaceDefinition<'a>,
+) -> NamespaceDefinition<'a> {
+    NamespaceDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        namespace: node.namespace,
+        identifier: f.fold_identifier(node.identifier),
+        members: Braced {
+            body: node
+                .members
+                .body
+                .into_iter()
+                .map(|it| f.fold_namespace_member(it))
+                .collect(),
+            ..node.members
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_namespace_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: NamespaceMember<'a>,
+) -> NamespaceMember<'a> {
+    match node {
+        NamespaceMember::Const(it) => NamespaceMember::Const(f.fold_const_namespace_member(it)),
+        NamespaceMember::Operation(it) => {
+            NamespaceMember::Operation(f.fold_operation_namespace_member(it))
+        }
+        NamespaceMember::Attribute(it) => {
+            NamespaceMember::Attribute(f.fold_attribute_namespace_member(it))
+        }
+    }
+}
+
+pub fn fold_non_any_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: NonAnyType<'a>,
+) -> NonAnyType<'a> {
+    match node {
+        NonAnyType::Promise(it) => NonAnyType::Promise(f.fold_promise_type(it)),
+        NonAnyType::Integer(it) => NonAnyType::Integer(MayBeNull {
+            type_: f.fold_integer_type(it.type_),
+            ..it
+        }),
+        NonAnyType::FloatingPoint(it) => NonAnyType::FloatingPoint(MayBeNull {
+            type_: f.fold_floating_point_type(it.type_),
+            ..it
+        }),
+        NonAnyType::Sequence(it) => NonAnyType::Sequence(MayBeNull {
+            type_: f.fold_sequence_type(it.type_),
+            ..it
+        }),
+        NonAnyType::FrozenArrayType(it) => NonAnyType::FrozenArrayType(MayBeNull {
+            type_: f.fold_frozen_array_type(it.type_),
+            ..it
+        }),
+        NonAnyType::ObservableArrayType(it) => NonAnyType::ObservableArrayType(MayBeNull {
+            type_: f.fold_observable_array_type(it.type_),
+            ..it
+        }),
+        NonAnyType::RecordType(it) => NonAnyType::RecordType(MayBeNull {
+            type_: f.fold_record_type(it.type_),
+            ..it
+        }),
+        NonAnyType::Identifier(it) => NonAnyType::Identifier(MayBeNull {
+            type_: f.fold_identifier(it.type_),
+            ..it
+        }),
+        node => node,
+    }
+}
+
+pub fn fold_observable_array_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ObservableArrayType<'a>,
+) -> ObservableArrayType<'a> {
+    ObservableArrayType {
+        observable_array: node.observable_array,
+        generics: Generics {
+            body: Box::new(f.fold_type(*node.generics.body)),
+            ..node.generics
+        },
+    }
+}
+
+pub fn fold_oct_lit<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: OctLit<'a>) -> OctLit<'a> {
+    node
+}
+
+pub fn fold_operation_interface_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: OperationInterfaceMember<'a>,
+) -> OperationInterfaceMember<'a> {
+    OperationInterfaceMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        modifier: node.modifier.map(|it| f.fold_stringifier_or_static(it)),
+        special: node.special.map(|it| f.fold_special(it)),
+        return_type: f.fold_return_type(node.return_type),
+        identifier: node.identifier.map(|it| f.fold_identifier(it)),
+        args: Parenthesized {
+            body: f.fold_argument_list(node.args.body),
+            ..node.args
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_operation_mixin_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: OperationMixinMember<'a>,
+) -> OperationMixinMember<'a> {
+    OperationMixinMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        stringifier: node.stringifier,
+        return_type: f.fold_return_type(node.return_type),
+        identifier: node.identifier.map(|it| f.fold_identifier(it)),
+        args: Parenthesized {
+            body: f.fold_argument_list(node.args.body),
+            ..node.args
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_operation_namespace_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: OperationNamespaceMember<'a>,
+) -> OperationNamespaceMember<'a> {
+    OperationNamespaceMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        return_type: f.fold_return_type(node.return_type),
+        identifier: node.identifier.map(|it| f.fold_identifier(it)),
+        args: Parenthesized {
+            body: f.fold_argument_list(node.args.body),
+            ..node.args
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_partial_dictionary_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: PartialDictionaryDefinition<'a>,
+) -> PartialDictionaryDefinition<'a> {
+    PartialDictionaryDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        partial: node.partial,
+        dictionary: node.dictionary,
+        identifier: f.fold_identifier(node.identifier),
+        members: Braced {
+            body: node
+                .members
+                .body
+                .into_iter()
+                .map(|it| f.fold_dictionary_member(it))
+                .collect(),
+            ..node.members
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_partial_interface_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: PartialInterfaceDefinition<'a>,
+) -> PartialInterfaceDefinition<'a> {
+    PartialInterfaceDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        partial: node.partial,
+        interface: node.interface,
+        identifier: f.fold_identifier(node.identifier),
+        members: Braced {
+            body: node
+                .members
+                .body
+                .into_iter()
+                .map(|it| f.fold_interface_member(it))
+                .collect(),
+            ..node.members
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_partial_interface_mixin_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: PartialInterfaceMixinDefinition<'a>,
+) -> PartialInterfaceMixinDefinition<'a> {
+    PartialInterfaceMixinDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        partial: node.partial,
+        interface: node.interface,
+        mixin: node.mixin,
+        identifier: f.fold_identifier(node.identifier),
+        members: Braced {
+            body: node
+                .members
+                .body
+                .into_iter()
+                .map(|it| f.fold_mixin_member(it))
+                .collect(),
+            ..node.members
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_partial_namespace_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: PartialNamespaceDefinition<'a>,
+) -> PartialNamespaceDefinition<'a> {
+    PartialNamespaceDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        partial: node.partial,
+        namespace: node.namespace,
+        identifier: f.fold_identifier(node.identifier),
+        members: Braced {
+            body: node
+                .members
+                .body
+                .into_iter()
+                .map(|it| f.fold_namespace_member(it))
+                .collect(),
+            ..node.members
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_promise_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: PromiseType<'a>,
+) -> PromiseType<'a> {
+    PromiseType {
+        promise: node.promise,
+        generics: Generics {
+            body: Box::new(f.fold_return_type(*node.generics.body)),
+            ..node.generics
+        },
+    }
+}
+
+pub fn fold_record_key_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: RecordKeyType<'a>,
+) -> RecordKeyType<'a> {
+    match node {
+        RecordKeyType::NonAny(it) => RecordKeyType::NonAny(f.fold_non_any_type(it)),
+        node => node,
+    }
+}
+
+pub fn fold_record_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: RecordType<'a>,
+) -> RecordType<'a> {
+    RecordType {
+        record: node.record,
+        generics: Generics {
+            body: (
+                Box::new(f.fold_record_key_type(*node.generics.body.0)),
+                node.generics.body.1,
+                Box::new(f.fold_type(*node.generics.body.2)),
+            ),
+            ..node.generics
+        },
+    }
+}
+
+pub fn fold_return_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: ReturnType<'a>,
+) -> ReturnType<'a> {
+    match node {
+        ReturnType::Type(it) => ReturnType::Type(f.fold_type(it)),
+        node => node,
+    }
+}
+
+pub fn fold_separate_async_iterable<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    node: SeparateAsyncIterable,
+) -> SeparateAsyncIterable {
+    node
+}
+
+pub fn fold_sequence_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: SequenceType<'a>,
+) -> SequenceType<'a> {
+    SequenceType {
+        sequence: node.sequence,
+        generics: Generics {
+            body: Box::new(f.fold_type(*node.generics.body)),
+            ..node.generics
+        },
+    }
+}
+
+pub fn fold_setlike_interface_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: SetlikeInterfaceMember<'a>,
+) -> SetlikeInterfaceMember<'a> {
+    SetlikeInterfaceMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        readonly: node.readonly,
+        setlike: node.setlike,
+        generics: Generics {
+            body: f.fold_attributed_type(node.generics.body),
+            ..node.generics
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_short_type<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: ShortType) -> ShortType {
+    node
+}
+
+pub fn fold_single_argument<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: SingleArgument<'a>,
+) -> SingleArgument<'a> {
+    SingleArgument {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        optional: node.optional,
+        type_: f.fold_attributed_type(node.type_),
+        identifier: f.fold_argument_name(node.identifier),
+        default: node.default.map(|it| f.fold_default(it)),
+    }
+}
+
+pub fn fold_single_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: SingleType<'a>,
+) -> SingleType<'a> {
+    match node {
+        SingleType::NonAny(it) => SingleType::NonAny(f.fold_non_any_type(it)),
+        node => node,
+    }
+}
+
+pub fn fold_single_typed_async_iterable<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: SingleTypedAsyncIterable<'a>,
+) -> SingleTypedAsyncIterable<'a> {
+    SingleTypedAsyncIterable {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        async_iterable: f.fold_async_iterable_keyword(node.async_iterable),
+        generics: Generics {
+            body: f.fold_attributed_type(node.generics.body),
+            ..node.generics
+        },
+        args: node.args.map(|it| Parenthesized {
+            body: f.fold_argument_list(it.body),
+            ..it
+        }),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_single_typed_iterable<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: SingleTypedIterable<'a>,
+) -> SingleTypedIterable<'a> {
+    SingleTypedIterable {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        iterable: node.iterable,
+        generics: Generics {
+            body: f.fold_attributed_type(node.generics.body),
+            ..node.generics
+        },
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_special<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: Special) -> Special {
+    node
+}
+
+pub fn fold_string_lit<'a, F: Fold<'a> + ?Sized>(_f: &mut F, node: StringLit<'a>) -> StringLit<'a> {
+    node
+}
+
+pub fn fold_stringifier_member<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: StringifierMember<'a>,
+) -> StringifierMember<'a> {
+    StringifierMember {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        stringifier: node.stringifier,
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_stringifier_or_inherit_or_static<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    node: StringifierOrInheritOrStatic,
+) -> StringifierOrInheritOrStatic {
+    node
+}
+
+pub fn fold_stringifier_or_static<'a, F: Fold<'a> + ?Sized>(
+    _f: &mut F,
+    node: StringifierOrStatic,
+) -> StringifierOrStatic {
+    node
+}
+
+pub fn fold_type<'a, F: Fold<'a> + ?Sized>(f: &mut F, node: Type<'a>) -> Type<'a> {
+    match node {
+        Type::Single(it) => Type::Single(f.fold_single_type(it)),
+        Type::Union(it) => Type::Union(MayBeNull {
+            type_: f.fold_union_type(it.type_),
+            ..it
+        }),
+    }
+}
+
+pub fn fold_typedef_definition<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: TypedefDefinition<'a>,
+) -> TypedefDefinition<'a> {
+    TypedefDefinition {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        typedef: node.typedef,
+        type_: f.fold_attributed_type(node.type_),
+        identifier: f.fold_identifier(node.identifier),
+        semi_colon: node.semi_colon,
+    }
+}
+
+pub fn fold_union_member_type<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: UnionMemberType<'a>,
+) -> UnionMemberType<'a> {
+    match node {
+        UnionMemberType::Single(it) => UnionMemberType::Single(f.fold_attributed_non_any_type(it)),
+        UnionMemberType::Union(it) => UnionMemberType::Union(MayBeNull {
+            type_: f.fold_union_type(it.type_),
+            ..it
+        }),
+    }
+}
+
+pub fn fold_union_type<'a, F: Fold<'a> + ?Sized>(f: &mut F, node: UnionType<'a>) -> UnionType<'a> {
+    Parenthesized {
+        body: Punctuated {
+            list: node
+                .body
+                .list
+                .into_iter()
+                .map(|it| f.fold_union_member_type(it))
+                .collect(),
+            ..node.body
+        },
+        ..node
+    }
+}
+
+pub fn fold_variadic_argument<'a, F: Fold<'a> + ?Sized>(
+    f: &mut F,
+    node: VariadicArgument<'a>,
+) -> VariadicArgument<'a> {
+    VariadicArgument {
+        attributes: node.attributes.map(|it| f.fold_extended_attribute_list(it)),
+        type_: f.fold_type(node.type_),
+        ellipsis: node.ellipsis,
+        identifier: f.fold_argument_name(node.identifier),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::print::ToWebIdl;
+
+    struct Unsigned;
+
+    impl<'a> Fold<'a> for Unsigned {
+        fn fold_long_type(&mut self, node: LongType) -> LongType {
+            LongType {
+                unsigned: Some(term!(unsigned)),
+                ..node
+            }
+        }
+    }
+
+    #[test]
+    fn should_fold_every_node() {
+        let parsed = crate::parse(
+            "interface Foo {
+                const long a = 1;
+                attribute record<DOMString, sequence<long?>> b;
+                undefined c(optional long d = 0, long... e);
+            };",
+        )
+        .unwrap();
+        let folded: Vec<_> = parsed
+            .into_iter()
+            .map(|definition| Unsigned.fold_definition(definition))
+            .collect();
+        assert_eq!(
+            folded.to_webidl(),
+            "interface Foo {
+    const unsigned long a = 1;
+    attribute record<DOMString, sequence<unsigned long?>> b;
+    undefined c(optional unsigned long d = 0, unsigned long... e);
+};"
+        );
+    }
+
+    struct FirstAttribute;
+
+    impl<'a> Fold<'a> for FirstAttribute {
+        fn fold_extended_attribute_list(
+            &mut self,
+            mut node: ExtendedAttributeList<'a>,
+        ) -> ExtendedAttributeList<'a> {
+            node.body.list.truncate(1);
+            node
+        }
+    }
+
+    #[test]
+    fn should_fold_type_aliases() {
+        let parsed = crate::parse("[A, B] interface Foo { [C, D] attribute long x; };").unwrap();
+        let folded: Vec<_> = parsed
+            .into_iter()
+            .map(|definition| FirstAttribute.fold_definition(definition))
+            .collect();
+        assert_eq!(
+            folded.to_webidl(),
+            "[A]\ninterface Foo {\n    [C] attribute long x;\n};"
+        );
+    }
+}
@@ -75,20 +75,20 @@ ast_types! {
                 semi_colon: term!(;),
             }),
         }),
-        /// Parses an async iterable declaration `[attributes]? async (iterable<attributedtype> | iterable<attributedtype, attributedtype>) (( args ))? ;`
+        /// Parses an async iterable declaration `[attributes]? (async iterable|async_iterable)(<attributedtype> | <attributedtype, attributedtype>) (( args ))? ;`
         AsyncIterable(enum AsyncIterableInterfaceMember<'a> {
-            /// Parses an async iterable declaration `[attributes]? async iterable<attributedtype> (( args ))? ;`
+            /// Parses an async iterable declaration `[attributes]? (async iterable|async_iterable)<attributedtype> (( args ))? ;`
             Single(struct SingleTypedAsyncIterable<'a> {
                 attributes: Option<ExtendedAttributeList<'a>>,
-                async_iterable: (term!(async), term!(iterable)),
+                async_iterable: AsyncIterableKeyword,
                 generics: Generics<AttributedType<'a>>,
                 args: Option<Parenthesized<ArgumentList<'a>>>,
                 semi_colon: term!(;),
             }),
-            /// Parses an async iterable declaration `[attributes]? async iterable<attributedtype, attributedtype> (( args ))? ;`
+            /// Parses an async iterable declaration `[attributes]? (async iterable|async_iterable)<attributedtype, attributedtype> (( args ))? ;`
             Double(struct DoubleTypedAsyncIterable<'a> {
                 attributes: Option<ExtendedAttributeList<'a>>,
-                async_iterable: (term!(async), term!(iterable)),
+                async_iterable: AsyncIterableKeyword,
                 generics: Generics<(AttributedType<'a>, term!(,), AttributedType<'a>)>,
                 args: Option<Parenthesized<ArgumentList<'a>>>,
                 semi_colon: term!(;),
@@ -141,6 +141,18 @@ ast_types! {
         Stringifier(term!(stringifier)),
         Static(term!(static)),
     }
+
+    /// Parses `async iterable|async_iterable`, keeping the spelling used
+    #[derive(Copy)]
+    enum AsyncIterableKeyword {
+        /// The older spelling, `async iterable`
+        #[derive(Copy, Default)]
+        Separate(struct SeparateAsyncIterable {
+            async_: term!(async),
+            iterable: term!(iterable),
+        }),
+        Joined(term!(async_iterable)),
+    }
 }
 
 #[cfg(test)]
@@ -225,6 +237,19 @@ mod test {
         args.is_some();
     });
 
+    test!(should_parse_joined_async_iterable { "async_iterable<long, long>(long a);" =>
+        "";
+        DoubleTypedAsyncIterable;
+        async_iterable == AsyncIterableKeyword::Joined(term!(async_iterable));
+        args.is_some();
+    });
+
+    test!(should_parse_separate_async_iterable { "async iterable<long>;" =>
+        "";
+        SingleTypedAsyncIterable;
+        async_iterable == AsyncIterableKeyword::Separate(SeparateAsyncIterable::default());
+    });
+
     test!(should_parse_constructor_interface_member { "constructor(long a);" =>
         "";
         ConstructorInterfaceMember;
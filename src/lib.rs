@@ -16,6 +16,22 @@
 //! println!("{:?}", parsed);
 //! ```
 //!
+//! ### Serde
+//!
+//! With the `serde` feature, every node implements `Serialize` and
+//! `Deserialize`. The serialized shape follows the Rust types:
+//!
+//! - structs are maps of their fields, named as in Rust (`type_`, `const_`)
+//! - enums are externally tagged, `{ "Interface": { ... } }`
+//! - keywords and punctuation are their text, `"interface"`, `";"`
+//! - identifiers and literals are their value as written, `"Foo"`, `"0x1F"`,
+//!   `true`, identifiers without their leading underscore
+//! - `Punctuated` lists are sequences of their items
+//! - absent optional nodes are `null`
+//!
+//! Spans are not serialized and are empty once deserialized. Strings are
+//! borrowed from the deserialized input, so it must not escape them.
+//!
 //! Note:
 //! This parser follows the grammar given at [WebIDL](https://heycam.github.io/webidl).
 //!
@@ -24,28 +40,19 @@
 // need a higher recusion limit for macros
 #![recursion_limit = "128"]
 
-#[macro_use(
-    alt,
-    cond,
-    do_parse,
-    map,
-    many0,
-    opt,
-    recognize,
-    separated_list,
-    separated_nonempty_list,
-    terminated
-)]
+#[macro_use(alt, cond, do_parse, map, many0, opt, pair, recognize)]
 extern crate nom;
 
 use self::argument::ArgumentList;
 use self::attribute::ExtendedAttributeList;
 use self::common::{Braced, Identifier, Parenthesized, PunctuatedNonEmpty};
 use self::dictionary::DictionaryMembers;
+pub use self::error::Error;
 use self::interface::{Inheritance, InterfaceMembers};
 use self::literal::StringLit;
 use self::mixin::MixinMembers;
 use self::namespace::NamespaceMembers;
+pub use self::span::{LineColumn, Span, Spanned};
 use self::types::{AttributedType, ReturnType};
 pub use nom::{error::ErrorKind, Err, IResult};
 
@@ -59,14 +66,32 @@ pub mod argument;
 pub mod attribute;
 pub mod common;
 pub mod dictionary;
+pub mod doc;
+pub mod error;
+pub mod fold;
 pub mod interface;
 pub mod literal;
+pub mod lossless;
 pub mod mixin;
+pub mod model;
 pub mod namespace;
+pub mod overload;
+pub mod print;
+pub mod resolve;
+pub mod span;
+pub mod token;
 pub mod types;
+pub mod validate;
+pub mod visit;
+pub mod visit_mut;
+#[cfg(feature = "webidl2")]
+pub mod webidl2;
 
 /// A convenient parse function
 ///
+/// Fails with an [`Error`](error/struct.Error.html) if any part of `raw` is not
+/// a valid definition.
+///
 /// ### Example
 ///
 /// ```
@@ -80,15 +105,68 @@ pub mod types;
 ///
 /// println!("{:?}", parsed);
 /// ```
-pub fn parse(raw: &str) -> Result<Definitions<'_>, Err<(&str, ErrorKind)>> {
-    let (remaining, parsed) = Definitions::parse(raw)?;
-    assert!(
-        remaining.is_empty(),
-        "There is redundant raw data after parsing"
-    );
+pub fn parse(raw: &str) -> Result<Definitions<'_>, Error> {
+    let (result, expected) = error::tracking(|| Definitions::parse(raw));
+    let (remaining, parsed) = result.map_err(|err| Error::from_nom(raw, err, &expected))?;
+    let remaining = whitespace::sp(remaining).map_or(remaining, |(rest, _)| rest);
+    if !remaining.is_empty() {
+        return Err(Error::new(raw, remaining, &expected));
+    }
     Ok(parsed)
 }
 
+/// Parses as many definitions as possible, collecting an error for each one
+/// which is not valid instead of stopping at the first
+///
+/// After a definition fails to parse, parsing resumes past the next `;` which
+/// is not nested in braces, so every mistake in `raw` is reported in one run.
+/// The [`span`](error/struct.Error.html#structfield.span) of each error covers
+/// the input which was skipped.
+///
+/// ### Example
+///
+/// ```
+/// extern crate weedle;
+///
+/// let (parsed, errors) = weedle::parse_with_recovery("
+///     interface Window {
+///         readonly attribute Storage sessionStorage
+///     };
+///     typedef long Short;
+///     enum Empty {};
+/// ");
+///
+/// assert_eq!(parsed.len(), 1);
+/// assert_eq!(errors.len(), 2);
+/// ```
+pub fn parse_with_recovery(raw: &str) -> (Definitions<'_>, Vec<Error>) {
+    let mut definitions = Vec::new();
+    let mut errors = Vec::new();
+    let mut input = raw;
+
+    loop {
+        let (result, expected) = error::tracking(|| Definition::parse(input));
+        match result {
+            Ok((rest, definition)) => {
+                definitions.push(definition);
+                input = rest;
+            }
+            Err(_) => {
+                let rest = whitespace::sp(input).map_or(input, |(rest, _)| rest);
+                if rest.is_empty() {
+                    break;
+                }
+                let mut error = Error::new(raw, rest, &expected);
+                input = error::skip_definition(rest);
+                error.span = Span::new(rest, input);
+                errors.push(error);
+            }
+        }
+    }
+
+    (definitions, errors)
+}
+
 pub trait Parse<'a>: Sized {
     fn parse(input: &'a str) -> IResult<&'a str, Self>;
 }
@@ -403,6 +481,12 @@ mod test {
         CallbackDefinition;
     });
 
+    test!(should_parse_with_line_comment_at_end_of_input {
+        "callback AsyncOperationCallback = undefined (DOMString status); // End" =>
+        "";
+        CallbackDefinition;
+    });
+
     test!(should_parse_with_block_comments { "
         /* This is a comment */
         callback AsyncOperationCallback = undefined (DOMString status);
@@ -411,6 +495,124 @@ mod test {
         CallbackDefinition;
     });
 
+    #[test]
+    fn should_parse_input_without_definitions() {
+        for input in &[
+            "",
+            "\n  \n",
+            "// Nothing\n",
+            "/* Nothing */ // Still nothing",
+        ] {
+            assert_eq!(parse(input).unwrap(), vec![]);
+        }
+    }
+
+    #[test]
+    fn should_parse_escaped_keywords() {
+        let parsed = parse(
+            "
+            dictionary _dictionary { _required _required; };
+            interface _interface : _partial {
+                attribute _long _attribute;
+                _getter _setter(_optional _async, optional _any _callback);
+                const _long _const = 1;
+            };
+            callback _callback = _undefined (_long... _long);
+            _interface includes _mixin;
+        ",
+        )
+        .unwrap();
+        let identifiers = match &parsed[..] {
+            [Definition::Dictionary(dictionary), Definition::Interface(interface), Definition::Callback(callback), Definition::IncludesStatement(includes)] =>
+            {
+                vec![
+                    dictionary.identifier,
+                    dictionary.members.body[0].identifier,
+                    interface.identifier,
+                    interface.inheritance.unwrap().identifier,
+                    callback.identifier,
+                    includes.rhs_identifier,
+                ]
+            }
+            _ => panic!("unexpected definitions: {:?}", parsed),
+        };
+        let names: Vec<_> = identifiers.iter().map(|it| it.0).collect();
+        assert_eq!(
+            names,
+            [
+                "dictionary",
+                "required",
+                "interface",
+                "partial",
+                "callback",
+                "mixin"
+            ]
+        );
+        assert!(identifiers.iter().all(|it| it.is_escaped()));
+    }
+
+    #[test]
+    fn should_error_on_trailing_input() {
+        let input = "
+            interface Foo {};
+            dictionary Bar {
+                long baz
+            };
+            enum Qux { \"a\" };
+        ";
+        let error = parse(input).unwrap_err();
+
+        assert_eq!(error.definition, Some(error::DefinitionKind::Dictionary));
+        assert_eq!(error.stopped_at.line, 3);
+        assert_eq!(error.stopped_at.column, 13);
+        assert_eq!(error.location.line, 5);
+        assert_eq!(error.expected, vec!["`=`", "`;`"]);
+    }
+
+    #[test]
+    fn should_error_on_unknown_definition() {
+        let error = parse("typedef long Foo;\nFoo bar;").unwrap_err();
+
+        assert_eq!(error.definition, None);
+        assert_eq!(error.stopped_at.line, 2);
+        assert_eq!(error.expected, vec!["`includes`", "`implements`"]);
+    }
+
+    #[test]
+    fn should_recover_from_invalid_definitions() {
+        let input = "
+            interface Foo { attribute long; };
+            typedef long Bar;
+            dictionary Baz { required long x = 5; };
+            enum Qux { \"a\" };
+            callback
+        ";
+        let (definitions, errors) = parse_with_recovery(input);
+
+        assert_eq!(definitions.len(), 2);
+        assert_eq!(errors.len(), 3);
+        assert_eq!(
+            errors[0].span.as_str(input),
+            Some("interface Foo { attribute long; };")
+        );
+        assert_eq!(errors[0].location.column, 43);
+        assert_eq!(
+            errors[1].definition,
+            Some(error::DefinitionKind::Dictionary)
+        );
+        assert_eq!(errors[1].expected, vec!["`;`"]);
+        assert_eq!(errors[2].definition, Some(error::DefinitionKind::Callback));
+        assert_eq!(errors[2].span.as_str(input).unwrap(), "callback\n        ");
+    }
+
+    #[test]
+    fn should_recover_nothing_from_valid_input() {
+        let (definitions, errors) = parse_with_recovery("typedef long Foo; // end\n");
+
+        assert_eq!(definitions.len(), 1);
+        assert!(errors.is_empty());
+    }
+
     test!(should_parse_with_multiple_comments { "
         // This is a comment
         // This is a comment
@@ -5,33 +5,33 @@ ast_types! {
         /// Parses `-?[1-9][0-9]*`
         #[derive(Copy)]
         Dec(struct DecLit<'a>(
-            &'a str = ws!(recognize!(do_parse!(
+            &'a str = expect!(recognize!(do_parse!(
                 opt!(char!('-')) >>
                 one_of!("123456789") >>
                 take_while!(|c: char| c.is_ascii_digit()) >>
                 (())
-            ))),
+            )), "integer"),
         )),
         /// Parses `-?0[Xx][0-9A-Fa-f]+)`
         #[derive(Copy)]
         Hex(struct HexLit<'a>(
-            &'a str = ws!(recognize!(do_parse!(
+            &'a str = expect!(recognize!(do_parse!(
                 opt!(char!('-')) >>
                 char!('0') >>
                 alt!(char!('x') | char!('X')) >>
                 take_while!(|c: char| c.is_ascii_hexdigit()) >>
                 (())
-            ))),
+            )), "integer"),
         )),
         /// Parses `-?0[0-7]*`
         #[derive(Copy)]
         Oct(struct OctLit<'a>(
-            &'a str = ws!(recognize!(do_parse!(
+            &'a str = expect!(recognize!(do_parse!(
                 opt!(char!('-')) >>
                 char!('0') >>
-                take_while!(|c| '0' <= c && c <= '7') >>
+                take_while!(|c| ('0'..='7').contains(&c)) >>
                 (())
-            ))),
+            )), "integer"),
         )),
     }
 
@@ -40,12 +40,12 @@ ast_types! {
     /// Follow `/"[^"]*"/`
     #[derive(Copy)]
     struct StringLit<'a>(
-        &'a str = ws!(do_parse!(
+        &'a str = expect!(do_parse!(
             char!('"') >>
             s: take_while!(|c| c != '"') >>
             char!('"') >>
             (s)
-        )),
+        ), "string"),
     )
 
     /// Represents a default literal value. Ex: `34|34.23|"value"|[ ]|true|false|null`
@@ -83,8 +83,8 @@ ast_types! {
     #[derive(Copy)]
     struct BooleanLit(
         bool = alt!(
-            weedle!(term!(true)) => {|_| true} |
-            weedle!(term!(false)) => {|_| false}
+            expect!(ident_tag!("true"), "`true`") => {|_| true} |
+            expect!(ident_tag!("false"), "`false`") => {|_| false}
         ),
     )
 
@@ -94,7 +94,7 @@ ast_types! {
         /// Parses `/-?(([0-9]+\.[0-9]*|[0-9]*\.[0-9]+)([Ee][+-]?[0-9]+)?|[0-9]+[Ee][+-]?[0-9]+)/`
         #[derive(Copy)]
         Value(struct FloatValueLit<'a>(
-            &'a str = ws!(recognize!(do_parse!(
+            &'a str = expect!(recognize!(do_parse!(
                 opt!(char!('-')) >>
                 alt!(
                     do_parse!(
@@ -134,7 +134,7 @@ ast_types! {
                     )
                 ) >>
                 (())
-            ))),
+            )), "float"),
         )),
         NegInfinity(term!(-Infinity)),
         Infinity(term!(Infinity)),
@@ -142,6 +142,98 @@ ast_types! {
     }
 }
 
+impl<'a> IntegerLit<'a> {
+    /// The value of the literal, `None` if it does not fit in an `i128`
+    pub fn value(&self) -> Option<i128> {
+        match self {
+            IntegerLit::Dec(it) => it.value(),
+            IntegerLit::Hex(it) => it.value(),
+            IntegerLit::Oct(it) => it.value(),
+        }
+    }
+
+    /// The value of the literal as an `f64`, infinite if it is too large
+    pub fn float_value(&self) -> f64 {
+        match self {
+            IntegerLit::Dec(it) => radix_float_value(it.0, 0, 10),
+            IntegerLit::Hex(it) => radix_float_value(it.0, 2, 16),
+            IntegerLit::Oct(it) => radix_float_value(it.0, 0, 8),
+        }
+    }
+}
+
+impl<'a> DecLit<'a> {
+    /// The value of the literal, `None` if it does not fit in an `i128`
+    pub fn value(&self) -> Option<i128> {
+        self.0.parse().ok()
+    }
+}
+
+impl<'a> HexLit<'a> {
+    /// The value of the literal, `None` if it does not fit in an `i128`
+    pub fn value(&self) -> Option<i128> {
+        radix_value(self.0, 2, 16)
+    }
+}
+
+impl<'a> OctLit<'a> {
+    /// The value of the literal, `None` if it does not fit in an `i128`
+    pub fn value(&self) -> Option<i128> {
+        // The leading `0` is an octal digit too
+        radix_value(self.0, 0, 8)
+    }
+}
+
+/// Evaluates `-?` followed by a `prefix` long prefix and digits in `radix`
+fn radix_value(literal: &str, prefix: usize, radix: u32) -> Option<i128> {
+    let (negative, literal) = match literal.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, literal),
+    };
+    let value = i128::from_str_radix(&literal[prefix..], radix).ok()?;
+    Some(if negative { -value } else { value })
+}
+
+/// Evaluates the same as `radix_value`, but without overflowing
+fn radix_float_value(literal: &str, prefix: usize, radix: u32) -> f64 {
+    let (negative, literal) = match literal.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, literal),
+    };
+    let value = literal[prefix..]
+        .chars()
+        .filter_map(|c| c.to_digit(radix))
+        .fold(0.0, |value, digit| {
+            value * f64::from(radix) + f64::from(digit)
+        });
+    if negative {
+        -value
+    } else {
+        value
+    }
+}
+
+impl<'a> FloatLit<'a> {
+    /// The value of the literal, infinite if it is too large for an `f64`,
+    /// `None` if it is not a valid float literal
+    pub fn value(&self) -> Option<f64> {
+        match self {
+            FloatLit::Value(it) => it.value(),
+            FloatLit::NegInfinity(_) => Some(f64::NEG_INFINITY),
+            FloatLit::Infinity(_) => Some(f64::INFINITY),
+            FloatLit::NaN(_) => Some(f64::NAN),
+        }
+    }
+}
+
+impl<'a> FloatValueLit<'a> {
+    /// The value of the literal, infinite if it is too large for an `f64`,
+    /// `None` if it is not a valid float literal
+    pub fn value(&self) -> Option<f64> {
+        self.0.parse().ok()
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -150,72 +242,72 @@ mod test {
 
     test!(should_parse_integer { "45" =>
         "";
-        IntegerLit => IntegerLit::Dec(DecLit("45"))
+        IntegerLit => IntegerLit::Dec(DecLit::new("45"))
     });
 
     test!(should_parse_integer_surrounding_with_spaces { "  123123  " =>
         "";
-        IntegerLit => IntegerLit::Dec(DecLit("123123"))
+        IntegerLit => IntegerLit::Dec(DecLit::new("123123"))
     });
 
     test!(should_parse_integer_preceeding_others { "3453 string" =>
         "string";
-        IntegerLit => IntegerLit::Dec(DecLit("3453"))
+        IntegerLit => IntegerLit::Dec(DecLit::new("3453"))
     });
 
     test!(should_parse_neg_integer { "-435" =>
         "";
-        IntegerLit => IntegerLit::Dec(DecLit("-435"))
+        IntegerLit => IntegerLit::Dec(DecLit::new("-435"))
     });
 
     test!(should_parse_hex_number { "0X08" =>
         "";
-        IntegerLit => IntegerLit::Hex(HexLit("0X08"))
+        IntegerLit => IntegerLit::Hex(HexLit::new("0X08"))
     });
 
     test!(should_parse_hex_large_number { "0xA" =>
         "";
-        IntegerLit => IntegerLit::Hex(HexLit("0xA"))
+        IntegerLit => IntegerLit::Hex(HexLit::new("0xA"))
     });
 
     test!(should_parse_zero { "0" =>
         "";
-        IntegerLit => IntegerLit::Oct(OctLit("0"))
+        IntegerLit => IntegerLit::Oct(OctLit::new("0"))
     });
 
     test!(should_parse_oct_number { "-07561" =>
         "";
-        IntegerLit => IntegerLit::Oct(OctLit("-07561"))
+        IntegerLit => IntegerLit::Oct(OctLit::new("-07561"))
     });
 
     test!(should_parse_float { "45.434" =>
         "";
-        FloatLit => FloatLit::Value(FloatValueLit("45.434"))
+        FloatLit => FloatLit::Value(FloatValueLit::new("45.434"))
     });
 
     test!(should_parse_float_surrounding_with_spaces { "  2345.2345  " =>
         "";
-        FloatLit => FloatLit::Value(FloatValueLit("2345.2345"))
+        FloatLit => FloatLit::Value(FloatValueLit::new("2345.2345"))
     });
 
     test!(should_parse_float_preceeding_others { "3453.32334 string" =>
         "string";
-        FloatLit => FloatLit::Value(FloatValueLit("3453.32334"))
+        FloatLit => FloatLit::Value(FloatValueLit::new("3453.32334"))
     });
 
     test!(should_parse_neg_float { "-435.3435" =>
         "";
-        FloatLit => FloatLit::Value(FloatValueLit("-435.3435"))
+        FloatLit => FloatLit::Value(FloatValueLit::new("-435.3435"))
     });
 
     test!(should_parse_float_exp { "5.3434e23" =>
         "";
-        FloatLit => FloatLit::Value(FloatValueLit("5.3434e23"))
+        FloatLit => FloatLit::Value(FloatValueLit::new("5.3434e23"))
     });
 
     test!(should_parse_float_exp_with_decimal { "3e23" =>
         "";
-        FloatLit => FloatLit::Value(FloatValueLit("3e23"))
+        FloatLit => FloatLit::Value(FloatValueLit::new("3e23"))
     });
 
     test!(should_parse_neg_infinity { "-Infinity" =>
@@ -230,33 +322,33 @@ mod test {
 
     test!(should_parse_string { r#""this is a string""# =>
         "";
-        StringLit => StringLit("this is a string")
+        StringLit => StringLit::new("this is a string")
     });
 
     test!(should_parse_string_surround_with_spaces { r#"  "this is a string"  "# =>
         "";
-        StringLit => StringLit("this is a string")
+        StringLit => StringLit::new("this is a string")
     });
 
     test!(should_parse_string_followed_by_string { r#" "this is first"  "this is second" "# =>
         r#""this is second" "#;
-        StringLit => StringLit("this is first")
+        StringLit => StringLit::new("this is first")
     });
 
     test!(should_parse_string_with_spaces { r#"  "  this is a string  "  "# =>
         "";
-        StringLit => StringLit("  this is a string  ")
+        StringLit => StringLit::new("  this is a string  ")
     });
 
     test!(should_parse_string_with_comment { r#"  "// this is still a string"
      "# =>
         "";
-        StringLit => StringLit("// this is still a string")
+        StringLit => StringLit::new("// this is still a string")
     });
 
     test!(should_parse_string_with_multiline_comment { r#"  "/*"  "*/"  "# =>
         r#""*/"  "#;
-        StringLit => StringLit("/*")
+        StringLit => StringLit::new("/*")
     });
 
     test!(should_parse_null { "null" =>
@@ -271,11 +363,51 @@ mod test {
 
     test!(should_parse_bool_true { "true" =>
         "";
-        BooleanLit => BooleanLit(true)
+        BooleanLit => BooleanLit::new(true)
     });
 
     test!(should_parse_bool_false { "false" =>
         "";
-        BooleanLit => BooleanLit(false)
+        BooleanLit => BooleanLit::new(false)
     });
+
+    #[test]
+    fn should_evaluate_integers() {
+        let value = |input| IntegerLit::parse(input).unwrap().1.value();
+        assert_eq!(value("42"), Some(42));
+        assert_eq!(value("-42"), Some(-42));
+        assert_eq!(value("0x1F"), Some(31));
+        assert_eq!(value("-0X1f"), Some(-31));
+        assert_eq!(value("077"), Some(63));
+        assert_eq!(value("-077"), Some(-63));
+        assert_eq!(value("0"), Some(0));
+        assert_eq!(value("999999999999999999999999999999999999999999"), None);
+    }
+
+    #[test]
+    fn should_evaluate_integers_as_floats() {
+        let value = |input| IntegerLit::parse(input).unwrap().1.float_value();
+        assert_eq!(value("-42"), -42.0);
+        assert_eq!(value("0x1F"), 31.0);
+        assert_eq!(value("-077"), -63.0);
+        assert_eq!(value("0x"), 0.0);
+        assert_eq!(
+            value("0x10000000000000000000000000000000000"),
+            2f64.powi(136)
+        );
+        assert_eq!(value(&"9".repeat(400)), f64::INFINITY);
+    }
+
+    #[test]
+    fn should_evaluate_floats() {
+        let value = |input| FloatLit::parse(input).unwrap().1.value();
+        assert_eq!(value("1.5"), Some(1.5));
+        assert_eq!(value("-.5e1"), Some(-5.0));
+        assert_eq!(value("2."), Some(2.0));
+        assert_eq!(value("1e400"), Some(f64::INFINITY));
+        assert_eq!(value("Infinity"), Some(f64::INFINITY));
+        assert_eq!(value("-Infinity"), Some(f64::NEG_INFINITY));
+        assert!(value("NaN").unwrap().is_nan());
+        assert_eq!(FloatValueLit::new("1.2.3").value(), None);
+    }
 }
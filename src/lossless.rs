@@ -0,0 +1,255 @@
+//! Lossless view of the parsed input, keeping whitespace and comments
+//!
+//! [`parse`](../fn.parse.html) only records the whitespace and comments around
+//! each token in its [`Span`](../span/struct.Span.html). A
+//! [`SyntaxTree`](struct.SyntaxTree.html) resolves them into the trivia of the
+//! tokens, so the input can be written back byte-for-byte.
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::lossless::SyntaxTree;
+//!
+//! let input = "
+//!     // The window
+//!     interface Window {
+//!         readonly attribute Storage sessionStorage; /* session */
+//!     };
+//! ";
+//! let tree = SyntaxTree::parse(input).unwrap();
+//!
+//! assert_eq!(tree.tokens()[0].leading_trivia, "\n    // The window\n    ");
+//! assert_eq!(tree.to_string(), input);
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+use crate::doc::{DocComment, Documented};
+use crate::span::{Span, Spanned};
+use crate::token::Tokens;
+use crate::whitespace::sp_trailing;
+use crate::{Definitions, Error};
+
+/// The definitions parsed from an input along with every token of the input
+/// and the trivia around them
+#[derive(Clone, Debug)]
+pub struct SyntaxTree<'a> {
+    source: &'a str,
+    definitions: Definitions<'a>,
+    tokens: Vec<SyntaxToken<'a>>,
+}
+
+/// A token along with the whitespace and comments around it
+///
+/// Trailing trivia runs up to and including the end of the line the token is
+/// on, leading trivia is whatever comes before that. The first token also
+/// takes the trivia at the start of the input and the last one the trivia at
+/// the end of it.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct SyntaxToken<'a> {
+    pub leading_trivia: &'a str,
+    pub text: &'a str,
+    pub trailing_trivia: &'a str,
+    pub span: Span,
+}
+
+impl<'a> SyntaxTree<'a> {
+    /// Parses `source`, failing just like [`parse`](../fn.parse.html)
+    pub fn parse(source: &'a str) -> Result<Self, Error> {
+        let definitions = crate::parse(source)?;
+        let tokens = attach_trivia(source, &definitions);
+        Ok(SyntaxTree {
+            source,
+            definitions,
+            tokens,
+        })
+    }
+
+    /// The text the tree was parsed from
+    pub fn source(&self) -> &'a str {
+        self.source
+    }
+
+    /// The parsed definitions
+    pub fn definitions(&self) -> &Definitions<'a> {
+        &self.definitions
+    }
+
+    /// Drops the trivia, keeping the parsed definitions
+    pub fn into_definitions(self) -> Definitions<'a> {
+        self.definitions
+    }
+
+    /// Every token of the input, in order
+    pub fn tokens(&self) -> &[SyntaxToken<'a>] {
+        &self.tokens
+    }
+
+    /// The whitespace and comments before `node`
+    pub fn leading_trivia<N: Spanned>(&self, node: &N) -> &'a str {
+        let offset = node.span().range(self.source).map(|range| range.start);
+        self.token_at(offset, |range| range.start)
+            .map_or("", |token| token.leading_trivia)
+    }
+
+    /// The whitespace and comments after `node`
+    pub fn trailing_trivia<N: Spanned>(&self, node: &N) -> &'a str {
+        let offset = node.span().range(self.source).map(|range| range.end);
+        self.token_at(offset, |range| range.end)
+            .map_or("", |token| token.trailing_trivia)
+    }
+
+    /// The doc comment of `node`
+    pub fn doc_comment<N: Documented>(&self, node: &N) -> Option<DocComment<'a>> {
+        node.doc_comment(self.source)
+    }
+
+    fn token_at<F>(&self, offset: Option<usize>, key: F) -> Option<&SyntaxToken<'a>>
+    where
+        F: Fn(Range<usize>) -> usize,
+    {
+        self.tokens
+            .binary_search_by_key(&offset?, |token| {
+                token.span.range(self.source).map_or(0, &key)
+            })
+            .ok()
+            .map(|i| &self.tokens[i])
+    }
+}
+
+/// Writes the source back exactly as it was parsed
+impl<'a> fmt::Display for SyntaxTree<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.tokens.is_empty() {
+            return f.write_str(self.source);
+        }
+        for token in &self.tokens {
+            f.write_str(token.leading_trivia)?;
+            f.write_str(token.text)?;
+            f.write_str(token.trailing_trivia)?;
+        }
+        Ok(())
+    }
+}
+
+// The tokens of the tree with the trivia parsed along with them. That is
+// everything after a token up to the next one, which is split at the end of
+// the line the token is on.
+fn attach_trivia<'a>(source: &'a str, definitions: &Definitions<'a>) -> Vec<SyntaxToken<'a>> {
+    let mut tokens: Vec<_> = definitions
+        .to_tokens()
+        .iter()
+        .filter(|token| !token.span.is_empty())
+        .filter_map(|token| {
+            Some(SyntaxToken {
+                leading_trivia: token.span.leading_trivia(source)?,
+                text: token.span.as_str(source)?,
+                trailing_trivia: token.span.trailing_trivia(source)?,
+                span: token.span,
+            })
+        })
+        .collect();
+    tokens.sort_by_key(|token| token.span.range(source).map(|range| range.start));
+
+    for i in 1..tokens.len() {
+        let trivia = tokens[i - 1].trailing_trivia;
+        let split = sp_trailing(trivia).map_or(0, |(rest, _)| trivia.len() - rest.len());
+        tokens[i - 1].trailing_trivia = &trivia[..split];
+        tokens[i].leading_trivia = &trivia[split..];
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Definition;
+
+    const INPUT: &str = "/* License */
+
+// Something to iterate over
+[Exposed=(Window,Worker)]
+interface Foo { // Opening
+    /** Counts */
+    attribute unsigned long   count ;
+
+    void bar(long a , long b,/* c */ long c);
+};
+
+enum Bar { \"a\", \"b\", };
+dictionary Baz {
+    required [Clamp] long x;
+};
+// The end
+";
+
+    #[test]
+    fn should_round_trip() {
+        let tree = SyntaxTree::parse(INPUT).unwrap();
+        assert_eq!(tree.to_string(), INPUT);
+        assert_eq!(tree.definitions(), &crate::parse(INPUT).unwrap());
+    }
+
+    #[test]
+    fn should_round_trip_trivia_only() {
+        for input in &["", "  // nothing\n", "/* nothing */", "// no newline"] {
+            let tree = SyntaxTree::parse(input).unwrap();
+            assert!(tree.tokens().is_empty());
+            assert_eq!(tree.to_string(), *input);
+        }
+    }
+
+    #[test]
+    fn should_keep_separators() {
+        let tree = SyntaxTree::parse(INPUT).unwrap();
+        let texts: Vec<_> = tree.tokens().iter().map(|t| t.text).collect();
+        assert_eq!(
+            &texts[3..10],
+            ["(", "Window", ",", "Worker", ")", "]", "interface"]
+        );
+        assert!(texts.ends_with(&["]", "long", "x", ";", "}", ";"]));
+    }
+
+    #[test]
+    fn should_split_trivia_at_end_of_line() {
+        let tree = SyntaxTree::parse(INPUT).unwrap();
+        let tokens = tree.tokens();
+        assert_eq!(tokens[0].text, "[");
+        assert_eq!(
+            tokens[0].leading_trivia,
+            "/* License */\n\n// Something to iterate over\n"
+        );
+
+        let brace = tokens.iter().position(|t| t.text == "{").unwrap();
+        assert_eq!(tokens[brace].trailing_trivia, " // Opening\n");
+        assert_eq!(tokens[brace + 1].leading_trivia, "    /** Counts */\n    ");
+
+        let count = tokens.iter().position(|t| t.text == "count").unwrap();
+        assert_eq!(tokens[count].trailing_trivia, " ");
+        assert_eq!(tokens[count + 1].trailing_trivia, "\n");
+        assert_eq!(tokens[count + 2].leading_trivia, "\n    ");
+
+        let last = tokens.last().unwrap();
+        assert_eq!(last.trailing_trivia, "\n// The end\n");
+    }
+
+    #[test]
+    fn should_find_trivia_of_nodes() {
+        let tree = SyntaxTree::parse(INPUT).unwrap();
+        let interface = match &tree.definitions()[0] {
+            Definition::Interface(interface) => interface,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            tree.leading_trivia(interface),
+            "/* License */\n\n// Something to iterate over\n"
+        );
+        assert_eq!(tree.trailing_trivia(interface), "\n");
+        assert_eq!(
+            tree.leading_trivia(&interface.members.body[0]),
+            "    /** Counts */\n    "
+        );
+        assert_eq!(tree.leading_trivia(&None::<Definition>), "");
+    }
+}
@@ -40,6 +40,29 @@ macro_rules! char {
     };
 }
 
+macro_rules! spanned {
+    ($i:expr, $submac:ident!( $($args:tt)* )) => ({
+        use $crate::nom::lib::std::result::Result::*;
+
+        let input = $i;
+        match $submac!(input, $($args)*) {
+            Err(e) => Err(e),
+            Ok((i, o)) => Ok((i, (o, $crate::span::Span::new(input, i)))),
+        }
+    });
+}
+
+macro_rules! expect {
+    ($i:expr, $submac:ident!( $($args:tt)* ), $what:expr) => ({
+        let input = $i;
+        let res = $submac!(input, $($args)*);
+        if res.is_err() {
+            $crate::error::expected(input, $what);
+        }
+        res
+    });
+}
+
 macro_rules! parser {
     ($submac:ident!( $($args:tt)* )) => {
         fn parse(input: &'a str) -> $crate::IResult<&'a str, Self> {
@@ -54,6 +77,20 @@ macro_rules! weedle {
     };
 }
 
+// Derives the serde traits of an item. Items with a lifetime borrow their
+// strings from the input being deserialized.
+macro_rules! with_serde {
+    ([ 'a ] $item:item) => {
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        #[cfg_attr(feature = "serde", serde(bound(deserialize = "'de: 'a")))]
+        $item
+    };
+    ([ $($generics:tt)* ] $item:item) => {
+        #[cfg_attr(feature = "serde", derive(::serde::Serialize, ::serde::Deserialize))]
+        $item
+    };
+}
+
 macro_rules! ast_types {
     (@extract_type struct $name:ident<'a> $($rest:tt)*) => ($name<'a>);
     (@extract_type struct $name:ident $($rest:tt)*) => ($name);
@@ -188,19 +225,83 @@ macro_rules! __ast_tuple_struct {
         ( $inner:ty = $submac:ident!( $($args:tt)* ), )
     ) => (
         $(#[$attr])*
-        #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
-        pub struct $name<$($maybe_a)*>(pub $inner);
+        #[derive(Clone, Debug)]
+        pub struct $name<$($maybe_a)*>(pub $inner, pub $crate::span::Span);
+
+        impl<$($maybe_a)*> $name<$($maybe_a)*> {
+            /// Creates the node with an empty span, as it is not parsed from
+            /// any input
+            pub fn new(inner: $inner) -> Self {
+                $name(inner, $crate::span::Span::EMPTY)
+            }
+        }
+
+        impl<$($maybe_a)*> PartialEq for $name<$($maybe_a)*> {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+
+        impl<$($maybe_a)*> Eq for $name<$($maybe_a)*> {}
+
+        impl<$($maybe_a)*> PartialOrd for $name<$($maybe_a)*> {
+            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl<$($maybe_a)*> Ord for $name<$($maybe_a)*> {
+            fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        impl<$($maybe_a)*> ::std::hash::Hash for $name<$($maybe_a)*> {
+            fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+                self.0.hash(state)
+            }
+        }
 
         impl<'a> $crate::Parse<'a> for $name<$($maybe_a)*> {
             fn parse(input: &'a str) -> $crate::IResult<&'a str, Self> {
                 use $crate::nom::lib::std::result::Result::*;
 
-                match $submac!(input, $($args)*) {
+                match ws!(input, $submac!($($args)*)) {
                     Err(e) => Err(e),
-                    Ok((i, inner)) => Ok((i, $name(inner))),
+                    Ok((i, (inner, span))) => Ok((i, $name(inner, span))),
                 }
             }
         }
+
+        impl<$($maybe_a)*> $crate::span::Spanned for $name<$($maybe_a)*> {
+            fn span(&self) -> $crate::span::Span {
+                self.1
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<$($maybe_a)*> ::serde::Serialize for $name<$($maybe_a)*> {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.0.serialize(serializer)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de, $($maybe_a)*> ::serde::Deserialize<'de> for $name<$($maybe_a)*>
+        where
+            $inner: ::serde::Deserialize<'de>,
+        {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                let inner = <$inner as ::serde::Deserialize>::deserialize(deserializer)?;
+                Ok($name::new(inner))
+            }
+        }
+
+        impl<$($maybe_a)*> ::std::fmt::Display for $name<$($maybe_a)*> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(&$crate::print::ToWebIdl::to_webidl(self))
+            }
+        }
     );
     (@launch_pad
         $(#[$attr:meta])*
@@ -208,12 +309,40 @@ macro_rules! __ast_tuple_struct {
         [ $($maybe_a:tt)* ]
         ( $inner:ty, )
     ) => (
-        __ast_tuple_struct! {
-            @launch_pad
-            $(#[$attr])*
-            $name
+        with_serde! {
             [ $($maybe_a)* ]
-            ( $inner = weedle!($inner), )
+            $(#[$attr])*
+            #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+            pub struct $name<$($maybe_a)*>(pub $inner);
+        }
+
+        impl<'a> $crate::Parse<'a> for $name<$($maybe_a)*> {
+            fn parse(input: &'a str) -> $crate::IResult<&'a str, Self> {
+                use $crate::nom::lib::std::result::Result::*;
+
+                match weedle!(input, $inner) {
+                    Err(e) => Err(e),
+                    Ok((i, inner)) => Ok((i, $name(inner))),
+                }
+            }
+        }
+
+        impl<$($maybe_a)*> $crate::span::Spanned for $name<$($maybe_a)*> {
+            fn span(&self) -> $crate::span::Span {
+                $crate::span::Spanned::span(&self.0)
+            }
+        }
+
+        impl<'a> $crate::token::Tokens<'a> for $name<$($maybe_a)*> {
+            fn tokens(&self, out: &mut Vec<$crate::token::Token<'a>>) {
+                $crate::token::Tokens::tokens(&self.0, out)
+            }
+        }
+
+        impl<$($maybe_a)*> ::std::fmt::Display for $name<$($maybe_a)*> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(&$crate::print::ToWebIdl::to_webidl(self))
+            }
         }
     );
 }
@@ -228,10 +357,13 @@ macro_rules! __ast_struct {
         }
         { }
     ) => {
-        $(#[$attr])*
-        #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
-        pub struct $name<$($generics)*> {
-            $(pub $field : $type,)*
+        with_serde! {
+            [ $($generics)* ]
+            $(#[$attr])*
+            #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+            pub struct $name<$($generics)*> {
+                $(pub $field : $type,)*
+            }
         }
     };
     (@build_struct_decl
@@ -311,6 +443,68 @@ macro_rules! __ast_struct {
         }
     );
 
+    (@build_walkers
+        { $($spanned:tt)* }
+        { $($tokens:tt)* }
+        { $($field:ident)* }
+        { }
+    ) => (
+        $($spanned)* {
+            fn span(&self) -> $crate::span::Span {
+                $crate::span::Span::EMPTY
+                    $(.join($crate::span::Spanned::span(&self.$field)))*
+            }
+        }
+
+        $($tokens)* {
+            fn tokens(&self, _out: &mut Vec<$crate::token::Token<'a>>) {
+                $($crate::token::Tokens::tokens(&self.$field, _out);)*
+            }
+        }
+    );
+    (@build_walkers
+        { $($spanned:tt)* }
+        { $($tokens:tt)* }
+        { $($prev:tt)* }
+        { $field:ident : $type:ty, $($rest:tt)* }
+    ) => (
+        __ast_struct! {
+            @build_walkers
+            { $($spanned)* }
+            { $($tokens)* }
+            { $($prev)* $field }
+            { $($rest)* }
+        }
+    );
+    (@build_walkers
+        { $($spanned:tt)* }
+        { $($tokens:tt)* }
+        { $($prev:tt)* }
+        { $field:ident : $type:ty = $submac:ident!( $($args:tt)* ), $($rest:tt)* }
+    ) => (
+        __ast_struct! {
+            @build_walkers
+            { $($spanned)* }
+            { $($tokens)* }
+            { $($prev)* $field }
+            { $($rest)* }
+        }
+    );
+    (@build_walkers
+        { $($spanned:tt)* }
+        { $($tokens:tt)* }
+        { $($prev:tt)* }
+        { $field:ident : $type:ty = marker, $($rest:tt)* }
+    ) => (
+        __ast_struct! {
+            @build_walkers
+            { $($spanned)* }
+            { $($tokens)* }
+            { $($prev)* }
+            { $($rest)* }
+        }
+    );
+
     (
         @launch_pad
         $(#[$attr:meta])*
@@ -338,6 +532,20 @@ macro_rules! __ast_struct {
                 }
             }
         }
+
+        __ast_struct! {
+            @build_walkers
+            { impl $crate::span::Spanned for $name }
+            { impl<'a> $crate::token::Tokens<'a> for $name }
+            { }
+            { $($fields)* }
+        }
+
+        impl ::std::fmt::Display for $name {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(&$crate::print::ToWebIdl::to_webidl(self))
+            }
+        }
     };
     (
         @launch_pad
@@ -366,6 +574,20 @@ macro_rules! __ast_struct {
                 }
             }
         }
+
+        __ast_struct! {
+            @build_walkers
+            { impl<'a> $crate::span::Spanned for $name<'a> }
+            { impl<'a> $crate::token::Tokens<'a> for $name<'a> }
+            { }
+            { $($fields)* }
+        }
+
+        impl<'a> ::std::fmt::Display for $name<'a> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(&$crate::print::ToWebIdl::to_webidl(self))
+            }
+        }
     };
     (
         @launch_pad
@@ -394,6 +616,28 @@ macro_rules! __ast_struct {
                 }
             }
         }
+
+        __ast_struct! {
+            @build_walkers
+            {
+                impl<$($generics),+> $crate::span::Spanned for $name<$($generics),+>
+                where $($generics: $crate::span::Spanned),+
+            }
+            {
+                impl<'a, $($generics),+> $crate::token::Tokens<'a> for $name<$($generics),+>
+                where $($generics: $crate::token::Tokens<'a>),+
+            }
+            { }
+            { $($fields)* }
+        }
+
+        impl<'a, $($generics),+> ::std::fmt::Display for $name<$($generics),+>
+        where $($generics: $crate::token::Tokens<'a>),+
+        {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(&$crate::print::ToWebIdl::to_webidl(self))
+            }
+        }
     };
 }
 
@@ -407,10 +651,13 @@ macro_rules! __ast_enum {
         }
         { }
     ) => (
-        $(#[$attr])*
-        #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
-        pub enum $name<$($maybe_a)*> {
-            $($variant($member),)*
+        with_serde! {
+            [ $($maybe_a)* ]
+            $(#[$attr])*
+            #[derive(Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+            pub enum $name<$($maybe_a)*> {
+                $($variant($member),)*
+            }
         }
     );
     (@build_enum_decl
@@ -519,6 +766,53 @@ macro_rules! __ast_enum {
         }
     );
 
+    (@build_walkers
+        { $name:ident [ $($maybe_a:tt)* ] $($variant:ident)* }
+        { }
+    ) => (
+        impl<$($maybe_a)*> $crate::span::Spanned for $name<$($maybe_a)*> {
+            fn span(&self) -> $crate::span::Span {
+                match self {
+                    $($name::$variant(x) => $crate::span::Spanned::span(x),)*
+                }
+            }
+        }
+
+        impl<'a> $crate::token::Tokens<'a> for $name<$($maybe_a)*> {
+            fn tokens(&self, out: &mut Vec<$crate::token::Token<'a>>) {
+                match self {
+                    $($name::$variant(x) => $crate::token::Tokens::tokens(x, out),)*
+                }
+            }
+        }
+
+        impl<$($maybe_a)*> ::std::fmt::Display for $name<$($maybe_a)*> {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(&$crate::print::ToWebIdl::to_webidl(self))
+            }
+        }
+    );
+    (@build_walkers
+        { $($prev:tt)* }
+        { $variant:ident($member:ty), $($rest:tt)* }
+    ) => (
+        __ast_enum! {
+            @build_walkers
+            { $($prev)* $variant }
+            { $($rest)* }
+        }
+    );
+    (@build_walkers
+        { $($prev:tt)* }
+        { $(#[$attr:meta])* $variant:ident( $($member:tt)* ), $($rest:tt)* }
+    ) => (
+        __ast_enum! {
+            @build_walkers
+            { $($prev)* $variant }
+            { $($rest)* }
+        }
+    );
+
     (@launch_pad
         $(#[$attr:meta])*
         $name:ident
@@ -548,6 +842,12 @@ macro_rules! __ast_enum {
             { $name [ $($maybe_a)* ] }
             { $($variants)* }
         }
+
+        __ast_enum! {
+            @build_walkers
+            { $name [ $($maybe_a)* ] }
+            { $($variants)* }
+        }
     );
 }
 
@@ -0,0 +1,602 @@
+//! Definitions merged by name
+//!
+//! The syntax tree keeps partial definitions and `includes` statements apart
+//! from what they extend. A [`Model`](struct.Model.html) gathers them into
+//! one record per interface, mixin, namespace and dictionary: the members of
+//! every partial definition are merged into the record of the same name, and
+//! the members of every mixin an interface includes are copied into it. Each
+//! member keeps the definition it was written in.
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::model::Model;
+//!
+//! let parsed = weedle::parse("
+//!     interface Window {
+//!         readonly attribute Document document;
+//!     };
+//!     partial interface Window {
+//!         undefined alert(DOMString message);
+//!     };
+//!     interface mixin WindowSessionStorage {
+//!         readonly attribute Storage sessionStorage;
+//!     };
+//!     Window includes WindowSessionStorage;
+//! ").unwrap();
+//!
+//! let model = Model::new(&parsed);
+//! let window = &model.interfaces["Window"];
+//!
+//! let names: Vec<_> = window.members.iter().map(|m| m.name().unwrap()).collect();
+//! assert_eq!(names, ["document", "alert", "sessionStorage"]);
+//! assert!(window.members[1].is_partial());
+//! assert_eq!(window.members[2].mixin(), Some("WindowSessionStorage"));
+//! ```
+
+use std::collections::BTreeMap;
+
+use crate::attribute::ExtendedAttributeList;
+use crate::common::Identifier;
+use crate::dictionary::DictionaryMember;
+use crate::interface::InterfaceMember;
+use crate::mixin::MixinMember;
+use crate::namespace::NamespaceMember;
+use crate::span::{Span, Spanned};
+use crate::{
+    CallbackDefinition, CallbackInterfaceDefinition, Definition, DictionaryDefinition,
+    EnumDefinition, IncludesStatementDefinition, InterfaceDefinition, InterfaceMixinDefinition,
+    NamespaceDefinition, PartialDictionaryDefinition, PartialInterfaceDefinition,
+    PartialInterfaceMixinDefinition, PartialNamespaceDefinition, TypedefDefinition,
+};
+
+/// The definitions of a set of WebIDL files, merged by name
+///
+/// Records are keyed by the name of what they define. When a name is defined
+/// more than once without `partial`, the first definition is kept as the
+/// record's definition, but the members of every one of them are merged.
+/// Legacy `implements` statements are ignored.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Model<'a> {
+    pub interfaces: BTreeMap<&'a str, Interface<'a>>,
+    pub mixins: BTreeMap<&'a str, Mixin<'a>>,
+    pub namespaces: BTreeMap<&'a str, Namespace<'a>>,
+    pub dictionaries: BTreeMap<&'a str, Dictionary<'a>>,
+    pub callback_interfaces: BTreeMap<&'a str, &'a CallbackInterfaceDefinition<'a>>,
+    pub callbacks: BTreeMap<&'a str, &'a CallbackDefinition<'a>>,
+    pub enums: BTreeMap<&'a str, &'a EnumDefinition<'a>>,
+    pub typedefs: BTreeMap<&'a str, &'a TypedefDefinition<'a>>,
+    /// The `includes` statements, in the order they were written
+    pub includes: Vec<&'a IncludesStatementDefinition<'a>>,
+}
+
+/// An interface together with its partial definitions and included mixins
+#[derive(Clone, Debug, PartialEq)]
+pub struct Interface<'a> {
+    pub name: &'a str,
+    /// `None` if the interface is only defined by partial definitions
+    pub definition: Option<&'a InterfaceDefinition<'a>>,
+    pub partials: Vec<&'a PartialInterfaceDefinition<'a>>,
+    /// The names of the mixins included, in the order of the `includes`
+    /// statements, whether they are defined or not
+    pub mixins: Vec<&'a str>,
+    /// The members of the definitions first, in the order they were written,
+    /// then those of the mixins included
+    pub members: Vec<Member<'a>>,
+}
+
+/// An interface mixin together with its partial definitions
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mixin<'a> {
+    pub name: &'a str,
+    /// `None` if the mixin is only defined by partial definitions
+    pub definition: Option<&'a InterfaceMixinDefinition<'a>>,
+    pub partials: Vec<&'a PartialInterfaceMixinDefinition<'a>>,
+    pub members: Vec<Member<'a>>,
+}
+
+/// A namespace together with its partial definitions
+#[derive(Clone, Debug, PartialEq)]
+pub struct Namespace<'a> {
+    pub name: &'a str,
+    /// `None` if the namespace is only defined by partial definitions
+    pub definition: Option<&'a NamespaceDefinition<'a>>,
+    pub partials: Vec<&'a PartialNamespaceDefinition<'a>>,
+    pub members: Vec<Member<'a>>,
+}
+
+/// A dictionary together with its partial definitions
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dictionary<'a> {
+    pub name: &'a str,
+    /// `None` if the dictionary is only defined by partial definitions
+    pub definition: Option<&'a DictionaryDefinition<'a>>,
+    pub partials: Vec<&'a PartialDictionaryDefinition<'a>>,
+    pub members: Vec<Member<'a>>,
+}
+
+impl<'a> Interface<'a> {
+    /// The name of the interface inherited from, if any
+    pub fn inheritance(&self) -> Option<&'a str> {
+        let definition = self.definition?;
+        definition.inheritance.map(|it| it.identifier.0)
+    }
+
+    /// The attributes of the interface definition, not of its partials
+    pub fn attributes(&self) -> Option<&'a ExtendedAttributeList<'a>> {
+        self.definition?.attributes.as_ref()
+    }
+}
+
+impl<'a> Mixin<'a> {
+    /// The attributes of the mixin definition, not of its partials
+    pub fn attributes(&self) -> Option<&'a ExtendedAttributeList<'a>> {
+        self.definition?.attributes.as_ref()
+    }
+}
+
+impl<'a> Namespace<'a> {
+    /// The attributes of the namespace definition, not of its partials
+    pub fn attributes(&self) -> Option<&'a ExtendedAttributeList<'a>> {
+        self.definition?.attributes.as_ref()
+    }
+}
+
+impl<'a> Dictionary<'a> {
+    /// The name of the dictionary inherited from, if any
+    pub fn inheritance(&self) -> Option<&'a str> {
+        let definition = self.definition?;
+        definition.inheritance.map(|it| it.identifier.0)
+    }
+
+    /// The attributes of the dictionary definition, not of its partials
+    pub fn attributes(&self) -> Option<&'a ExtendedAttributeList<'a>> {
+        self.definition?.attributes.as_ref()
+    }
+}
+
+/// A member of a record
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Member<'a> {
+    pub node: MemberNode<'a>,
+    /// The definition the member is written in: the definition of the record,
+    /// one of its partials, or a mixin or partial mixin it includes
+    pub source: &'a Definition<'a>,
+}
+
+/// The syntax tree node of a member
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MemberNode<'a> {
+    Interface(&'a InterfaceMember<'a>),
+    Mixin(&'a MixinMember<'a>),
+    Namespace(&'a NamespaceMember<'a>),
+    Dictionary(&'a DictionaryMember<'a>),
+}
+
+impl<'a> Member<'a> {
+    /// The name of the member, if it has one
+    pub fn name(&self) -> Option<&'a str> {
+        self.node.name()
+    }
+
+    /// Returns `true` if the member is written in a partial definition
+    pub fn is_partial(&self) -> bool {
+        matches!(
+            self.source,
+            Definition::PartialInterface(_)
+                | Definition::PartialInterfaceMixin(_)
+                | Definition::PartialNamespace(_)
+                | Definition::PartialDictionary(_)
+        )
+    }
+
+    /// The name of the mixin the member is written in, if any
+    pub fn mixin(&self) -> Option<&'a str> {
+        match self.source {
+            Definition::InterfaceMixin(mixin) => Some(mixin.identifier.0),
+            Definition::PartialInterfaceMixin(mixin) => Some(mixin.identifier.0),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> MemberNode<'a> {
+    /// The name of the member, if it has one
+    ///
+    /// Constructors, iterable, maplike and setlike declarations, `stringifier;`
+    /// and special operations written without an identifier have none.
+    pub fn name(&self) -> Option<&'a str> {
+        self.identifier().map(|it| it.0)
+    }
+
+    /// The identifier of the member, if it has one
+    pub fn identifier(&self) -> Option<&'a Identifier<'a>> {
+        match *self {
+            MemberNode::Interface(member) => match member {
+                InterfaceMember::Const(it) => Some(&it.identifier),
+                InterfaceMember::Attribute(it) => Some(&it.identifier),
+                InterfaceMember::Operation(it) => it.identifier.as_ref(),
+                InterfaceMember::Constructor(_)
+                | InterfaceMember::Iterable(_)
+                | InterfaceMember::AsyncIterable(_)
+                | InterfaceMember::Maplike(_)
+                | InterfaceMember::Setlike(_)
+                | InterfaceMember::Stringifier(_) => None,
+            },
+            MemberNode::Mixin(member) => match member {
+                MixinMember::Const(it) => Some(&it.identifier),
+                MixinMember::Operation(it) => it.identifier.as_ref(),
+                MixinMember::Attribute(it) => Some(&it.identifier),
+                MixinMember::Stringifier(_) => None,
+            },
+            MemberNode::Namespace(member) => match member {
+                NamespaceMember::Const(it) => Some(&it.identifier),
+                NamespaceMember::Operation(it) => it.identifier.as_ref(),
+                NamespaceMember::Attribute(it) => Some(&it.identifier),
+            },
+            MemberNode::Dictionary(member) => Some(&member.identifier),
+        }
+    }
+
+    /// Returns `true` if the member is a regular, static or special operation
+    pub fn is_operation(&self) -> bool {
+        matches!(
+            self,
+            MemberNode::Interface(InterfaceMember::Operation(_))
+                | MemberNode::Mixin(MixinMember::Operation(_))
+                | MemberNode::Namespace(NamespaceMember::Operation(_))
+        )
+    }
+}
+
+impl<'a> Spanned for Member<'a> {
+    fn span(&self) -> Span {
+        self.node.span()
+    }
+}
+
+impl<'a> Spanned for MemberNode<'a> {
+    fn span(&self) -> Span {
+        match *self {
+            MemberNode::Interface(member) => member.span(),
+            MemberNode::Mixin(member) => member.span(),
+            MemberNode::Namespace(member) => member.span(),
+            MemberNode::Dictionary(member) => member.span(),
+        }
+    }
+}
+
+impl<'a> Model<'a> {
+    /// Merges `definitions` by name
+    pub fn new(definitions: &'a [Definition<'a>]) -> Self {
+        let mut model = Model::default();
+
+        for source in definitions {
+            match source {
+                Definition::Interface(def) => {
+                    let record = model.interface(def.identifier.0);
+                    record.definition.get_or_insert(def);
+                    record
+                        .members
+                        .extend(interface_members(&def.members.body, source));
+                }
+                Definition::PartialInterface(def) => {
+                    let record = model.interface(def.identifier.0);
+                    record.partials.push(def);
+                    record
+                        .members
+                        .extend(interface_members(&def.members.body, source));
+                }
+                Definition::InterfaceMixin(def) => {
+                    let record = model.mixin(def.identifier.0);
+                    record.definition.get_or_insert(def);
+                    record
+                        .members
+                        .extend(mixin_members(&def.members.body, source));
+                }
+                Definition::PartialInterfaceMixin(def) => {
+                    let record = model.mixin(def.identifier.0);
+                    record.partials.push(def);
+                    record
+                        .members
+                        .extend(mixin_members(&def.members.body, source));
+                }
+                Definition::Namespace(def) => {
+                    let record = model.namespace(def.identifier.0);
+                    record.definition.get_or_insert(def);
+                    record
+                        .members
+                        .extend(namespace_members(&def.members.body, source));
+                }
+                Definition::PartialNamespace(def) => {
+                    let record = model.namespace(def.identifier.0);
+                    record.partials.push(def);
+                    record
+                        .members
+                        .extend(namespace_members(&def.members.body, source));
+                }
+                Definition::Dictionary(def) => {
+                    let record = model.dictionary(def.identifier.0);
+                    record.definition.get_or_insert(def);
+                    record
+                        .members
+                        .extend(dictionary_members(&def.members.body, source));
+                }
+                Definition::PartialDictionary(def) => {
+                    let record = model.dictionary(def.identifier.0);
+                    record.partials.push(def);
+                    record
+                        .members
+                        .extend(dictionary_members(&def.members.body, source));
+                }
+                Definition::CallbackInterface(def) => {
+                    model
+                        .callback_interfaces
+                        .entry(def.identifier.0)
+                        .or_insert(def);
+                }
+                Definition::Callback(def) => {
+                    model.callbacks.entry(def.identifier.0).or_insert(def);
+                }
+                Definition::Enum(def) => {
+                    model.enums.entry(def.identifier.0).or_insert(def);
+                }
+                Definition::Typedef(def) => {
+                    model.typedefs.entry(def.identifier.0).or_insert(def);
+                }
+                Definition::IncludesStatement(def) => model.includes.push(def),
+                Definition::Implements(_) => {}
+            }
+        }
+
+        // Mixins are only complete once every partial has been seen
+        for includes in &model.includes {
+            let interface = match model.interfaces.get_mut(includes.lhs_identifier.0) {
+                Some(interface) => interface,
+                None => continue,
+            };
+            let name = includes.rhs_identifier.0;
+            if interface.mixins.contains(&name) {
+                continue;
+            }
+            interface.mixins.push(name);
+            if let Some(mixin) = model.mixins.get(name) {
+                interface.members.extend(mixin.members.iter().cloned());
+            }
+        }
+
+        model
+    }
+
+    /// The names of the interfaces `name` inherits from, closest first
+    ///
+    /// The chain stops at the first interface which is not defined, and
+    /// before any interface which would appear twice in it, `name` included.
+    pub fn interface_ancestors(&self, name: &str) -> Vec<&'a str> {
+        ancestors(name, |name| self.interfaces.get(name)?.inheritance())
+    }
+
+    /// The names of the dictionaries `name` inherits from, closest first
+    ///
+    /// The chain stops at the first dictionary which is not defined, and
+    /// before any dictionary which would appear twice in it, `name` included.
+    pub fn dictionary_ancestors(&self, name: &str) -> Vec<&'a str> {
+        ancestors(name, |name| self.dictionaries.get(name)?.inheritance())
+    }
+
+    fn interface(&mut self, name: &'a str) -> &mut Interface<'a> {
+        self.interfaces.entry(name).or_insert_with(|| Interface {
+            name,
+            definition: None,
+            partials: Vec::new(),
+            mixins: Vec::new(),
+            members: Vec::new(),
+        })
+    }
+
+    fn mixin(&mut self, name: &'a str) -> &mut Mixin<'a> {
+        self.mixins.entry(name).or_insert_with(|| Mixin {
+            name,
+            definition: None,
+            partials: Vec::new(),
+            members: Vec::new(),
+        })
+    }
+
+    fn namespace(&mut self, name: &'a str) -> &mut Namespace<'a> {
+        self.namespaces.entry(name).or_insert_with(|| Namespace {
+            name,
+            definition: None,
+            partials: Vec::new(),
+            members: Vec::new(),
+        })
+    }
+
+    fn dictionary(&mut self, name: &'a str) -> &mut Dictionary<'a> {
+        self.dictionaries.entry(name).or_insert_with(|| Dictionary {
+            name,
+            definition: None,
+            partials: Vec::new(),
+            members: Vec::new(),
+        })
+    }
+}
+
+fn ancestors<'a>(name: &str, parent: impl Fn(&str) -> Option<&'a str>) -> Vec<&'a str> {
+    let mut ancestors = Vec::new();
+    let mut current = parent(name);
+    while let Some(ancestor) = current {
+        if ancestor == name || ancestors.contains(&ancestor) {
+            break;
+        }
+        ancestors.push(ancestor);
+        current = parent(ancestor);
+    }
+    ancestors
+}
+
+fn interface_members<'a>(
+    members: &'a [InterfaceMember<'a>],
+    source: &'a Definition<'a>,
+) -> impl Iterator<Item = Member<'a>> {
+    members.iter().map(move |it| Member {
+        node: MemberNode::Interface(it),
+        source,
+    })
+}
+
+fn mixin_members<'a>(
+    members: &'a [MixinMember<'a>],
+    source: &'a Definition<'a>,
+) -> impl Iterator<Item = Member<'a>> {
+    members.iter().map(move |it| Member {
+        node: MemberNode::Mixin(it),
+        source,
+    })
+}
+
+fn namespace_members<'a>(
+    members: &'a [NamespaceMember<'a>],
+    source: &'a Definition<'a>,
+) -> impl Iterator<Item = Member<'a>> {
+    members.iter().map(move |it| Member {
+        node: MemberNode::Namespace(it),
+        source,
+    })
+}
+
+fn dictionary_members<'a>(
+    members: &'a [DictionaryMember<'a>],
+    source: &'a Definition<'a>,
+) -> impl Iterator<Item = Member<'a>> {
+    members.iter().map(move |it| Member {
+        node: MemberNode::Dictionary(it),
+        source,
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn names<'a>(members: &[Member<'a>]) -> Vec<Option<&'a str>> {
+        members.iter().map(Member::name).collect()
+    }
+
+    #[test]
+    fn should_merge_partials() {
+        let parsed = crate::parse(
+            "
+            partial interface Foo { attribute long a; };
+            interface Foo : Bar { attribute long b; };
+            partial interface Foo { constructor(); };
+            partial dictionary Dict { long d; };
+            namespace Ns { readonly attribute long n; };
+            partial namespace Ns { undefined f(); };
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+
+        let foo = &model.interfaces["Foo"];
+        assert_eq!(foo.name, "Foo");
+        assert!(foo.definition.is_some());
+        assert_eq!(foo.inheritance(), Some("Bar"));
+        assert_eq!(foo.partials.len(), 2);
+        assert_eq!(names(&foo.members), [Some("a"), Some("b"), None]);
+        assert!(foo.members[0].is_partial());
+        assert!(!foo.members[1].is_partial());
+        assert!(std::ptr::eq(foo.members[2].source, &parsed[2]));
+
+        let dict = &model.dictionaries["Dict"];
+        assert!(dict.definition.is_none());
+        assert_eq!(dict.inheritance(), None);
+        assert_eq!(names(&dict.members), [Some("d")]);
+
+        let ns = &model.namespaces["Ns"];
+        assert_eq!(names(&ns.members), [Some("n"), Some("f")]);
+    }
+
+    #[test]
+    fn should_copy_mixin_members() {
+        let parsed = crate::parse(
+            "
+            Foo includes Mixin;
+            interface Foo { attribute long a; };
+            interface mixin Mixin { attribute long b; };
+            partial interface mixin Mixin { const long C = 1; };
+            Foo includes Mixin;
+            Foo includes Missing;
+            Unknown includes Mixin;
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+
+        let foo = &model.interfaces["Foo"];
+        assert_eq!(foo.mixins, ["Mixin", "Missing"]);
+        assert_eq!(names(&foo.members), [Some("a"), Some("b"), Some("C")]);
+        assert_eq!(foo.members[0].mixin(), None);
+        assert_eq!(foo.members[1].mixin(), Some("Mixin"));
+        assert!(!foo.members[1].is_partial());
+        assert_eq!(foo.members[2].mixin(), Some("Mixin"));
+        assert!(foo.members[2].is_partial());
+
+        assert_eq!(model.mixins["Mixin"].members.len(), 2);
+        assert_eq!(model.includes.len(), 4);
+        assert!(!model.interfaces.contains_key("Unknown"));
+    }
+
+    #[test]
+    fn should_list_ancestors() {
+        let parsed = crate::parse(
+            "
+            interface A : B {};
+            interface B : C {};
+            partial interface C {};
+            interface D : E {};
+            interface E : F {};
+            interface F : E {};
+            dictionary G : H {};
+            dictionary H : G {};
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+
+        assert_eq!(model.interface_ancestors("A"), ["B", "C"]);
+        assert!(model.interface_ancestors("C").is_empty());
+        assert_eq!(model.interface_ancestors("D"), ["E", "F"]);
+        assert_eq!(model.interface_ancestors("E"), ["F"]);
+        assert_eq!(model.dictionary_ancestors("G"), ["H"]);
+        assert!(model.dictionary_ancestors("Missing").is_empty());
+    }
+
+    #[test]
+    fn should_keep_other_definitions() {
+        let parsed = crate::parse(
+            "
+            callback interface Listener { undefined handle(); };
+            callback Handler = undefined ();
+            enum Mode { \"a\" };
+            typedef long Long;
+            typedef short Long;
+            Foo implements Bar;
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+
+        assert!(model.callback_interfaces.contains_key("Listener"));
+        assert!(model.callbacks.contains_key("Handler"));
+        assert!(model.enums.contains_key("Mode"));
+        assert!(std::ptr::eq(
+            model.typedefs["Long"],
+            match &parsed[3] {
+                Definition::Typedef(it) => it,
+                _ => unreachable!(),
+            }
+        ));
+        assert!(model.interfaces.is_empty());
+    }
+}
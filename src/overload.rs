@@ -0,0 +1,601 @@
+//! Overloaded operations and constructors
+//!
+//! The operations of a definition which share an identifier, and its
+//! constructors, form an overload set. When called, the overload to run is
+//! chosen from the effective overload set, which lists every count of
+//! arguments each overload can be called with. Among the entries taking the
+//! same count of arguments, the types at one index, the distinguishing
+//! argument index, must tell them apart.
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::model::Model;
+//! use weedle::overload::overload_sets;
+//!
+//! let parsed = weedle::parse("
+//!     interface Canvas {
+//!         undefined fill(optional DOMString rule);
+//!         undefined fill(Path path, optional DOMString rule);
+//!     };
+//!     interface Path {};
+//! ").unwrap();
+//! let model = Model::new(&parsed);
+//!
+//! let sets = overload_sets(&model.interfaces["Canvas"].members);
+//! assert_eq!(sets[0].name, Some("fill"));
+//!
+//! let effective = sets[0].effective(&model);
+//! let lengths: Vec<_> = effective.iter().map(|entry| entry.types.len()).collect();
+//! assert_eq!(lengths, [1, 0, 2, 1]);
+//!
+//! let entries: Vec<_> = effective.iter().filter(|entry| entry.types.len() == 1).collect();
+//! assert_eq!(model.distinguishing_argument_index(&entries), Some(0));
+//! ```
+
+use crate::argument::{Argument, ArgumentList};
+use crate::attribute::ExtendedAttribute;
+use crate::interface::{InterfaceMember, StringifierOrStatic};
+use crate::mixin::MixinMember;
+use crate::model::{Member, MemberNode, Model};
+use crate::namespace::NamespaceMember;
+use crate::resolve::{IdentifierKind, ResolvedType, ResolvedTypeKind};
+use crate::types::NonAnyType;
+
+/// What an overload set is made of
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum OverloadKind {
+    Operation,
+    StaticOperation,
+    Constructor,
+}
+
+/// The operations sharing an identifier, or the constructors, of a definition
+#[derive(Clone, Debug, PartialEq)]
+pub struct OverloadSet<'a> {
+    /// The identifier of the operations, `None` for constructors
+    pub name: Option<&'a str>,
+    pub kind: OverloadKind,
+    /// The overloads in the order they are merged in
+    pub members: Vec<Member<'a>>,
+}
+
+/// How an argument of an entry of an effective overload set is passed
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Optionality {
+    Required,
+    Optional,
+    Variadic,
+}
+
+/// An entry of an effective overload set: one count of arguments an overload
+/// can be called with
+#[derive(Clone, Debug, PartialEq)]
+pub struct EffectiveOverload<'a> {
+    /// The operation or constructor called
+    pub member: Member<'a>,
+    /// The types of the arguments, with their typedefs expanded
+    pub types: Vec<ResolvedType<'a>>,
+    pub optionality: Vec<Optionality>,
+}
+
+/// Groups the operations and constructors of `members` into overload sets,
+/// in the order of their first overload
+///
+/// Regular and static operations are grouped apart. Operations without an
+/// identifier, which are only special operations, are left out.
+pub fn overload_sets<'a>(members: &[Member<'a>]) -> Vec<OverloadSet<'a>> {
+    let mut sets: Vec<OverloadSet<'a>> = Vec::new();
+    for member in members {
+        let (name, kind) = match overload_kind(member) {
+            Some(it) => it,
+            None => continue,
+        };
+        if name.is_none() && kind != OverloadKind::Constructor {
+            continue;
+        }
+        match sets
+            .iter_mut()
+            .find(|set| set.name == name && set.kind == kind)
+        {
+            Some(set) => set.members.push(*member),
+            None => sets.push(OverloadSet {
+                name,
+                kind,
+                members: vec![*member],
+            }),
+        }
+    }
+    sets
+}
+
+/// The identifier and kind of the overload set `member` belongs to, if it is
+/// an operation or a constructor
+///
+/// Special operations with an identifier are also regular operations, so they
+/// overload the regular operations sharing it.
+pub(crate) fn overload_kind<'a>(member: &Member<'a>) -> Option<(Option<&'a str>, OverloadKind)> {
+    Some(match member.node {
+        MemberNode::Interface(InterfaceMember::Constructor(_)) => (None, OverloadKind::Constructor),
+        MemberNode::Interface(InterfaceMember::Operation(it)) => {
+            let kind = match it.modifier {
+                Some(StringifierOrStatic::Static(_)) => OverloadKind::StaticOperation,
+                _ => OverloadKind::Operation,
+            };
+            (it.identifier.map(|it| it.0), kind)
+        }
+        MemberNode::Mixin(MixinMember::Operation(it)) => {
+            (it.identifier.map(|it| it.0), OverloadKind::Operation)
+        }
+        MemberNode::Namespace(NamespaceMember::Operation(it)) => {
+            (it.identifier.map(|it| it.0), OverloadKind::Operation)
+        }
+        _ => return None,
+    })
+}
+
+/// The arguments of an operation or constructor
+fn arguments<'a>(member: &Member<'a>) -> &'a ArgumentList<'a> {
+    match member.node {
+        MemberNode::Interface(InterfaceMember::Constructor(it)) => &it.args.body,
+        MemberNode::Interface(InterfaceMember::Operation(it)) => &it.args.body,
+        MemberNode::Mixin(MixinMember::Operation(it)) => &it.args.body,
+        MemberNode::Namespace(NamespaceMember::Operation(it)) => &it.args.body,
+        _ => unreachable!("overload sets only hold operations and constructors"),
+    }
+}
+
+impl<'a> OverloadSet<'a> {
+    /// The effective overload set, as used to check the overloads can be
+    /// distinguished
+    pub fn effective(&self, model: &Model<'a>) -> Vec<EffectiveOverload<'a>> {
+        self.effective_for(model, 0)
+    }
+
+    /// The effective overload set for calls with `argument_count` arguments
+    ///
+    /// Entries are listed per overload: the overload as declared, then as
+    /// called with more variadic arguments, then with fewer optional ones.
+    pub fn effective_for(
+        &self,
+        model: &Model<'a>,
+        argument_count: usize,
+    ) -> Vec<EffectiveOverload<'a>> {
+        let max = self
+            .members
+            .iter()
+            .map(|member| arguments(member).list.len())
+            .max()
+            .unwrap_or(0)
+            .max(argument_count);
+
+        let mut entries = Vec::new();
+        for member in &self.members {
+            let arguments = &arguments(member).list;
+            let mut types = Vec::new();
+            let mut optionality = Vec::new();
+            for argument in arguments {
+                match argument {
+                    Argument::Single(it) => {
+                        types.push(model.resolve_attributed(&it.type_));
+                        optionality.push(if it.optional.is_some() {
+                            Optionality::Optional
+                        } else {
+                            Optionality::Required
+                        });
+                    }
+                    Argument::Variadic(it) => {
+                        types.push(model.resolve(&it.type_));
+                        optionality.push(Optionality::Variadic);
+                    }
+                }
+            }
+            let entry = |length: usize| EffectiveOverload {
+                member: *member,
+                types: types[..length].to_vec(),
+                optionality: optionality[..length].to_vec(),
+            };
+
+            let n = arguments.len();
+            entries.push(entry(n));
+            if let Some(Argument::Variadic(_)) = arguments.last() {
+                for length in n + 1..=max {
+                    let mut entry = entry(n);
+                    entry.types.resize(length, types[n - 1].clone());
+                    entry.optionality.resize(length, Optionality::Variadic);
+                    entries.push(entry);
+                }
+            }
+            for length in (0..n).rev() {
+                if optionality[length] == Optionality::Required {
+                    break;
+                }
+                entries.push(entry(length));
+            }
+        }
+        entries
+    }
+}
+
+/// The categories of types which tell which ones are distinguishable
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Category<'a> {
+    Undefined,
+    Boolean,
+    Numeric,
+    BigInt,
+    String,
+    Object,
+    Symbol,
+    /// Interfaces and buffer source types, by name
+    InterfaceLike(&'a str),
+    CallbackFunction(&'a str),
+    DictionaryLike,
+    SequenceLike,
+}
+
+/// Buffer source types which may hold any of several other ones
+const BUFFER_VIEWS: &[&str] = &[
+    "DataView",
+    "Int8Array",
+    "Int16Array",
+    "Int32Array",
+    "Uint8Array",
+    "Uint16Array",
+    "Uint32Array",
+    "Uint8ClampedArray",
+    "BigInt64Array",
+    "BigUint64Array",
+    "Float16Array",
+    "Float32Array",
+    "Float64Array",
+];
+
+impl<'a> Model<'a> {
+    /// Returns `true` if a JavaScript value can never be converted to both
+    /// `a` and `b`, following the distinguishability rules of WebIDL
+    ///
+    /// Identifiers which are not defined are taken to be interfaces.
+    pub fn distinguishable(&self, a: &ResolvedType<'a>, b: &ResolvedType<'a>) -> bool {
+        if (includes_nullable(a) && (includes_nullable(b) || self.has_dictionary(b)))
+            || (includes_nullable(b) && self.has_dictionary(a))
+        {
+            return false;
+        }
+        match (&a.kind, &b.kind) {
+            (ResolvedTypeKind::Union(members), _) => {
+                members.iter().all(|member| self.distinguishable(member, b))
+            }
+            (_, ResolvedTypeKind::Union(members)) => {
+                members.iter().all(|member| self.distinguishable(a, member))
+            }
+            _ => match (self.category(a), self.category(b)) {
+                (Some(a), Some(b)) => self.distinguishable_categories(a, b),
+                _ => false,
+            },
+        }
+    }
+
+    /// The lowest index at which the types of `entries`, which all have the
+    /// same count of types, are distinguishable from one another
+    ///
+    /// The types and optionality of the entries before that index must all
+    /// be the same. Returns `None` if there is no such index.
+    pub fn distinguishing_argument_index(
+        &self,
+        entries: &[&EffectiveOverload<'a>],
+    ) -> Option<usize> {
+        let length = entries.first()?.types.len();
+        for index in 0..length {
+            let distinguishable = entries.iter().enumerate().all(|(i, a)| {
+                entries[i + 1..]
+                    .iter()
+                    .all(|b| self.distinguishable(&a.types[index], &b.types[index]))
+            });
+            if distinguishable {
+                return Some(index);
+            }
+            let same = entries.iter().all(|entry| {
+                entry.types[index].to_string() == entries[0].types[index].to_string()
+                    && entry.optionality[index] == entries[0].optionality[index]
+            });
+            if !same {
+                return None;
+            }
+        }
+        None
+    }
+
+    fn has_dictionary(&self, type_: &ResolvedType<'a>) -> bool {
+        match &type_.kind {
+            ResolvedTypeKind::Identifier(_, IdentifierKind::Dictionary) => true,
+            ResolvedTypeKind::Union(members) => {
+                members.iter().any(|member| self.has_dictionary(member))
+            }
+            _ => false,
+        }
+    }
+
+    fn category(&self, type_: &ResolvedType<'a>) -> Option<Category<'a>> {
+        let category = match &type_.kind {
+            ResolvedTypeKind::Any | ResolvedTypeKind::Promise(_) | ResolvedTypeKind::Union(_) => {
+                return None
+            }
+            ResolvedTypeKind::Undefined => Category::Undefined,
+            ResolvedTypeKind::Sequence(_)
+            | ResolvedTypeKind::FrozenArray(_)
+            | ResolvedTypeKind::ObservableArray(_) => Category::SequenceLike,
+            ResolvedTypeKind::Record(..) => Category::DictionaryLike,
+            ResolvedTypeKind::Identifier(name, kind) => match kind {
+                IdentifierKind::Interface | IdentifierKind::Unknown => {
+                    Category::InterfaceLike(name)
+                }
+                IdentifierKind::CallbackInterface | IdentifierKind::Dictionary => {
+                    Category::DictionaryLike
+                }
+                IdentifierKind::Enum => Category::String,
+                IdentifierKind::CallbackFunction => Category::CallbackFunction(name),
+                IdentifierKind::Typedef => return None,
+            },
+            ResolvedTypeKind::Builtin(builtin) => match builtin {
+                NonAnyType::Integer(_)
+                | NonAnyType::FloatingPoint(_)
+                | NonAnyType::Byte(_)
+                | NonAnyType::Octet(_) => Category::Numeric,
+                NonAnyType::BigInt(_) => Category::BigInt,
+                NonAnyType::Boolean(_) => Category::Boolean,
+                NonAnyType::ByteString(_) | NonAnyType::DOMString(_) | NonAnyType::USVString(_) => {
+                    Category::String
+                }
+                NonAnyType::Object(_) => Category::Object,
+                NonAnyType::Symbol(_) => Category::Symbol,
+                NonAnyType::Error(_) => Category::InterfaceLike("Error"),
+                NonAnyType::ArrayBuffer(_) => Category::InterfaceLike("ArrayBuffer"),
+                NonAnyType::SharedArrayBuffer(_) => Category::InterfaceLike("SharedArrayBuffer"),
+                NonAnyType::DataView(_) => Category::InterfaceLike("DataView"),
+                NonAnyType::Int8Array(_) => Category::InterfaceLike("Int8Array"),
+                NonAnyType::Int16Array(_) => Category::InterfaceLike("Int16Array"),
+                NonAnyType::Int32Array(_) => Category::InterfaceLike("Int32Array"),
+                NonAnyType::Uint8Array(_) => Category::InterfaceLike("Uint8Array"),
+                NonAnyType::Uint16Array(_) => Category::InterfaceLike("Uint16Array"),
+                NonAnyType::Uint32Array(_) => Category::InterfaceLike("Uint32Array"),
+                NonAnyType::Uint8ClampedArray(_) => Category::InterfaceLike("Uint8ClampedArray"),
+                NonAnyType::BigInt64Array(_) => Category::InterfaceLike("BigInt64Array"),
+                NonAnyType::BigUint64Array(_) => Category::InterfaceLike("BigUint64Array"),
+                NonAnyType::Float16Array(_) => Category::InterfaceLike("Float16Array"),
+                NonAnyType::Float32Array(_) => Category::InterfaceLike("Float32Array"),
+                NonAnyType::Float64Array(_) => Category::InterfaceLike("Float64Array"),
+                NonAnyType::ArrayBufferView(_) => Category::InterfaceLike("ArrayBufferView"),
+                NonAnyType::BufferSource(_) => Category::InterfaceLike("BufferSource"),
+                NonAnyType::Promise(_)
+                | NonAnyType::Sequence(_)
+                | NonAnyType::FrozenArrayType(_)
+                | NonAnyType::ObservableArrayType(_)
+                | NonAnyType::RecordType(_)
+                | NonAnyType::Identifier(_) => return None,
+            },
+        };
+        Some(category)
+    }
+
+    fn distinguishable_categories(&self, a: Category<'a>, b: Category<'a>) -> bool {
+        use self::Category::*;
+
+        match (a, b) {
+            (InterfaceLike(a), InterfaceLike(b)) => !self.overlap(a, b) && !self.overlap(b, a),
+            (CallbackFunction(name), DictionaryLike) | (DictionaryLike, CallbackFunction(name)) => {
+                !self.treats_non_object_as_null(name)
+            }
+            (CallbackFunction(_), CallbackFunction(_)) => false,
+            (Undefined, DictionaryLike) | (DictionaryLike, Undefined) => false,
+            (Object, InterfaceLike(_))
+            | (InterfaceLike(_), Object)
+            | (Object, CallbackFunction(_))
+            | (CallbackFunction(_), Object)
+            | (Object, DictionaryLike)
+            | (DictionaryLike, Object)
+            | (Object, SequenceLike)
+            | (SequenceLike, Object) => false,
+            (a, b) => a != b,
+        }
+    }
+
+    /// Returns `true` if an object implementing `a` may implement `b` too
+    fn overlap(&self, a: &str, b: &str) -> bool {
+        a == b
+            || self.interface_ancestors(a).contains(&b)
+            || (a == "ArrayBufferView" && BUFFER_VIEWS.contains(&b))
+            || (a == "BufferSource"
+                && (b == "ArrayBuffer" || b == "ArrayBufferView" || BUFFER_VIEWS.contains(&b)))
+            || (a == "AllowSharedBufferSource"
+                && (b == "SharedArrayBuffer" || self.overlap("BufferSource", b)))
+    }
+
+    fn treats_non_object_as_null(&self, callback: &str) -> bool {
+        let attributes = self
+            .callbacks
+            .get(callback)
+            .and_then(|it| it.attributes.as_ref());
+        attributes
+            .into_iter()
+            .flat_map(|it| &it.body.list)
+            .any(|attribute| match attribute {
+                ExtendedAttribute::NoArgs(it) => (it.0).0 == "LegacyTreatNonObjectAsNull",
+                _ => false,
+            })
+    }
+}
+
+fn includes_nullable(type_: &ResolvedType) -> bool {
+    type_.nullable
+        || match &type_.kind {
+            ResolvedTypeKind::Union(members) => members.iter().any(includes_nullable),
+            _ => false,
+        }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::types::Type;
+    use crate::Parse;
+
+    const DEFINITIONS: &str = "
+        interface Node {};
+        interface Element : Node {};
+        interface Text : Node {};
+        dictionary Options {};
+        callback interface Listener { undefined handleEvent(); };
+        callback Callback = undefined ();
+        [LegacyTreatNonObjectAsNull] callback Handler = undefined ();
+        enum Mode { \"a\" };
+        typedef (Element or DOMString) ElementOrString;
+    ";
+
+    fn distinguishable(a: &str, b: &str) -> bool {
+        let parsed = crate::parse(DEFINITIONS).unwrap();
+        let model = Model::new(&parsed);
+        let (_, a) = Type::parse(a).unwrap();
+        let (_, b) = Type::parse(b).unwrap();
+        model.distinguishable(&model.resolve(&a), &model.resolve(&b))
+    }
+
+    #[test]
+    fn should_distinguish_categories() {
+        assert!(distinguishable("long", "DOMString"));
+        assert!(distinguishable("long", "bigint"));
+        assert!(distinguishable("BigInt64Array", "BigUint64Array"));
+        assert!(distinguishable("boolean", "Mode"));
+        assert!(distinguishable("Element", "Text"));
+        assert!(distinguishable("Element", "sequence<long>"));
+        assert!(distinguishable("Options", "sequence<long>"));
+        assert!(distinguishable("Callback", "Options"));
+        assert!(distinguishable("ArrayBuffer", "Uint8Array"));
+        assert!(distinguishable("Node?", "DOMString"));
+        assert!(distinguishable("ElementOrString", "sequence<Node>"));
+        assert!(distinguishable("ElementOrString", "Text?"));
+
+        assert!(!distinguishable("long", "double"));
+        assert!(!distinguishable("DOMString", "Mode"));
+        assert!(!distinguishable("Node", "Element"));
+        assert!(!distinguishable("object", "Node"));
+        assert!(!distinguishable("Options", "record<DOMString, long>"));
+        assert!(!distinguishable("Listener", "Options"));
+        assert!(!distinguishable("Handler", "Options"));
+        assert!(!distinguishable("Callback", "Handler"));
+        assert!(!distinguishable("FrozenArray<long>", "sequence<long>"));
+        assert!(!distinguishable("BufferSource", "Uint8Array"));
+        assert!(!distinguishable("ArrayBufferView", "BigInt64Array"));
+        assert!(!distinguishable("AllowSharedBufferSource", "Float16Array"));
+        assert!(distinguishable("BufferSource", "SharedArrayBuffer"));
+        assert!(!distinguishable("any", "long"));
+        assert!(!distinguishable("Node?", "DOMString?"));
+        assert!(!distinguishable("Node?", "Options"));
+        assert!(!distinguishable("ElementOrString?", "Text?"));
+        assert!(!distinguishable("ElementOrString", "Node"));
+    }
+
+    #[test]
+    fn should_group_overloads() {
+        let parsed = crate::parse(
+            "
+            interface Foo {
+                constructor();
+                undefined f();
+                static undefined f(long a);
+                getter long (unsigned long index);
+                undefined g();
+                constructor(long a);
+                undefined f(long a);
+            };
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+
+        let sets = overload_sets(&model.interfaces["Foo"].members);
+        let found: Vec<_> = sets
+            .iter()
+            .map(|set| (set.name, set.kind, set.members.len()))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (None, OverloadKind::Constructor, 2),
+                (Some("f"), OverloadKind::Operation, 2),
+                (Some("f"), OverloadKind::StaticOperation, 1),
+                (Some("g"), OverloadKind::Operation, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_compute_effective_overload_sets() {
+        let parsed = crate::parse(
+            "
+            interface Foo {
+                undefined f(DOMString a);
+                undefined f(Node a, DOMString b, optional long c, long... d);
+                undefined f(long a, optional DOMString b, optional DOMString c);
+            };
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+        let set = &overload_sets(&model.interfaces["Foo"].members)[0];
+
+        let entries: Vec<_> = set
+            .effective_for(&model, 5)
+            .iter()
+            .map(|entry| {
+                let types: Vec<_> = entry.types.iter().map(|it| it.to_string()).collect();
+                (types.join(", "), entry.optionality.last().copied())
+            })
+            .collect();
+        let variadic = Some(Optionality::Variadic);
+        let optional = Some(Optionality::Optional);
+        let required = Some(Optionality::Required);
+        assert_eq!(
+            entries,
+            [
+                ("DOMString".to_string(), required),
+                ("Node, DOMString, long, long".to_string(), variadic),
+                ("Node, DOMString, long, long, long".to_string(), variadic),
+                ("Node, DOMString, long".to_string(), optional),
+                ("Node, DOMString".to_string(), required),
+                ("long, DOMString, DOMString".to_string(), optional),
+                ("long, DOMString".to_string(), optional),
+                ("long".to_string(), required),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_find_distinguishing_argument_index() {
+        let parsed = crate::parse(
+            "
+            interface Foo {
+                undefined f(long a, DOMString b);
+                undefined f(long a, Node b);
+                undefined g(long a, DOMString b);
+                undefined g(double a, Node b);
+            };
+            interface Node {};
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+        let sets = overload_sets(&model.interfaces["Foo"].members);
+
+        let f = sets[0].effective(&model);
+        assert_eq!(
+            model.distinguishing_argument_index(&f.iter().collect::<Vec<_>>()),
+            Some(1)
+        );
+        let g = sets[1].effective(&model);
+        assert_eq!(
+            model.distinguishing_argument_index(&g.iter().collect::<Vec<_>>()),
+            None
+        );
+    }
+}
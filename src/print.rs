@@ -0,0 +1,793 @@
+//! Writes the syntax tree back as canonically formatted WebIDL
+//!
+//! Every node of the syntax tree implements `Display` with the formatting
+//! below, and [`ToWebIdl`](trait.ToWebIdl.html) does the same for lists of
+//! nodes like [`Definitions`](../type.Definitions.html).
+//!
+//! - definitions are separated by a blank line
+//! - members, and enum values, go on their own line, indented by four spaces
+//! - extended attributes of a definition go on the line before it, those of
+//!   a member or argument on the same line
+//! - tokens are separated by a single space, except around punctuation
+//! - identifiers named after a keyword are escaped with `_`, as are those
+//!   which were written escaped
+//!
+//! The output re-parses into a syntax tree equal to the one printed. The
+//! indentation and the wrapping of long extended attribute lists can be
+//! changed through [`Options`](struct.Options.html), and
+//! [`format`](fn.format.html) formats a whole file keeping its comments.
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::print::ToWebIdl;
+//!
+//! let parsed = weedle::parse("
+//!     [Exposed=Window] interface Window{readonly attribute Storage sessionStorage ;};
+//! ").unwrap();
+//!
+//! assert_eq!(
+//!     parsed.to_webidl(),
+//!     "[Exposed=Window]\ninterface Window {\n    readonly attribute Storage sessionStorage;\n};"
+//! );
+//! ```
+
+use std::collections::HashMap;
+
+use crate::lossless::SyntaxTree;
+use crate::term::KEYWORDS;
+use crate::token::{Token, TokenKind, Tokens};
+use crate::Error;
+
+/// Implemented by every node of the syntax tree, and lists of them
+pub trait ToWebIdl {
+    /// Formats the node as WebIDL
+    fn to_webidl(&self) -> String {
+        self.to_webidl_with(&Options::default())
+    }
+
+    /// Formats the node as WebIDL, laid out as given by `options`
+    fn to_webidl_with(&self, options: &Options) -> String;
+}
+
+impl<'a, T: Tokens<'a> + ?Sized> ToWebIdl for T {
+    fn to_webidl_with(&self, options: &Options) -> String {
+        Printer::new(options, None).run(&self.to_tokens())
+    }
+}
+
+/// How to lay out the printed WebIDL
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Options {
+    /// Written once per level of nesting, four spaces by default
+    pub indent: String,
+    /// Extended attribute lists which would make a line longer than this many
+    /// characters are written one attribute per line. They are never wrapped
+    /// by default.
+    pub wrap_width: Option<usize>,
+    /// Whether [`format`](fn.format.html) keeps the comments of its input,
+    /// which it does by default
+    pub comments: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            indent: "    ".to_string(),
+            wrap_width: None,
+            comments: true,
+        }
+    }
+}
+
+/// Formats a whole WebIDL file
+///
+/// Definitions and members are kept in the order they are written. Comments
+/// are kept next to the token they were written next to, unless disabled in
+/// `options`. The output ends with a newline.
+///
+/// ### Example
+///
+/// ```
+/// use weedle::print::{format, Options};
+///
+/// let options = Options {
+///     indent: "  ".to_string(),
+///     ..Options::default()
+/// };
+/// let formatted = format("interface Foo{  // Foo
+///     attribute long bar;};", &options).unwrap();
+///
+/// assert_eq!(formatted, "interface Foo { // Foo\n  attribute long bar;\n};\n");
+/// ```
+pub fn format(source: &str, options: &Options) -> Result<String, Error> {
+    let tree = SyntaxTree::parse(source)?;
+    let comments = if options.comments {
+        Some(Comments::new(&tree))
+    } else {
+        None
+    };
+    let mut out = Printer::new(options, comments).run(&tree.definitions().to_tokens());
+    if !out.is_empty() {
+        out.push('\n');
+    }
+    Ok(out)
+}
+
+/// Keywords which can name an argument, attribute or operation unescaped
+const NAME_KEYWORDS: &[&str] = &[
+    "async",
+    "attribute",
+    "callback",
+    "const",
+    "constructor",
+    "deleter",
+    "dictionary",
+    "enum",
+    "getter",
+    "includes",
+    "inherit",
+    "interface",
+    "iterable",
+    "maplike",
+    "mixin",
+    "namespace",
+    "partial",
+    "readonly",
+    "required",
+    "setlike",
+    "setter",
+    "static",
+    "stringifier",
+    "typedef",
+    "unrestricted",
+];
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Delimiter {
+    Paren,
+    Bracket,
+    /// Brackets of an extended attribute list too long to fit on a line
+    WrappedBracket,
+    Angle,
+    /// Braces around members
+    Block,
+    /// Braces around enum values
+    EnumBlock,
+    /// Braces of an empty dictionary literal
+    EmptyDictionary,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
+enum Separator {
+    Nothing,
+    Space,
+    Newline,
+    BlankLine,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Comment<'a> {
+    text: &'a str,
+    /// Preceded by a line break
+    own_line: bool,
+    /// Followed by a line break
+    breaks: bool,
+    /// Followed by a blank line
+    spaced: bool,
+}
+
+impl<'a> Comment<'a> {
+    fn is_line(&self) -> bool {
+        self.text.starts_with("//")
+    }
+}
+
+/// The comments of a parsed input, by the offset of the token they are
+/// written before or after
+#[derive(Default)]
+struct Comments<'a> {
+    before: HashMap<usize, Vec<Comment<'a>>>,
+    after: HashMap<usize, Vec<Comment<'a>>>,
+    end: Vec<Comment<'a>>,
+    source: &'a str,
+}
+
+impl<'a> Comments<'a> {
+    fn new(tree: &SyntaxTree<'a>) -> Self {
+        let source = tree.source();
+        let mut printed: Vec<_> = tree
+            .definitions()
+            .to_tokens()
+            .iter()
+            .filter(|token| !token.span.is_empty())
+            .filter_map(|token| token.span.range(source))
+            .collect();
+        printed.sort_by_key(|range| range.start);
+
+        // Comments around the separators which are not printed from the
+        // tree go with the printed tokens next to them
+        let mut comments = Comments {
+            source,
+            ..Comments::default()
+        };
+        let mut next = 0;
+        let mut last = None;
+        for token in tree.tokens() {
+            let range = match token.span.range(source) {
+                Some(range) => range,
+                None => continue,
+            };
+            let is_printed = printed.get(next) == Some(&range);
+            if is_printed {
+                next += 1;
+            }
+            let before = extract(token.leading_trivia);
+            if !before.is_empty() {
+                let comments = match printed.get(next - is_printed as usize) {
+                    Some(token) => comments.before.entry(token.start).or_default(),
+                    None => &mut comments.end,
+                };
+                comments.extend(before);
+            }
+            if is_printed {
+                last = Some(range.end);
+            }
+            // Only the trailing trivia of the last token goes past the end of
+            // its line
+            for comment in extract(token.trailing_trivia) {
+                let comments = match last {
+                    _ if comment.own_line => &mut comments.end,
+                    Some(end) => comments.after.entry(end).or_default(),
+                    None => comments.before.entry(printed[0].start).or_default(),
+                };
+                comments.push(comment);
+            }
+        }
+        comments
+    }
+}
+
+// The comments in `trivia`, which holds nothing but whitespace and comments
+fn extract(trivia: &str) -> Vec<Comment<'_>> {
+    let mut comments = Vec::new();
+    let mut offset = 0;
+    while offset < trivia.len() {
+        let rest = &trivia[offset..];
+        let len = trivia_len(rest).max(1);
+        if rest.starts_with("//") || rest.starts_with("/*") {
+            let text = rest[..len].trim_end();
+            let after = &rest[text.len()..];
+            let whitespace = after.len() - after.trim_start().len();
+            comments.push(Comment {
+                text,
+                own_line: trivia[..offset].contains('\n'),
+                breaks: text.starts_with("//") || after[..whitespace].contains('\n'),
+                spaced: after[..whitespace].matches('\n').count() > 1,
+            });
+        }
+        offset += len;
+    }
+    comments
+}
+
+struct Printer<'a, 'o> {
+    options: &'o Options,
+    comments: Option<Comments<'a>>,
+    out: String,
+    open: Vec<Delimiter>,
+    previous: Option<TokenKind<'a>>,
+    in_enum: bool,
+    line_start: bool,
+    pending: Vec<Comment<'a>>,
+}
+
+impl<'a, 'o> Printer<'a, 'o> {
+    fn new(options: &'o Options, comments: Option<Comments<'a>>) -> Self {
+        Printer {
+            options,
+            comments,
+            out: String::new(),
+            open: Vec::new(),
+            previous: None,
+            in_enum: false,
+            line_start: true,
+            pending: Vec::new(),
+        }
+    }
+
+    fn run(mut self, tokens: &[Token<'a>]) -> String {
+        for (i, token) in tokens.iter().enumerate() {
+            self.print(tokens, i);
+            let source = self.comments.as_ref().map(|comments| comments.source);
+            let range = source
+                .filter(|_| !token.span.is_empty())
+                .and_then(|source| token.span.range(source));
+            if let Some(range) = range {
+                if let Some(after) = self
+                    .comments
+                    .as_mut()
+                    .and_then(|c| c.after.remove(&range.end))
+                {
+                    self.pending.extend(after);
+                }
+            }
+        }
+
+        for comment in std::mem::take(&mut self.pending) {
+            self.out.push(' ');
+            self.out.push_str(comment.text);
+        }
+        let end = self
+            .comments
+            .as_mut()
+            .map(|comments| std::mem::take(&mut comments.end))
+            .unwrap_or_default();
+        for comment in end {
+            if !self.out.is_empty() {
+                self.out.push('\n');
+            }
+            self.out.push_str(comment.text);
+        }
+        self.out
+    }
+
+    fn print(&mut self, tokens: &[Token<'a>], i: usize) {
+        let token = tokens[i];
+        let kind = token.kind;
+        let closed = match kind {
+            TokenKind::Term(")")
+            | TokenKind::Term("]")
+            | TokenKind::Term(">")
+            | TokenKind::Term("}") => self.open.pop(),
+            _ => None,
+        };
+
+        let mut separator = match self.previous {
+            Some(previous) => self.separator(previous, kind, closed),
+            None => Separator::Nothing,
+        };
+        // An empty block is only written on one line if there is no comment
+        // inside it
+        if is_block(closed) && self.has_comments_before(token) {
+            separator = separator.max(Separator::Newline);
+        }
+        self.write_pending(separator, kind);
+
+        if let Some(comments) = &mut self.comments {
+            let range = Some(token.span)
+                .filter(|span| !span.is_empty())
+                .and_then(|span| span.range(comments.source));
+            if let Some(range) = range {
+                if let Some(before) = comments.before.remove(&range.start) {
+                    // Comments before a closing brace are inside the block
+                    let nested = is_block(closed);
+                    for comment in before {
+                        self.write_comment(comment, nested);
+                    }
+                }
+            }
+        }
+
+        let next = tokens.get(i + 1).map(|next| next.kind);
+        match kind {
+            TokenKind::Identifier(name) if token.escaped || needs_escape(name, next) => {
+                self.out.push('_');
+                self.out.push_str(name);
+            }
+            _ => self.out.push_str(&kind.to_string()),
+        }
+        self.line_start = false;
+
+        match kind {
+            TokenKind::Term("(") => self.open.push(Delimiter::Paren),
+            TokenKind::Term("[") => {
+                let delimiter = if self.wraps(tokens, i) {
+                    Delimiter::WrappedBracket
+                } else {
+                    Delimiter::Bracket
+                };
+                self.open.push(delimiter);
+            }
+            TokenKind::Term("<") => self.open.push(Delimiter::Angle),
+            TokenKind::Term("{") => {
+                let delimiter = if self.previous == Some(TokenKind::Term("=")) {
+                    Delimiter::EmptyDictionary
+                } else if self.in_enum {
+                    Delimiter::EnumBlock
+                } else {
+                    Delimiter::Block
+                };
+                self.open.push(delimiter);
+                self.in_enum = false;
+            }
+            TokenKind::Term("enum") if self.open.is_empty() => self.in_enum = true,
+            _ => {}
+        }
+        self.previous = Some(kind);
+    }
+
+    fn has_comments_before(&self, token: Token<'a>) -> bool {
+        match &self.comments {
+            Some(comments) if !token.span.is_empty() => matches!(
+                token.span.range(comments.source),
+                Some(range) if comments.before.contains_key(&range.start)
+            ),
+            _ => false,
+        }
+    }
+
+    fn separator(
+        &self,
+        previous: TokenKind<'a>,
+        next: TokenKind<'a>,
+        closed: Option<Delimiter>,
+    ) -> Separator {
+        let top = self.open.last().cloned();
+        let (previous, next) = match (previous, next) {
+            (TokenKind::Term(previous), TokenKind::Term(next)) => (previous, next),
+            (TokenKind::Term(previous), _) => (previous, ""),
+            (_, TokenKind::Term(next)) => ("", next),
+            _ => ("", ""),
+        };
+
+        let newline = match (previous, next) {
+            (";", _) if top.is_none() && closed.is_none() => return Separator::BlankLine,
+            (";", _) => true,
+            ("{", "}") => false,
+            (_, "}") | (_, "]") => is_block(closed),
+            ("{", _) | ("[", _) | (",", _) => is_block(top),
+            ("]", _) => top.is_none(),
+            _ => false,
+        };
+        if newline {
+            return Separator::Newline;
+        }
+
+        let space = match (previous, next) {
+            (_, ",") | (_, ";") | (_, ")") | (_, "]") | (_, ">") | (_, "?") | (_, "...") => false,
+            ("(", _) | ("[", _) | ("<", _) | (_, "<") => false,
+            ("{", "}") => false,
+            ("=", _) | (_, "=") => !is_bracket(top),
+            // Argument lists stick to the name or type before them, union
+            // types do not
+            (_, "(") => [
+                "attribute",
+                "typedef",
+                "optional",
+                "required",
+                "or",
+                "static",
+                "stringifier",
+                "getter",
+                "setter",
+                "deleter",
+                "legacycaller",
+                "]",
+                ",",
+            ]
+            .contains(&previous),
+            _ => true,
+        };
+        if space {
+            Separator::Space
+        } else {
+            Separator::Nothing
+        }
+    }
+
+    // Writes the comments left at the end of the previous token, unless the
+    // next token sticks to it, then separates it from the next token
+    fn write_pending(&mut self, separator: Separator, next: TokenKind<'a>) {
+        let sticks = match next {
+            TokenKind::Term(next) => [",", ";", ")", "]", ">", "?", "..."].contains(&next),
+            _ => false,
+        };
+        if self.pending.is_empty() || (separator == Separator::Nothing && sticks) {
+            self.write_separator(separator);
+            return;
+        }
+
+        let pending = std::mem::take(&mut self.pending);
+        let last = pending.len() - 1;
+        for (i, comment) in pending.into_iter().enumerate() {
+            self.out.push(' ');
+            self.out.push_str(comment.text);
+            if comment.is_line() && (i < last || separator < Separator::Newline) {
+                self.write_continuation();
+            }
+        }
+        match separator {
+            Separator::Nothing | Separator::Space if !self.line_start => self.out.push(' '),
+            Separator::Nothing | Separator::Space => {}
+            _ => self.write_separator(separator),
+        }
+    }
+
+    fn write_comment(&mut self, comment: Comment<'a>, nested: bool) {
+        if self.line_start && nested {
+            self.out.push_str(&self.options.indent);
+        }
+        self.out.push_str(comment.text);
+        if self.line_start && comment.breaks {
+            if comment.spaced {
+                self.out.push('\n');
+            }
+            self.write_separator(Separator::Newline);
+        } else if comment.is_line() {
+            self.write_continuation();
+        } else {
+            self.out.push(' ');
+            self.line_start = false;
+        }
+    }
+
+    fn write_separator(&mut self, separator: Separator) {
+        match separator {
+            Separator::Nothing => {}
+            Separator::Space => self.out.push(' '),
+            Separator::BlankLine | Separator::Newline => {
+                if separator == Separator::BlankLine {
+                    self.out.push('\n');
+                }
+                self.out.push('\n');
+                for _ in 0..self.depth() {
+                    self.out.push_str(&self.options.indent);
+                }
+                self.line_start = true;
+            }
+        }
+    }
+
+    // A line comment ended a line which was not meant to end there
+    fn write_continuation(&mut self) {
+        self.write_separator(Separator::Newline);
+        self.out.push_str(&self.options.indent);
+    }
+
+    // Whether the extended attribute list opened at `tokens[i]` is too long
+    fn wraps(&self, tokens: &[Token<'a>], i: usize) -> bool {
+        let width = match self.options.wrap_width {
+            Some(width) => width,
+            None => return false,
+        };
+        if self.previous == Some(TokenKind::Term("=")) {
+            return false;
+        }
+
+        let mut depth = 0;
+        let mut end = i;
+        for (j, token) in tokens.iter().enumerate().skip(i) {
+            match token.kind {
+                TokenKind::Term("[") => depth += 1,
+                TokenKind::Term("]") => depth -= 1,
+                _ => {}
+            }
+            if depth == 0 {
+                end = j;
+                break;
+            }
+        }
+        let options = Options {
+            wrap_width: None,
+            ..self.options.clone()
+        };
+        let list = Printer::new(&options, None).run(&tokens[i..=end]);
+        let column = self.out.len() - self.out.rfind('\n').map_or(0, |i| i + 1);
+        column + list.chars().count() > width
+    }
+
+    fn depth(&self) -> usize {
+        self.open
+            .iter()
+            .filter(|open| is_block(Some(**open)))
+            .count()
+    }
+}
+
+fn needs_escape(name: &str, next: Option<TokenKind>) -> bool {
+    let is_name = match next {
+        Some(TokenKind::Term(next)) => [";", ",", ")", "=", "("].contains(&next),
+        _ => false,
+    };
+    KEYWORDS.contains(&name) && !(is_name && NAME_KEYWORDS.contains(&name))
+}
+
+fn is_block(delimiter: Option<Delimiter>) -> bool {
+    matches!(
+        delimiter,
+        Some(Delimiter::Block) | Some(Delimiter::EnumBlock) | Some(Delimiter::WrappedBracket)
+    )
+}
+
+fn is_bracket(delimiter: Option<Delimiter>) -> bool {
+    delimiter == Some(Delimiter::Bracket) || delimiter == Some(Delimiter::WrappedBracket)
+}
+
+fn is_whitespace(c: char) -> bool {
+    c == '\t' || c == '\n' || c == '\r' || c == ' '
+}
+
+// Length of the whitespace run or comment `input` starts with
+fn trivia_len(input: &str) -> usize {
+    if input.starts_with("//") {
+        input.find('\n').map_or(input.len(), |i| i + 1)
+    } else if let Some(comment) = input.strip_prefix("/*") {
+        comment.find("*/").map_or(input.len(), |i| i + 4)
+    } else {
+        input.len() - input.trim_start_matches(is_whitespace).len()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Parse;
+
+    fn reformat(input: &str) -> String {
+        crate::parse(input).unwrap().to_webidl()
+    }
+
+    #[test]
+    fn should_print_interfaces() {
+        assert_eq!(
+            reformat(
+                "[Exposed=(Window,Worker), SecureContext]interface Foo:Bar{
+                    constructor(optional long x=5);
+                    [SameObject] readonly attribute FrozenArray<DOMString>? names;
+                    getter any(DOMString name);
+                    Promise<undefined> run(sequence<long?> a, long... rest);
+                    const unsigned long long MAX = 0xFF;
+                    iterable<DOMString, long>;
+                };"
+            ),
+            "[Exposed=(Window, Worker), SecureContext]
+interface Foo : Bar {
+    constructor(optional long x = 5);
+    [SameObject] readonly attribute FrozenArray<DOMString>? names;
+    getter any(DOMString name);
+    Promise<undefined> run(sequence<long?> a, long... rest);
+    const unsigned long long MAX = 0xFF;
+    iterable<DOMString, long>;
+};"
+        );
+    }
+
+    #[test]
+    fn should_print_definitions_apart() {
+        assert_eq!(
+            reformat(
+                "enum E{\"a\",\"b\"};dictionary D{required long x;sequence<long> y=[];D z={};};
+                 typedef (long or DOMString) T; A includes B; interface mixin M {};"
+            ),
+            "enum E {
+    \"a\",
+    \"b\"
+};
+
+dictionary D {
+    required long x;
+    sequence<long> y = [];
+    D z = {};
+};
+
+typedef (long or DOMString) T;
+
+A includes B;
+
+interface mixin M {};"
+        );
+    }
+
+    #[test]
+    fn should_space_union_types_only() {
+        assert_eq!(
+            reformat(
+                "interface A{getter(long or Node)(long i);setter undefined(long i,(long or Node)n);};"
+            ),
+            "interface A {
+    getter (long or Node)(long i);
+    setter undefined(long i, (long or Node) n);
+};"
+        );
+    }
+
+    #[test]
+    fn should_keep_async_iterable_spelling() {
+        assert_eq!(
+            reformat(
+                "interface A{async iterable<long>;};interface B{async_iterable<long>(long x);};"
+            ),
+            "interface A {
+    async iterable<long>;
+};
+
+interface B {
+    async_iterable<long>(long x);
+};"
+        );
+    }
+
+    #[test]
+    fn should_escape_keywords() {
+        assert_eq!(
+            reformat("interface _interface { attribute _long _required; getter _long(); };"),
+            "interface _interface {\n    attribute _long _required;\n    getter _long();\n};"
+        );
+        assert_eq!(
+            reformat("interface A { undefined f(long interface, any... required); };"),
+            "interface A {\n    undefined f(long interface, any... required);\n};"
+        );
+    }
+
+    #[test]
+    fn should_print_allow_shared_buffer_source_as_identifier() {
+        let input = "typedef (ArrayBuffer or SharedArrayBuffer) AllowSharedBufferSource;";
+        assert_eq!(reformat(input), input);
+        assert_eq!(
+            reformat("typedef AllowSharedBufferSource Source;"),
+            "typedef AllowSharedBufferSource Source;"
+        );
+    }
+
+    #[test]
+    fn should_not_escape_typedefs_of_the_spec() {
+        for input in &[
+            "typedef long BufferSource;",
+            "typedef long ArrayBufferView;",
+            "interface BufferSource {\n    attribute BufferSource BufferSource;\n};",
+        ] {
+            assert_eq!(reformat(input), *input);
+        }
+    }
+
+    #[test]
+    fn should_display_nodes() {
+        let (_, parsed) = crate::types::Type::parse("record<DOMString, (long or Foo)?>").unwrap();
+        assert_eq!(parsed.to_string(), "record<DOMString, (long or Foo)?>");
+
+        let (_, parsed) = crate::literal::DefaultValue::parse("-Infinity").unwrap();
+        assert_eq!(parsed.to_string(), "-Infinity");
+    }
+
+    #[test]
+    fn should_keep_comments() {
+        let options = Options::default();
+        assert_eq!(
+            format(
+                "// License\n\n// Foo\ninterface Foo { // Opening\n/* x */ attribute long x;\n\
+                 void bar(long a, // A\n long b); /* Bar */\n// Last\n};\n// End",
+                &options
+            )
+            .unwrap(),
+            "// License\n\n// Foo\ninterface Foo { // Opening\n    /* x */ attribute long x;\n    \
+             void bar(long a, // A\n        long b); /* Bar */\n    // Last\n};\n// End\n"
+        );
+
+        let options = Options {
+            comments: false,
+            ..Options::default()
+        };
+        assert_eq!(
+            format("// Foo\ntypedef long /* x */ Foo;", &options).unwrap(),
+            "typedef long Foo;\n"
+        );
+    }
+
+    #[test]
+    fn should_wrap_long_attribute_lists() {
+        let options = Options {
+            indent: "  ".to_string(),
+            wrap_width: Some(30),
+            ..Options::default()
+        };
+        assert_eq!(
+            format(
+                "[Exposed=Window, SecureContext] interface Foo { [A, B] attribute long x; };",
+                &options
+            )
+            .unwrap(),
+            "[\n  Exposed=Window,\n  SecureContext\n]\ninterface Foo {\n  [A, B] attribute long x;\n};\n"
+        );
+    }
+}
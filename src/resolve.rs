@@ -0,0 +1,434 @@
+//! Typedef resolution
+//!
+//! A typedef only gives a new name to a type, which is used as an identifier
+//! type anywhere a type can be written. Resolving a type against a
+//! [`Model`](../model/struct.Model.html) expands every typedef it uses, at any
+//! depth, and tells what definition each remaining identifier refers to. The
+//! extended attributes and nullability of the typedefs are carried over to
+//! the types they are expanded to.
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::model::Model;
+//! use weedle::resolve::{IdentifierKind, ResolvedTypeKind};
+//! use weedle::Definition;
+//!
+//! let parsed = weedle::parse("
+//!     typedef (Node or DOMString) NodeOrString;
+//!     typedef [EnforceRange] NodeOrString? MaybeNode;
+//!     interface Node {
+//!         undefined append(MaybeNode node);
+//!     };
+//! ").unwrap();
+//! let model = Model::new(&parsed);
+//!
+//! let typedef = match &parsed[1] {
+//!     Definition::Typedef(typedef) => typedef,
+//!     _ => unreachable!(),
+//! };
+//! let resolved = model.resolve_attributed(&typedef.type_);
+//! assert!(resolved.nullable);
+//! assert_eq!(resolved.attributes.len(), 1);
+//! assert_eq!(resolved.typedefs, ["NodeOrString"]);
+//!
+//! match &resolved.kind {
+//!     ResolvedTypeKind::Union(members) => {
+//!         assert_eq!(members[0].kind, ResolvedTypeKind::Identifier("Node", IdentifierKind::Interface));
+//!     }
+//!     _ => unreachable!(),
+//! }
+//! ```
+
+use std::fmt;
+
+use crate::attribute::{ExtendedAttribute, ExtendedAttributeList};
+use crate::model::Model;
+use crate::print::ToWebIdl;
+use crate::types::{
+    AttributedNonAnyType, AttributedType, MayBeNull, NonAnyType, RecordKeyType, ReturnType,
+    SingleType, Type, UnionMemberType, UnionType,
+};
+
+/// What an identifier refers to when used as a type
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum IdentifierKind {
+    Interface,
+    CallbackInterface,
+    Dictionary,
+    Enum,
+    CallbackFunction,
+    /// Only given by [`Model::classify`](../model/struct.Model.html#method.classify),
+    /// resolved types have their typedefs expanded
+    Typedef,
+    /// Nothing of that name which can be used as a type is defined
+    Unknown,
+}
+
+/// A type with its typedefs expanded
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResolvedType<'a> {
+    /// The extended attributes written where the type is used, then those of
+    /// the typedefs expanded
+    pub attributes: Vec<&'a ExtendedAttribute<'a>>,
+    /// Whether the type or one of the typedefs expanded is nullable
+    pub nullable: bool,
+    /// The names of the typedefs expanded, outermost first
+    pub typedefs: Vec<&'a str>,
+    pub kind: ResolvedTypeKind<'a>,
+}
+
+/// The kinds of resolved types
+#[derive(Clone, Debug, PartialEq)]
+pub enum ResolvedTypeKind<'a> {
+    Any,
+    Undefined,
+    /// Any other type which is not generic, as written: `long`, `DOMString`,
+    /// `object`, `ArrayBuffer`...
+    Builtin(&'a NonAnyType<'a>),
+    Promise(Box<ResolvedType<'a>>),
+    Sequence(Box<ResolvedType<'a>>),
+    FrozenArray(Box<ResolvedType<'a>>),
+    ObservableArray(Box<ResolvedType<'a>>),
+    Record(&'a RecordKeyType<'a>, Box<ResolvedType<'a>>),
+    /// The member types of a union, nested unions are kept as they are
+    Union(Vec<ResolvedType<'a>>),
+    /// An identifier which is not a typedef. It is `Unknown` for typedefs
+    /// which refer to themselves.
+    Identifier(&'a str, IdentifierKind),
+}
+
+impl<'a> ResolvedType<'a> {
+    fn new(kind: ResolvedTypeKind<'a>) -> Self {
+        ResolvedType {
+            attributes: Vec::new(),
+            nullable: false,
+            typedefs: Vec::new(),
+            kind,
+        }
+    }
+
+    fn nullable(mut self, nullable: bool) -> Self {
+        self.nullable |= nullable;
+        self
+    }
+
+    fn attributed(mut self, attributes: &'a Option<ExtendedAttributeList<'a>>) -> Self {
+        if let Some(attributes) = attributes {
+            let mut all: Vec<_> = attributes.body.list.iter().collect();
+            all.append(&mut self.attributes);
+            self.attributes = all;
+        }
+        self
+    }
+}
+
+/// Writes the type as WebIDL, without its extended attributes
+impl<'a> fmt::Display for ResolvedType<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.kind {
+            ResolvedTypeKind::Any => f.write_str("any")?,
+            ResolvedTypeKind::Undefined => f.write_str("undefined")?,
+            ResolvedTypeKind::Builtin(type_) => {
+                f.write_str(type_.to_webidl().trim_end_matches('?'))?
+            }
+            ResolvedTypeKind::Promise(type_) => write!(f, "Promise<{}>", type_)?,
+            ResolvedTypeKind::Sequence(type_) => write!(f, "sequence<{}>", type_)?,
+            ResolvedTypeKind::FrozenArray(type_) => write!(f, "FrozenArray<{}>", type_)?,
+            ResolvedTypeKind::ObservableArray(type_) => write!(f, "ObservableArray<{}>", type_)?,
+            ResolvedTypeKind::Record(key, value) => {
+                write!(f, "record<{}, {}>", key.to_webidl(), value)?
+            }
+            ResolvedTypeKind::Union(members) => {
+                f.write_str("(")?;
+                for (i, member) in members.iter().enumerate() {
+                    if i > 0 {
+                        f.write_str(" or ")?;
+                    }
+                    write!(f, "{}", member)?;
+                }
+                f.write_str(")")?;
+            }
+            ResolvedTypeKind::Identifier(name, _) => f.write_str(name)?,
+        }
+        if self.nullable {
+            f.write_str("?")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> Model<'a> {
+    /// What `name` refers to when used as a type, without expanding typedefs
+    pub fn classify(&self, name: &str) -> IdentifierKind {
+        if self.interfaces.contains_key(name) {
+            IdentifierKind::Interface
+        } else if self.callback_interfaces.contains_key(name) {
+            IdentifierKind::CallbackInterface
+        } else if self.dictionaries.contains_key(name) {
+            IdentifierKind::Dictionary
+        } else if self.enums.contains_key(name) {
+            IdentifierKind::Enum
+        } else if self.callbacks.contains_key(name) {
+            IdentifierKind::CallbackFunction
+        } else if self.typedefs.contains_key(name) {
+            IdentifierKind::Typedef
+        } else {
+            IdentifierKind::Unknown
+        }
+    }
+
+    /// Expands the typedefs of `type_`
+    pub fn resolve(&self, type_: &'a Type<'a>) -> ResolvedType<'a> {
+        self.type_(type_, &mut Vec::new())
+    }
+
+    /// Expands the typedefs of `type_`, keeping its attributes
+    pub fn resolve_attributed(&self, type_: &'a AttributedType<'a>) -> ResolvedType<'a> {
+        self.resolve(&type_.type_).attributed(&type_.attributes)
+    }
+
+    /// Expands the typedefs of `type_`
+    pub fn resolve_non_any(&self, type_: &'a NonAnyType<'a>) -> ResolvedType<'a> {
+        self.non_any(type_, &mut Vec::new())
+    }
+
+    /// Expands the typedefs of `type_`
+    pub fn resolve_return_type(&self, type_: &'a ReturnType<'a>) -> ResolvedType<'a> {
+        self.return_type(type_, &mut Vec::new())
+    }
+
+    /// Expands the typedefs named `name`, or tells what it refers to if it is
+    /// not a typedef
+    pub fn resolve_identifier(&self, name: &'a str) -> ResolvedType<'a> {
+        self.identifier(name, &mut Vec::new())
+    }
+
+    // `expanding` holds the typedefs being expanded, to stop at cycles
+    fn type_(&self, type_: &'a Type<'a>, expanding: &mut Vec<&'a str>) -> ResolvedType<'a> {
+        match type_ {
+            Type::Single(SingleType::Any(_)) => ResolvedType::new(ResolvedTypeKind::Any),
+            Type::Single(SingleType::NonAny(type_)) => self.non_any(type_, expanding),
+            Type::Union(type_) => self.union(type_, expanding),
+        }
+    }
+
+    fn return_type(
+        &self,
+        type_: &'a ReturnType<'a>,
+        expanding: &mut Vec<&'a str>,
+    ) -> ResolvedType<'a> {
+        match type_ {
+            ReturnType::Undefined(_) => ResolvedType::new(ResolvedTypeKind::Undefined),
+            ReturnType::Type(type_) => self.type_(type_, expanding),
+        }
+    }
+
+    fn union(
+        &self,
+        type_: &'a MayBeNull<UnionType<'a>>,
+        expanding: &mut Vec<&'a str>,
+    ) -> ResolvedType<'a> {
+        let members = type_
+            .type_
+            .body
+            .list
+            .iter()
+            .map(|member| match member {
+                UnionMemberType::Single(AttributedNonAnyType { attributes, type_ }) => {
+                    self.non_any(type_, expanding).attributed(attributes)
+                }
+                UnionMemberType::Union(union) => self.union(union, expanding),
+            })
+            .collect();
+        ResolvedType::new(ResolvedTypeKind::Union(members)).nullable(type_.q_mark.is_some())
+    }
+
+    fn non_any(&self, type_: &'a NonAnyType<'a>, expanding: &mut Vec<&'a str>) -> ResolvedType<'a> {
+        let kind = match type_ {
+            NonAnyType::Promise(promise) => ResolvedTypeKind::Promise(Box::new(
+                self.return_type(&promise.generics.body, expanding),
+            )),
+            NonAnyType::Sequence(sequence) => ResolvedTypeKind::Sequence(Box::new(
+                self.type_(&sequence.type_.generics.body, expanding),
+            )),
+            NonAnyType::FrozenArrayType(array) => ResolvedTypeKind::FrozenArray(Box::new(
+                self.type_(&array.type_.generics.body, expanding),
+            )),
+            NonAnyType::ObservableArrayType(array) => ResolvedTypeKind::ObservableArray(Box::new(
+                self.type_(&array.type_.generics.body, expanding),
+            )),
+            NonAnyType::RecordType(record) => {
+                let (key, _, value) = &record.type_.generics.body;
+                ResolvedTypeKind::Record(key, Box::new(self.type_(value, expanding)))
+            }
+            NonAnyType::Identifier(identifier) => {
+                return self
+                    .identifier(identifier.type_.0, expanding)
+                    .nullable(identifier.q_mark.is_some());
+            }
+            _ => ResolvedTypeKind::Builtin(type_),
+        };
+        ResolvedType::new(kind).nullable(type_.is_nullable())
+    }
+
+    fn identifier(&self, name: &'a str, expanding: &mut Vec<&'a str>) -> ResolvedType<'a> {
+        let typedef = match self.typedefs.get(name) {
+            Some(typedef) if !expanding.contains(&name) => typedef,
+            Some(_) => {
+                return ResolvedType::new(ResolvedTypeKind::Identifier(
+                    name,
+                    IdentifierKind::Unknown,
+                ))
+            }
+            None => {
+                return ResolvedType::new(ResolvedTypeKind::Identifier(name, self.classify(name)))
+            }
+        };
+
+        expanding.push(name);
+        let mut resolved = self
+            .type_(&typedef.type_.type_, expanding)
+            .attributed(&typedef.type_.attributes);
+        expanding.pop();
+        resolved.typedefs.insert(0, name);
+        resolved
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Parse;
+
+    #[test]
+    fn should_classify_identifiers() {
+        let parsed = crate::parse(
+            "
+            interface I {};
+            partial interface P {};
+            callback interface CI { undefined f(); };
+            dictionary D {};
+            enum E { \"e\" };
+            callback C = undefined ();
+            typedef long T;
+            interface mixin M {};
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+
+        assert_eq!(model.classify("I"), IdentifierKind::Interface);
+        assert_eq!(model.classify("P"), IdentifierKind::Interface);
+        assert_eq!(model.classify("CI"), IdentifierKind::CallbackInterface);
+        assert_eq!(model.classify("D"), IdentifierKind::Dictionary);
+        assert_eq!(model.classify("E"), IdentifierKind::Enum);
+        assert_eq!(model.classify("C"), IdentifierKind::CallbackFunction);
+        assert_eq!(model.classify("T"), IdentifierKind::Typedef);
+        assert_eq!(model.classify("M"), IdentifierKind::Unknown);
+        assert_eq!(model.classify("Nope"), IdentifierKind::Unknown);
+    }
+
+    #[test]
+    fn should_expand_nested_typedefs() {
+        let parsed = crate::parse(
+            "
+            typedef [Clamp] octet Byte;
+            typedef Byte? MaybeByte;
+            typedef sequence<MaybeByte> Bytes;
+            dictionary D {};
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+        let (_, type_) = Type::parse("Bytes?").unwrap();
+
+        let resolved = model.resolve(&type_);
+        assert!(resolved.nullable);
+        assert_eq!(resolved.typedefs, ["Bytes"]);
+        let item = match &resolved.kind {
+            ResolvedTypeKind::Sequence(item) => item,
+            kind => panic!("{:?}", kind),
+        };
+        assert!(item.nullable);
+        assert_eq!(item.typedefs, ["MaybeByte", "Byte"]);
+        assert_eq!(item.attributes.len(), 1);
+        match item.kind {
+            ResolvedTypeKind::Builtin(NonAnyType::Octet(_)) => {}
+            ref kind => panic!("{:?}", kind),
+        }
+
+        let resolved = model.resolve_identifier("D");
+        assert_eq!(
+            resolved.kind,
+            ResolvedTypeKind::Identifier("D", IdentifierKind::Dictionary)
+        );
+        assert!(resolved.typedefs.is_empty());
+    }
+
+    #[test]
+    fn should_expand_union_typedefs() {
+        let parsed = crate::parse(
+            "
+            typedef (long or Inner) Outer;
+            typedef ([EnforceRange] long or E)? Inner;
+            enum E { \"e\" };
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+
+        let resolved = model.resolve_identifier("Outer");
+        assert!(!resolved.nullable);
+        let members = match &resolved.kind {
+            ResolvedTypeKind::Union(members) => members,
+            kind => panic!("{:?}", kind),
+        };
+        assert_eq!(members.len(), 2);
+        assert!(members[1].nullable);
+        assert_eq!(members[1].typedefs, ["Inner"]);
+        let inner = match &members[1].kind {
+            ResolvedTypeKind::Union(members) => members,
+            kind => panic!("{:?}", kind),
+        };
+        assert_eq!(inner[0].attributes.len(), 1);
+        assert_eq!(
+            inner[1].kind,
+            ResolvedTypeKind::Identifier("E", IdentifierKind::Enum)
+        );
+    }
+
+    #[test]
+    fn should_display_expanded_types() {
+        let parsed = crate::parse(
+            "
+            typedef [Clamp] long? Long;
+            typedef (Long or sequence<DOMString>) Union;
+            ",
+        )
+        .unwrap();
+        let model = Model::new(&parsed);
+        let (_, type_) = Type::parse("record<DOMString, Promise<Union>>?").unwrap();
+
+        assert_eq!(
+            model.resolve(&type_).to_string(),
+            "record<DOMString, Promise<(long? or sequence<DOMString>)>>?"
+        );
+    }
+
+    #[test]
+    fn should_stop_at_typedef_cycles() {
+        let parsed = crate::parse("typedef B A; typedef sequence<A> B;").unwrap();
+        let model = Model::new(&parsed);
+
+        let resolved = model.resolve_identifier("A");
+        assert_eq!(resolved.typedefs, ["A", "B"]);
+        match &resolved.kind {
+            ResolvedTypeKind::Sequence(item) => assert_eq!(
+                item.kind,
+                ResolvedTypeKind::Identifier("A", IdentifierKind::Unknown)
+            ),
+            kind => panic!("{:?}", kind),
+        }
+    }
+}
@@ -0,0 +1,389 @@
+//! Source locations of the parsed nodes
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::{Definition, Spanned};
+//!
+//! let input = "
+//!     interface Window {
+//!         readonly attribute Storage sessionStorage;
+//!     };
+//! ";
+//! let parsed = weedle::parse(input).unwrap();
+//!
+//! if let Definition::Interface(interface) = &parsed[0] {
+//!     let span = interface.identifier.span();
+//!     assert_eq!(span.as_str(input), Some("Window"));
+//!
+//!     let start = span.start(input).unwrap();
+//!     assert_eq!(start.line, 2);
+//!     assert_eq!(start.column, 15);
+//! }
+//! ```
+
+use std::convert::TryFrom;
+use std::ops::Range;
+
+/// A region of the input a node was parsed from
+///
+/// The parser only ever sees what is left of its input, so a span records how
+/// much input remained at its start and at its end. It is resolved into byte
+/// offsets or line/column locations against the text originally given to the
+/// parser. Spans never include the whitespace or comments around a node, but
+/// know how much of it there is on either side, see
+/// [`leading_trivia`](#method.leading_trivia).
+///
+/// Every node is full of spans, so they are kept small by storing lengths as
+/// `u32`. Lengths of 4 GiB or more saturate to `u32::MAX`, which marks them as
+/// unknown: the methods resolving such a span against the input return
+/// `None`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
+pub struct Span {
+    start: u32,
+    end: u32,
+    leading: u32,
+    trailing: u32,
+}
+
+impl Span {
+    /// A span which covers nothing, used by nodes not parsed from any input,
+    /// such as those created with `new`
+    pub const EMPTY: Span = Span {
+        start: 0,
+        end: 0,
+        leading: 0,
+        trailing: 0,
+    };
+
+    /// Creates the span of whatever was consumed going from `input` to `rest`
+    pub(crate) fn new(input: &str, rest: &str) -> Self {
+        Span {
+            start: saturate(input.len()),
+            end: saturate(rest.len()),
+            leading: 0,
+            trailing: 0,
+        }
+    }
+
+    /// Records the whitespace and comments right before and after the span
+    pub(crate) fn with_trivia(self, leading: &str, trailing: &str) -> Self {
+        Span {
+            leading: saturate(leading.len()),
+            trailing: saturate(trailing.len()),
+            ..self
+        }
+    }
+
+    /// Length of the span in bytes
+    pub fn len(&self) -> usize {
+        (self.start - self.end) as usize
+    }
+
+    /// Returns `true` if the span covers nothing
+    pub fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Returns the smallest span covering both `self` and `other`
+    ///
+    /// Empty spans are ignored. The trivia before the joined span is that of
+    /// the span starting first, the trivia after it that of the span ending
+    /// last.
+    pub fn join(self, other: Span) -> Span {
+        if other.is_empty() {
+            self
+        } else if self.is_empty() {
+            other
+        } else {
+            let first = if other.start > self.start {
+                other
+            } else {
+                self
+            };
+            let last = if other.end < self.end { other } else { self };
+            Span {
+                start: first.start,
+                end: last.end,
+                leading: first.leading,
+                trailing: last.trailing,
+            }
+        }
+    }
+
+    /// Byte offsets of the span within `input`, the text originally parsed,
+    /// `None` if the span cannot be part of `input`
+    pub fn range(&self, input: &str) -> Option<Range<usize>> {
+        if self.start == u32::MAX || self.end == u32::MAX {
+            return None;
+        }
+        let start = input.len().checked_sub(self.start as usize)?;
+        let end = input.len().checked_sub(self.end as usize)?;
+        input.get(start..end).map(|_| start..end)
+    }
+
+    /// The text covered by the span within `input`, the text originally parsed
+    pub fn as_str<'a>(&self, input: &'a str) -> Option<&'a str> {
+        self.range(input).map(|range| &input[range])
+    }
+
+    /// The whitespace and comments parsed right before the span within
+    /// `input`
+    ///
+    /// Those between two tokens are parsed along with the first one, so only
+    /// a span starting with the first token of the input has any.
+    pub fn leading_trivia<'a>(&self, input: &'a str) -> Option<&'a str> {
+        let range = self.range(input)?;
+        if self.leading == u32::MAX {
+            return None;
+        }
+        input.get(range.start.checked_sub(self.leading as usize)?..range.start)
+    }
+
+    /// The whitespace and comments parsed right after the span within `input`
+    pub fn trailing_trivia<'a>(&self, input: &'a str) -> Option<&'a str> {
+        let range = self.range(input)?;
+        if self.trailing == u32::MAX {
+            return None;
+        }
+        input.get(range.end..range.end.checked_add(self.trailing as usize)?)
+    }
+
+    /// Line and column at which the span starts within `input`
+    pub fn start(&self, input: &str) -> Option<LineColumn> {
+        self.range(input)
+            .map(|range| LineColumn::at(input, range.start))
+    }
+
+    /// Line and column right after the end of the span within `input`
+    pub fn end(&self, input: &str) -> Option<LineColumn> {
+        self.range(input)
+            .map(|range| LineColumn::at(input, range.end))
+    }
+}
+
+/// Converts a length to `u32`, saturating to `u32::MAX` if it does not fit
+fn saturate(len: usize) -> u32 {
+    u32::try_from(len).unwrap_or(u32::MAX)
+}
+
+/// A location in the input. Both line and column start at 1 and columns are
+/// counted in characters.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct LineColumn {
+    pub line: usize,
+    pub column: usize,
+}
+
+impl LineColumn {
+    /// Computes the location of the byte `offset` within `input`
+    pub fn at(input: &str, offset: usize) -> Self {
+        let before = &input[..offset];
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        LineColumn {
+            line: before.matches('\n').count() + 1,
+            column: before[line_start..].chars().count() + 1,
+        }
+    }
+}
+
+/// Implemented by every node of the syntax tree
+pub trait Spanned {
+    /// The region of the input covered by the node
+    fn span(&self) -> Span;
+}
+
+impl<T: Spanned> Spanned for Option<T> {
+    fn span(&self) -> Span {
+        self.as_ref().map_or(Span::EMPTY, Spanned::span)
+    }
+}
+
+impl<T: Spanned> Spanned for Box<T> {
+    fn span(&self) -> Span {
+        (**self).span()
+    }
+}
+
+impl<T: Spanned> Spanned for Vec<T> {
+    fn span(&self) -> Span {
+        self.iter().fold(Span::EMPTY, |span, x| span.join(x.span()))
+    }
+}
+
+impl<T: Spanned, U: Spanned> Spanned for (T, U) {
+    fn span(&self) -> Span {
+        self.0.span().join(self.1.span())
+    }
+}
+
+impl<T: Spanned, U: Spanned, V: Spanned> Spanned for (T, U, V) {
+    fn span(&self) -> Span {
+        self.0.span().join(self.1.span()).join(self.2.span())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::attribute::ExtendedAttribute;
+    use crate::interface::InterfaceMember;
+    use crate::{Definition, Parse};
+
+    const INPUT: &str = "
+        // A comment
+        [Exposed=Window]
+        interface Foo : Bar {
+            attribute long? x;
+        };
+    ";
+
+    #[test]
+    fn should_span_terms_and_identifiers() {
+        let (_, parsed) = Definition::parse(INPUT).unwrap();
+        let interface = match parsed {
+            Definition::Interface(interface) => interface,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(interface.interface.span.as_str(INPUT).unwrap(), "interface");
+        assert_eq!(interface.identifier.span().as_str(INPUT).unwrap(), "Foo");
+        assert_eq!(
+            interface.identifier.span().start(INPUT),
+            Some(LineColumn {
+                line: 4,
+                column: 19
+            })
+        );
+        assert_eq!(
+            interface.identifier.span().end(INPUT),
+            Some(LineColumn {
+                line: 4,
+                column: 22
+            })
+        );
+        assert_eq!(interface.inheritance.span().as_str(INPUT).unwrap(), ": Bar");
+        assert_eq!(interface.semi_colon.span.as_str(INPUT).unwrap(), ";");
+    }
+
+    #[test]
+    fn should_span_composite_nodes() {
+        let (_, parsed) = Definition::parse(INPUT).unwrap();
+        let interface = match parsed {
+            Definition::Interface(interface) => interface,
+            _ => unreachable!(),
+        };
+
+        let attributes = interface.attributes.as_ref().unwrap();
+        assert_eq!(attributes.span().as_str(INPUT).unwrap(), "[Exposed=Window]");
+        match &attributes.body.list[0] {
+            ExtendedAttribute::Ident(ident) => {
+                assert_eq!(ident.span().as_str(INPUT).unwrap(), "Exposed=Window");
+            }
+            _ => unreachable!(),
+        }
+
+        match &interface.members.body[0] {
+            member @ InterfaceMember::Attribute(attribute) => {
+                assert_eq!(member.span().as_str(INPUT).unwrap(), "attribute long? x;");
+                assert_eq!(attribute.type_.span().as_str(INPUT).unwrap(), "long?");
+            }
+            _ => unreachable!(),
+        }
+
+        assert!(interface
+            .span()
+            .as_str(INPUT)
+            .unwrap()
+            .starts_with("[Exposed=Window]\n        interface Foo"));
+        assert!(interface.span().as_str(INPUT).unwrap().ends_with("};"));
+        assert_eq!(interface.span().start(INPUT).unwrap().line, 3);
+        assert_eq!(interface.span().end(INPUT).unwrap().line, 6);
+    }
+
+    #[test]
+    fn should_span_literals() {
+        let input = r#"  "value"  "#;
+        let (_, parsed) = crate::literal::StringLit::parse(input).unwrap();
+        assert_eq!(parsed.span().as_str(input).unwrap(), r#""value""#);
+
+        let input = "  -0x1F ";
+        let (_, parsed) = crate::literal::IntegerLit::parse(input).unwrap();
+        assert_eq!(parsed.span().range(input), Some(2..7));
+    }
+
+    #[test]
+    fn should_record_trivia_around_tokens() {
+        let input = "/* Foo */ interface Foo { // Members
+    attribute long x; /* x */
+};
+";
+        let (_, parsed) = Definition::parse(input).unwrap();
+        let interface = match &parsed {
+            Definition::Interface(interface) => interface,
+            _ => unreachable!(),
+        };
+
+        let span = interface.interface.span;
+        assert_eq!(span.leading_trivia(input), Some("/* Foo */ "));
+        assert_eq!(span.trailing_trivia(input), Some(" "));
+        let span = interface.members.open_brace.span;
+        assert_eq!(span.leading_trivia(input), Some(""));
+        assert_eq!(span.trailing_trivia(input), Some(" // Members\n    "));
+        let span = interface.members.body[0].span();
+        assert_eq!(span.trailing_trivia(input), Some(" /* x */\n"));
+
+        assert_eq!(parsed.span().leading_trivia(input), Some("/* Foo */ "));
+        assert_eq!(parsed.span().trailing_trivia(input), Some("\n"));
+        assert_eq!(Span::EMPTY.trailing_trivia(input), Some(""));
+    }
+
+    #[test]
+    fn should_not_resolve_spans_against_other_input() {
+        let span = Span::new("abcdef", "def");
+        assert_eq!(span.range("abcdef"), Some(0..3));
+        assert_eq!(span.range("ab"), None);
+        assert_eq!(span.as_str("ab"), None);
+        assert_eq!(span.start("ab"), None);
+        // Not on a character boundary
+        assert_eq!(span.range("ab\u{e9}ef"), None);
+    }
+
+    #[test]
+    fn should_not_compare_spans() {
+        let (_, first) = Definition::parse("typedef long Foo;").unwrap();
+        let (_, second) = Definition::parse("  typedef   long   Foo ; ").unwrap();
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn should_ignore_empty_spans_when_joining() {
+        let span = Span::new("abcdef", "def");
+        assert_eq!(Span::EMPTY.join(span), span);
+        assert_eq!(span.join(Span::EMPTY), span);
+        assert_eq!(span.join(Span::new("cdef", "")).range("abcdef"), Some(0..6));
+        assert!(Span::EMPTY.is_empty());
+    }
+
+    #[test]
+    fn should_not_resolve_saturated_spans() {
+        assert_eq!(saturate(5), 5);
+        assert_eq!(saturate(usize::MAX), u32::MAX);
+
+        let span = Span::new("cdef", "ef");
+        let too_long = Span {
+            start: u32::MAX,
+            ..span
+        };
+        assert_eq!(too_long.range("abcdef"), None);
+        assert_eq!(too_long.start("abcdef"), None);
+        let trivia = span.with_trivia("ab", "ef");
+        assert_eq!(trivia.leading_trivia("abcdef"), Some("ab"));
+        let trivia = Span {
+            leading: u32::MAX,
+            ..trivia
+        };
+        assert_eq!(trivia.leading_trivia("abcdef"), None);
+        assert_eq!(trivia.trailing_trivia("abcdef"), Some("ef"));
+    }
+}
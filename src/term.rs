@@ -1,16 +1,120 @@
+macro_rules! generate_term {
+    ($(#[$attr:meta])* $typ:ident => $tok:expr, $tag:ident) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default)]
+        pub struct $typ {
+            pub span: $crate::span::Span,
+        }
+
+        $(#[$attr])*
+        #[allow(non_upper_case_globals)]
+        pub const $typ: $typ = $typ {
+            span: $crate::span::Span::EMPTY,
+        };
+
+        impl<'a> $crate::Parse<'a> for $typ {
+            parser!(do_parse!(
+                token: ws!(expect!($tag!($tok), concat!("`", $tok, "`"))) >>
+                ($typ { span: token.1 })
+            ));
+        }
+
+        impl $crate::span::Spanned for $typ {
+            fn span(&self) -> $crate::span::Span {
+                self.span
+            }
+        }
+
+        impl<'a> $crate::token::Tokens<'a> for $typ {
+            fn tokens(&self, out: &mut Vec<$crate::token::Token<'a>>) {
+                out.push($crate::token::Token {
+                    kind: $crate::token::TokenKind::Term($tok),
+                    span: self.span,
+                    escaped: false,
+                });
+            }
+        }
+
+        impl ::std::fmt::Display for $typ {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str($tok)
+            }
+        }
+
+        // Tokens are all alike wherever they were parsed from
+        impl ::std::fmt::Debug for $typ {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+                f.write_str(stringify!($typ))
+            }
+        }
+
+        impl PartialEq for $typ {
+            fn eq(&self, _: &Self) -> bool {
+                true
+            }
+        }
+
+        impl Eq for $typ {}
+
+        impl PartialOrd for $typ {
+            fn partial_cmp(&self, other: &Self) -> Option<::std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+
+        impl Ord for $typ {
+            fn cmp(&self, _: &Self) -> ::std::cmp::Ordering {
+                ::std::cmp::Ordering::Equal
+            }
+        }
+
+        impl ::std::hash::Hash for $typ {
+            fn hash<H: ::std::hash::Hasher>(&self, _: &mut H) {}
+        }
+
+        // Tokens are written as their text
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $typ {
+            fn serialize<S: ::serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str($tok)
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $typ {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str($crate::term::TermVisitor($tok))?;
+                Ok($typ::default())
+            }
+        }
+    };
+}
+
+// Accepts the text of a token and nothing else
+#[cfg(feature = "serde")]
+pub(crate) struct TermVisitor(pub(crate) &'static str);
+
+#[cfg(feature = "serde")]
+impl<'de> serde::de::Visitor<'de> for TermVisitor {
+    type Value = ();
+
+    fn expecting(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        write!(f, "`{}`", self.0)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<(), E> {
+        if value == self.0 {
+            Ok(())
+        } else {
+            Err(E::invalid_value(serde::de::Unexpected::Str(value), &self))
+        }
+    }
+}
+
 macro_rules! generate_terms {
     ($( $(#[$attr:meta])* $typ:ident => $tok:expr ),*) => {
         $(
-            $(#[$attr])*
-            #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-            pub struct $typ;
-
-            impl<'a> $crate::Parse<'a> for $typ {
-                parser!(do_parse!(
-                    ws!(tag!($tok)) >>
-                    ($typ)
-                ));
-            }
+            generate_term!($(#[$attr])* $typ => $tok, tag);
         )*
     };
 }
@@ -38,16 +142,21 @@ macro_rules! ident_tag (
 macro_rules! generate_terms_for_names {
     ($( $(#[$attr:meta])* $typ:ident => $tok:expr,)*) => {
         $(
-            $(#[$attr])*
-            #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
-            pub struct $typ;
-
-            impl<'a> $crate::Parse<'a> for $typ {
-                parser!(do_parse!(
-                    ws!(ident_tag!($tok)) >>
-                    ($typ)
-                ));
-            }
+            generate_term!($(#[$attr])* $typ => $tok, ident_tag);
+        )*
+
+        /// Every keyword, which an identifier must be escaped with `_` to be
+        /// named after
+        pub(crate) const KEYWORDS: &[&str] = &[$($tok),*];
+    };
+}
+
+/// For the names of typedefs the spec defines, which are not keywords, so
+/// identifiers may be named after them without `_`
+macro_rules! generate_terms_for_typedef_names {
+    ($( $(#[$attr:meta])* $typ:ident => $tok:expr,)*) => {
+        $(
+            generate_term!($(#[$attr])* $typ => $tok, ident_tag);
         )*
     };
 }
@@ -115,6 +224,9 @@ generate_terms_for_names! {
     /// Represents the terminal symbol `async`
     Async => "async",
 
+    /// Represents the terminal symbol `async_iterable`
+    AsyncIterable => "async_iterable",
+
     /// Represents the terminal symbol `attribute`
     Attribute => "attribute",
 
@@ -181,6 +293,9 @@ generate_terms_for_names! {
     /// Represents the terminal symbol `symbol`
     Symbol => "symbol",
 
+    /// Represents the terminal symbol `bigint`
+    BigInt => "bigint",
+
     /// Represents the terminal symbol `Infinity`
     NegInfinity => "-Infinity",
 
@@ -256,6 +371,9 @@ generate_terms_for_names! {
     /// Represents the terminal symbol `ArrayBuffer`
     ArrayBuffer => "ArrayBuffer",
 
+    /// Represents the terminal symbol `SharedArrayBuffer`
+    SharedArrayBuffer => "SharedArrayBuffer",
+
     /// Represents the terminal symbol `DataView`
     DataView => "DataView",
 
@@ -280,18 +398,21 @@ generate_terms_for_names! {
     /// Represents the terminal symbol `Uint8ClampedArray`
     Uint8ClampedArray => "Uint8ClampedArray",
 
+    /// Represents the terminal symbol `BigInt64Array`
+    BigInt64Array => "BigInt64Array",
+
+    /// Represents the terminal symbol `BigUint64Array`
+    BigUint64Array => "BigUint64Array",
+
+    /// Represents the terminal symbol `Float16Array`
+    Float16Array => "Float16Array",
+
     /// Represents the terminal symbol `Float32Array`
     Float32Array => "Float32Array",
 
     /// Represents the terminal symbol `Float64Array`
     Float64Array => "Float64Array",
 
-    /// Represents the terminal symbol `ArrayBufferView`
-    ArrayBufferView => "ArrayBufferView",
-
-    /// Represents the terminal symbol `BufferSource
-    BufferSource => "BufferSource",
-
     /// Represents the terminal symbol `Promise`
     Promise => "Promise",
 
@@ -314,6 +435,14 @@ generate_terms_for_names! {
     Constructor => "constructor",
 }
 
+generate_terms_for_typedef_names! {
+    /// Represents the terminal symbol `ArrayBufferView`
+    ArrayBufferView => "ArrayBufferView",
+
+    /// Represents the terminal symbol `BufferSource`
+    BufferSource => "BufferSource",
+}
+
 #[macro_export]
 macro_rules! term {
     (OpenParen) => {
@@ -376,6 +505,9 @@ macro_rules! term {
     (async) => {
         $crate::term::Async
     };
+    (async_iterable) => {
+        $crate::term::AsyncIterable
+    };
     (attribute) => {
         $crate::term::Attribute
     };
@@ -442,6 +574,9 @@ macro_rules! term {
     (symbol) => {
         $crate::term::Symbol
     };
+    (bigint) => {
+        $crate::term::BigInt
+    };
     (- Infinity) => {
         $crate::term::NegInfinity
     };
@@ -517,6 +652,9 @@ macro_rules! term {
     (ArrayBuffer) => {
         $crate::term::ArrayBuffer
     };
+    (SharedArrayBuffer) => {
+        $crate::term::SharedArrayBuffer
+    };
     (DataView) => {
         $crate::term::DataView
     };
@@ -541,6 +679,15 @@ macro_rules! term {
     (Uint8ClampedArray) => {
         $crate::term::Uint8ClampedArray
     };
+    (BigInt64Array) => {
+        $crate::term::BigInt64Array
+    };
+    (BigUint64Array) => {
+        $crate::term::BigUint64Array
+    };
+    (Float16Array) => {
+        $crate::term::Float16Array
+    };
     (Float32Array) => {
         $crate::term::Float32Array
     };
@@ -645,6 +792,7 @@ mod test {
         or, Or, "or";
         optional, Optional, "optional";
         async_, Async, "async";
+        async_iterable, AsyncIterable, "async_iterable";
         attribute, Attribute, "attribute";
         callback, Callback, "callback";
         const_, Const, "const";
@@ -667,6 +815,7 @@ mod test {
         typedef, Typedef, "typedef";
         unrestricted, Unrestricted, "unrestricted";
         symbol, Symbol, "symbol";
+        bigint, BigInt, "bigint";
         neginfinity, NegInfinity, "-Infinity";
         bytestring, ByteString, "ByteString";
         domstring, DOMString, "DOMString";
@@ -692,6 +841,7 @@ mod test {
         undefined, Undefined, "undefined";
         record, Record, "record";
         arraybuffer, ArrayBuffer, "ArrayBuffer";
+        sharedarraybuffer, SharedArrayBuffer, "SharedArrayBuffer";
         dataview, DataView, "DataView";
         int8array, Int8Array, "Int8Array";
         int16array, Int16Array, "Int16Array";
@@ -700,6 +850,9 @@ mod test {
         uint16array, Uint16Array, "Uint16Array";
         uint32array, Uint32Array, "Uint32Array";
         uint8clampedarray, Uint8ClampedArray, "Uint8ClampedArray";
+        bigint64array, BigInt64Array, "BigInt64Array";
+        biguint64array, BigUint64Array, "BigUint64Array";
+        float16array, Float16Array, "Float16Array";
         float32array, Float32Array, "Float32Array";
         float64array, Float64Array, "Float64Array";
         promise, Promise, "Promise";
@@ -0,0 +1,222 @@
+//! The tokens every node of the syntax tree is made of
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::token::{TokenKind, Tokens};
+//!
+//! let parsed = weedle::parse("typedef long Foo;").unwrap();
+//! let kinds: Vec<_> = parsed.to_tokens().into_iter().map(|t| t.kind).collect();
+//! assert_eq!(
+//!     kinds,
+//!     [
+//!         TokenKind::Term("typedef"),
+//!         TokenKind::Term("long"),
+//!         TokenKind::Identifier("Foo"),
+//!         TokenKind::Term(";"),
+//!     ]
+//! );
+//! ```
+
+use std::fmt;
+
+use crate::common::Identifier;
+use crate::literal::{BooleanLit, DecLit, FloatValueLit, HexLit, OctLit, StringLit};
+use crate::span::Span;
+
+/// A single keyword, punctuation, identifier or literal
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Token<'a> {
+    pub kind: TokenKind<'a>,
+    /// Where the token was parsed from. Tokens which are implied by the tree
+    /// but were never parsed, like the separators of a
+    /// [`Punctuated`](../common/struct.Punctuated.html), have an empty span.
+    pub span: Span,
+    /// Whether the token is an identifier written with a leading `_`, see
+    /// [`Identifier::is_escaped`](../common/struct.Identifier.html#method.is_escaped)
+    pub escaped: bool,
+}
+
+/// What a token is
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TokenKind<'a> {
+    /// A keyword or punctuation from the [`term`](../term/index.html) module
+    Term(&'static str),
+    /// An identifier, without its leading underscore
+    Identifier(&'a str),
+    /// An integer literal as written
+    Integer(&'a str),
+    /// A float literal as written
+    Float(&'a str),
+    /// A string literal, without its quotes
+    String(&'a str),
+}
+
+impl<'a> fmt::Display for TokenKind<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            TokenKind::Term(text)
+            | TokenKind::Identifier(text)
+            | TokenKind::Integer(text)
+            | TokenKind::Float(text) => f.write_str(text),
+            TokenKind::String(text) => write!(f, "\"{}\"", text),
+        }
+    }
+}
+
+/// Implemented by every node of the syntax tree
+pub trait Tokens<'a> {
+    /// Appends the tokens of the node to `out`, in the order they are written
+    fn tokens(&self, out: &mut Vec<Token<'a>>);
+
+    /// Collects the tokens of the node
+    fn to_tokens(&self) -> Vec<Token<'a>> {
+        let mut out = Vec::new();
+        self.tokens(&mut out);
+        out
+    }
+}
+
+impl<'a, T: Tokens<'a>> Tokens<'a> for Option<T> {
+    fn tokens(&self, out: &mut Vec<Token<'a>>) {
+        if let Some(inner) = self {
+            inner.tokens(out);
+        }
+    }
+}
+
+impl<'a, T: Tokens<'a>> Tokens<'a> for Box<T> {
+    fn tokens(&self, out: &mut Vec<Token<'a>>) {
+        (**self).tokens(out)
+    }
+}
+
+impl<'a, T: Tokens<'a>> Tokens<'a> for Vec<T> {
+    fn tokens(&self, out: &mut Vec<Token<'a>>) {
+        for item in self {
+            item.tokens(out);
+        }
+    }
+}
+
+impl<'a, T: Tokens<'a>, U: Tokens<'a>> Tokens<'a> for (T, U) {
+    fn tokens(&self, out: &mut Vec<Token<'a>>) {
+        self.0.tokens(out);
+        self.1.tokens(out);
+    }
+}
+
+impl<'a, T: Tokens<'a>, U: Tokens<'a>, V: Tokens<'a>> Tokens<'a> for (T, U, V) {
+    fn tokens(&self, out: &mut Vec<Token<'a>>) {
+        self.0.tokens(out);
+        self.1.tokens(out);
+        self.2.tokens(out);
+    }
+}
+
+macro_rules! leaf_tokens {
+    ($($typ:ident => $kind:ident,)*) => {
+        $(
+            impl<'a> Tokens<'a> for $typ<'a> {
+                fn tokens(&self, out: &mut Vec<Token<'a>>) {
+                    out.push(Token {
+                        kind: TokenKind::$kind(self.0),
+                        span: self.1,
+                        escaped: false,
+                    });
+                }
+            }
+        )*
+    };
+}
+
+impl<'a> Tokens<'a> for Identifier<'a> {
+    fn tokens(&self, out: &mut Vec<Token<'a>>) {
+        out.push(Token {
+            kind: TokenKind::Identifier(self.0),
+            span: self.1,
+            escaped: self.2,
+        });
+    }
+}
+
+leaf_tokens! {
+    DecLit => Integer,
+    HexLit => Integer,
+    OctLit => Integer,
+    FloatValueLit => Float,
+    StringLit => String,
+}
+
+impl<'a> Tokens<'a> for BooleanLit {
+    fn tokens(&self, out: &mut Vec<Token<'a>>) {
+        out.push(Token {
+            kind: TokenKind::Term(if self.0 { "true" } else { "false" }),
+            span: self.1,
+            escaped: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Parse;
+
+    #[test]
+    fn should_list_tokens_in_order() {
+        let input = r#"[Exposed=Window] interface Foo { const long x = 0x1F; };"#;
+        let (_, parsed) = crate::Definition::parse(input).unwrap();
+        let texts: Vec<_> = parsed
+            .to_tokens()
+            .iter()
+            .filter_map(|t| t.span.as_str(input))
+            .collect();
+        assert_eq!(
+            texts,
+            [
+                "[",
+                "Exposed",
+                "=",
+                "Window",
+                "]",
+                "interface",
+                "Foo",
+                "{",
+                "const",
+                "long",
+                "x",
+                "=",
+                "0x1F",
+                ";",
+                "}",
+                ";"
+            ]
+        );
+    }
+
+    #[test]
+    fn should_list_separators() {
+        let input = "long a, optional DOMString b";
+        let (_, mut parsed) = crate::argument::ArgumentList::parse(input).unwrap();
+        let tokens = parsed.to_tokens();
+        let separator = tokens[2];
+        assert_eq!(separator.kind, TokenKind::Term(","));
+        assert_eq!(separator.span.as_str(input), Some(","));
+        assert_eq!(tokens.len(), 6);
+
+        // Separators are implied once the items do not match them anymore
+        parsed.list.push(parsed.list[0].clone());
+        let tokens = parsed.to_tokens();
+        assert_eq!(tokens[2].kind, TokenKind::Term(","));
+        assert!(tokens[2].span.is_empty());
+        assert!(tokens[6].span.is_empty());
+        assert_eq!(tokens.len(), 9);
+    }
+
+    #[test]
+    fn should_display_literals() {
+        let (_, parsed) = StringLit::parse(r#""value""#).unwrap();
+        assert_eq!(parsed.to_tokens()[0].kind.to_string(), r#""value""#);
+    }
+}
@@ -25,6 +25,7 @@ ast_types! {
         Boolean(MayBeNull<term!(boolean)>),
         Byte(MayBeNull<term!(byte)>),
         Octet(MayBeNull<term!(octet)>),
+        BigInt(MayBeNull<term!(bigint)>),
         ByteString(MayBeNull<term!(ByteString)>),
         DOMString(MayBeNull<term!(DOMString)>),
         USVString(MayBeNull<term!(USVString)>),
@@ -33,6 +34,7 @@ ast_types! {
         Symbol(MayBeNull<term!(symbol)>),
         Error(MayBeNull<term!(Error)>),
         ArrayBuffer(MayBeNull<term!(ArrayBuffer)>),
+        SharedArrayBuffer(MayBeNull<term!(SharedArrayBuffer)>),
         DataView(MayBeNull<term!(DataView)>),
         Int8Array(MayBeNull<term!(Int8Array)>),
         Int16Array(MayBeNull<term!(Int16Array)>),
@@ -41,6 +43,9 @@ ast_types! {
         Uint16Array(MayBeNull<term!(Uint16Array)>),
         Uint32Array(MayBeNull<term!(Uint32Array)>),
         Uint8ClampedArray(MayBeNull<term!(Uint8ClampedArray)>),
+        BigInt64Array(MayBeNull<term!(BigInt64Array)>),
+        BigUint64Array(MayBeNull<term!(BigUint64Array)>),
+        Float16Array(MayBeNull<term!(Float16Array)>),
         Float32Array(MayBeNull<term!(Float32Array)>),
         Float64Array(MayBeNull<term!(Float64Array)>),
         ArrayBufferView(MayBeNull<term!(ArrayBufferView)>),
@@ -151,6 +156,7 @@ ast_types! {
         Boolean(MayBeNull<term!(boolean)>),
         Byte(MayBeNull<term!(byte)>),
         Octet(MayBeNull<term!(octet)>),
+        BigInt(MayBeNull<term!(bigint)>),
         Identifier(MayBeNull<Identifier<'a>>),
     }
 
@@ -173,6 +179,75 @@ ast_types! {
     }
 }
 
+impl<'a> NonAnyType<'a> {
+    /// Returns `true` if the type is followed by `?`
+    pub fn is_nullable(&self) -> bool {
+        match self {
+            NonAnyType::Promise(_) => false,
+            NonAnyType::Integer(it) => it.q_mark.is_some(),
+            NonAnyType::FloatingPoint(it) => it.q_mark.is_some(),
+            NonAnyType::Boolean(it) => it.q_mark.is_some(),
+            NonAnyType::Byte(it) => it.q_mark.is_some(),
+            NonAnyType::Octet(it) => it.q_mark.is_some(),
+            NonAnyType::BigInt(it) => it.q_mark.is_some(),
+            NonAnyType::ByteString(it) => it.q_mark.is_some(),
+            NonAnyType::DOMString(it) => it.q_mark.is_some(),
+            NonAnyType::USVString(it) => it.q_mark.is_some(),
+            NonAnyType::Sequence(it) => it.q_mark.is_some(),
+            NonAnyType::Object(it) => it.q_mark.is_some(),
+            NonAnyType::Symbol(it) => it.q_mark.is_some(),
+            NonAnyType::Error(it) => it.q_mark.is_some(),
+            NonAnyType::ArrayBuffer(it) => it.q_mark.is_some(),
+            NonAnyType::SharedArrayBuffer(it) => it.q_mark.is_some(),
+            NonAnyType::DataView(it) => it.q_mark.is_some(),
+            NonAnyType::Int8Array(it) => it.q_mark.is_some(),
+            NonAnyType::Int16Array(it) => it.q_mark.is_some(),
+            NonAnyType::Int32Array(it) => it.q_mark.is_some(),
+            NonAnyType::Uint8Array(it) => it.q_mark.is_some(),
+            NonAnyType::Uint16Array(it) => it.q_mark.is_some(),
+            NonAnyType::Uint32Array(it) => it.q_mark.is_some(),
+            NonAnyType::Uint8ClampedArray(it) => it.q_mark.is_some(),
+            NonAnyType::BigInt64Array(it) => it.q_mark.is_some(),
+            NonAnyType::BigUint64Array(it) => it.q_mark.is_some(),
+            NonAnyType::Float16Array(it) => it.q_mark.is_some(),
+            NonAnyType::Float32Array(it) => it.q_mark.is_some(),
+            NonAnyType::Float64Array(it) => it.q_mark.is_some(),
+            NonAnyType::ArrayBufferView(it) => it.q_mark.is_some(),
+            NonAnyType::BufferSource(it) => it.q_mark.is_some(),
+            NonAnyType::FrozenArrayType(it) => it.q_mark.is_some(),
+            NonAnyType::ObservableArrayType(it) => it.q_mark.is_some(),
+            NonAnyType::RecordType(it) => it.q_mark.is_some(),
+            NonAnyType::Identifier(it) => it.q_mark.is_some(),
+        }
+    }
+
+    /// Returns `true` for `ArrayBuffer`, `SharedArrayBuffer`, `DataView`, the
+    /// typed arrays and the `ArrayBufferView` and `BufferSource` unions of
+    /// them
+    pub fn is_buffer_source_type(&self) -> bool {
+        matches!(
+            self,
+            NonAnyType::ArrayBuffer(_)
+                | NonAnyType::SharedArrayBuffer(_)
+                | NonAnyType::DataView(_)
+                | NonAnyType::Int8Array(_)
+                | NonAnyType::Int16Array(_)
+                | NonAnyType::Int32Array(_)
+                | NonAnyType::Uint8Array(_)
+                | NonAnyType::Uint16Array(_)
+                | NonAnyType::Uint32Array(_)
+                | NonAnyType::Uint8ClampedArray(_)
+                | NonAnyType::BigInt64Array(_)
+                | NonAnyType::BigUint64Array(_)
+                | NonAnyType::Float16Array(_)
+                | NonAnyType::Float32Array(_)
+                | NonAnyType::Float64Array(_)
+                | NonAnyType::ArrayBufferView(_)
+                | NonAnyType::BufferSource(_)
+        )
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -203,6 +278,7 @@ mod test {
             Boolean == "boolean",
             Byte == "byte",
             Octet == "octet",
+            BigInt == "bigint",
             Identifier == "name",
         }
     );
@@ -215,6 +291,7 @@ mod test {
             Boolean == "boolean",
             Byte == "byte",
             Octet == "octet",
+            BigInt == "bigint",
             ByteString == "ByteString",
             DOMString == "DOMString",
             USVString == "USVString",
@@ -223,6 +300,7 @@ mod test {
             Symbol == "symbol",
             Error == "Error",
             ArrayBuffer == "ArrayBuffer",
+            SharedArrayBuffer == "SharedArrayBuffer",
             DataView == "DataView",
             Int8Array == "Int8Array",
             Int16Array == "Int16Array",
@@ -231,6 +309,9 @@ mod test {
             Uint16Array == "Uint16Array",
             Uint32Array == "Uint32Array",
             Uint8ClampedArray == "Uint8ClampedArray",
+            BigInt64Array == "BigInt64Array",
+            BigUint64Array == "BigUint64Array",
+            Float16Array == "Float16Array",
             Float32Array == "Float32Array",
             Float64Array == "Float64Array",
             ArrayBufferView == "ArrayBufferView",
@@ -395,4 +476,30 @@ mod test {
             }
         }
     }
+
+    #[test]
+    fn should_tell_nullable_types() {
+        let (_, nullable) = crate::types::NonAnyType::parse("sequence<long>?").unwrap();
+        let (_, not_nullable) = crate::types::NonAnyType::parse("Promise<long?>").unwrap();
+        assert!(nullable.is_nullable());
+        assert!(!not_nullable.is_nullable());
+    }
+
+    #[test]
+    fn should_tell_buffer_source_types() {
+        for buffer in &["Float16Array?", "SharedArrayBuffer", "BufferSource"] {
+            let (_, type_) = crate::types::NonAnyType::parse(buffer).unwrap();
+            assert!(type_.is_buffer_source_type());
+        }
+        // A typedef of the spec rather than a keyword
+        for other in &[
+            "sequence<octet>",
+            "Float16",
+            "object",
+            "AllowSharedBufferSource",
+        ] {
+            let (_, type_) = crate::types::NonAnyType::parse(other).unwrap();
+            assert!(!type_.is_buffer_source_type());
+        }
+    }
 }
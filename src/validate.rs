@@ -0,0 +1,1406 @@
+//! Checks of definitions which parse but do not make sense
+//!
+//! The grammar accepts any name wherever a definition is referred to, so a
+//! typo such as `attribute Strorage foo;` is only caught by
+//! [`validate`](fn.validate.html), which reports each mistake it finds as a
+//! [`Diagnostic`](struct.Diagnostic.html).
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::validate::{validate, DiagnosticKind, Options};
+//!
+//! let input = "
+//!     interface Window {
+//!         readonly attribute Strorage sessionStorage;
+//!     };
+//! ";
+//! let parsed = weedle::parse(input).unwrap();
+//!
+//! let diagnostics = validate(&parsed, &Options::default());
+//! assert_eq!(diagnostics.len(), 1);
+//! assert_eq!(diagnostics[0].kind, DiagnosticKind::UndefinedReference);
+//! assert_eq!(diagnostics[0].message, "cannot find type `Strorage`");
+//! assert_eq!(diagnostics[0].span.start(input).unwrap().line, 3);
+//!
+//! let options = Options {
+//!     external: vec!["Strorage".to_string()],
+//! };
+//! assert!(validate(&parsed, &options).is_empty());
+//! ```
+
+use std::fmt;
+
+use crate::argument::SingleArgument;
+use crate::attribute::{ExtendedAttribute, ExtendedAttributeList, IdentifierOrString};
+use crate::common::{Default, Identifier};
+use crate::dictionary::DictionaryMember;
+use crate::interface::{AttributeInterfaceMember, ConstMember, Inheritance, InterfaceMember};
+use crate::literal::{ConstValue, DefaultValue};
+use crate::mixin::{AttributeMixinMember, MixinMember};
+use crate::model::{Member, MemberNode, Model};
+use crate::namespace::ConstNamespaceMember;
+use crate::overload::{overload_kind, overload_sets};
+use crate::resolve::{IdentifierKind, ResolvedType, ResolvedTypeKind};
+use crate::span::{Span, Spanned};
+use crate::types::{AttributedType, ConstType, FloatingPointType, IntegerType, NonAnyType, Type};
+use crate::visit::{self, Visit};
+use crate::{Definition, IncludesStatementDefinition};
+
+/// What to accept when validating
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Options {
+    /// Names defined outside of the definitions validated, such as by other
+    /// specifications. They are accepted wherever a definition or a global
+    /// name is referred to.
+    pub external: Vec<String>,
+}
+
+/// A mistake found by [`validate`](fn.validate.html)
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Diagnostic {
+    pub kind: DiagnosticKind,
+    pub message: String,
+    /// The part of the input the mistake is in
+    pub span: Span,
+}
+
+/// The kinds of mistakes [`validate`](fn.validate.html) finds
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum DiagnosticKind {
+    /// A name which is not defined, or not as what it is used for
+    UndefinedReference,
+    /// A definition which inherits from itself
+    InheritanceCycle,
+    /// A definition which inherits from a different kind of definition, or a
+    /// callback interface which inherits at all
+    InheritanceKind,
+    /// Overloads which cannot be told apart by the types of their arguments
+    IndistinguishableOverloads,
+    /// A definition, member or enum value which shares its name with another
+    Duplicate,
+    /// A number too large or too small for its type
+    OutOfRange,
+    /// A value which cannot be of its type at all
+    TypeMismatch,
+    /// A `NaN` for a floating point type which is not `unrestricted`
+    RestrictedNaN,
+}
+
+impl Diagnostic {
+    fn new(kind: DiagnosticKind, span: Span, message: String) -> Self {
+        Diagnostic {
+            kind,
+            message,
+            span,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for Diagnostic {}
+
+/// Checks `definitions`, all of them together
+///
+/// Diagnostics are grouped by the check which found them, each group in the
+/// order of the input.
+pub fn validate<'a>(definitions: &'a [Definition<'a>], options: &Options) -> Vec<Diagnostic> {
+    let model = Model::new(definitions);
+    let mut diagnostics = Vec::new();
+
+    let mut references = References {
+        model: &model,
+        options,
+        globals: globals(&model),
+        diagnostics: &mut diagnostics,
+    };
+    for definition in definitions {
+        references.visit_definition(definition);
+    }
+
+    inheritance(&model, definitions, &mut diagnostics);
+    overloads(&model, definitions, &mut diagnostics);
+    duplicates(&model, definitions, &mut diagnostics);
+
+    let mut values = Values {
+        model: &model,
+        diagnostics: &mut diagnostics,
+    };
+    for definition in definitions {
+        values.visit_definition(definition);
+    }
+
+    diagnostics
+}
+
+/// The kind of definition named `name`, if any
+fn describe(model: &Model, name: &str) -> Option<&'static str> {
+    let kind = match model.classify(name) {
+        IdentifierKind::Interface => "interface",
+        IdentifierKind::CallbackInterface => "callback interface",
+        IdentifierKind::Dictionary => "dictionary",
+        IdentifierKind::Enum => "enum",
+        IdentifierKind::CallbackFunction => "callback",
+        IdentifierKind::Typedef => "typedef",
+        IdentifierKind::Unknown if model.mixins.contains_key(name) => "interface mixin",
+        IdentifierKind::Unknown if model.namespaces.contains_key(name) => "namespace",
+        IdentifierKind::Unknown => return None,
+    };
+    Some(kind)
+}
+
+/// Checks interfaces and dictionaries inherit from their own kind without
+/// cycles, and callback interfaces do not inherit
+fn inheritance<'a>(
+    model: &Model<'a>,
+    definitions: &'a [Definition<'a>],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for definition in definitions {
+        let (kind, name, inheritance) = match definition {
+            Definition::Interface(it) => ("interface", it.identifier.0, it.inheritance),
+            Definition::Dictionary(it) => ("dictionary", it.identifier.0, it.inheritance),
+            Definition::CallbackInterface(it) => {
+                ("callback interface", it.identifier.0, it.inheritance)
+            }
+            _ => continue,
+        };
+        let parent = match inheritance {
+            Some(inheritance) => inheritance.identifier,
+            None => continue,
+        };
+
+        let (diagnostic_kind, message) = match describe(model, parent.0) {
+            // Undefined names are reported with the other references
+            None => continue,
+            Some(_) if kind == "callback interface" => (
+                DiagnosticKind::InheritanceKind,
+                format!("callback interface `{}` cannot inherit", name),
+            ),
+            Some(parent_kind) if parent_kind != kind => (
+                DiagnosticKind::InheritanceKind,
+                format!(
+                    "{} `{}` cannot inherit from {} `{}`",
+                    kind, name, parent_kind, parent.0
+                ),
+            ),
+            Some(_) => {
+                let (ancestors, last_parent) = if kind == "interface" {
+                    let ancestors = model.interface_ancestors(name);
+                    let last_parent = match ancestors.last() {
+                        Some(last) => model.interfaces.get(last).and_then(|it| it.inheritance()),
+                        None => Some(parent.0),
+                    };
+                    (ancestors, last_parent)
+                } else {
+                    let ancestors = model.dictionary_ancestors(name);
+                    let last_parent = match ancestors.last() {
+                        Some(last) => model.dictionaries.get(last).and_then(|it| it.inheritance()),
+                        None => Some(parent.0),
+                    };
+                    (ancestors, last_parent)
+                };
+                // Chains which run into a cycle are reported by its members
+                if last_parent != Some(name) {
+                    continue;
+                }
+                let message = if ancestors.is_empty() {
+                    format!("{} `{}` inherits from itself", kind, name)
+                } else {
+                    format!(
+                        "{} `{}` inherits from itself through `{}`",
+                        kind,
+                        name,
+                        ancestors.join("`, `")
+                    )
+                };
+                (DiagnosticKind::InheritanceCycle, message)
+            }
+        };
+        diagnostics.push(Diagnostic::new(diagnostic_kind, parent.span(), message));
+    }
+}
+
+/// The names `[Exposed]` may refer to: those of the interfaces with a
+/// `[Global]` attribute and the names it lists
+fn globals<'a>(model: &Model<'a>) -> Vec<&'a str> {
+    let mut globals = Vec::new();
+    for interface in model.interfaces.values() {
+        for attribute in each_attribute(interface.attributes()) {
+            match attribute {
+                ExtendedAttribute::NoArgs(it) if (it.0).0 == "Global" => {}
+                ExtendedAttribute::Ident(it) if it.lhs_identifier.0 == "Global" => {
+                    if let IdentifierOrString::Identifier(name) = it.rhs {
+                        globals.push(name.0);
+                    }
+                }
+                ExtendedAttribute::IdentList(it) if it.identifier.0 == "Global" => {
+                    globals.extend(it.list.body.list.iter().map(|name| name.0));
+                }
+                _ => continue,
+            }
+            globals.push(interface.name);
+        }
+    }
+    globals
+}
+
+fn each_attribute<'a>(
+    attributes: Option<&'a ExtendedAttributeList<'a>>,
+) -> impl Iterator<Item = &'a ExtendedAttribute<'a>> {
+    attributes.into_iter().flat_map(|it| it.body.list.iter())
+}
+
+/// Checks the overloads of each interface, mixin and namespace have a
+/// distinguishing argument index
+///
+/// Overloads all copied from a mixin are only checked once, with the mixin.
+fn overloads<'a>(
+    model: &Model<'a>,
+    definitions: &'a [Definition<'a>],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut seen = Vec::new();
+    for definition in definitions {
+        let (kind, name) = match definition {
+            Definition::Interface(it) => ("interface", it.identifier.0),
+            Definition::PartialInterface(it) => ("interface", it.identifier.0),
+            Definition::InterfaceMixin(it) => ("mixin", it.identifier.0),
+            Definition::PartialInterfaceMixin(it) => ("mixin", it.identifier.0),
+            Definition::Namespace(it) => ("namespace", it.identifier.0),
+            Definition::PartialNamespace(it) => ("namespace", it.identifier.0),
+            _ => continue,
+        };
+        if seen.contains(&(kind, name)) {
+            continue;
+        }
+        seen.push((kind, name));
+        let members = match kind {
+            "interface" => &model.interfaces[name].members,
+            "mixin" => &model.mixins[name].members,
+            _ => &model.namespaces[name].members,
+        };
+
+        for set in overload_sets(members) {
+            if kind == "interface" && set.members.iter().all(|it| it.mixin().is_some()) {
+                continue;
+            }
+            let effective = set.effective(model);
+            let mut lengths: Vec<_> = effective.iter().map(|it| it.types.len()).collect();
+            lengths.sort_unstable();
+            lengths.dedup();
+            for length in lengths {
+                let entries: Vec<_> = effective
+                    .iter()
+                    .filter(|it| it.types.len() == length)
+                    .collect();
+                if entries.len() < 2 || model.distinguishing_argument_index(&entries).is_some() {
+                    continue;
+                }
+                let arguments = match length {
+                    1 => "1 argument".to_string(),
+                    length => format!("{} arguments", length),
+                };
+                let message = match set.name {
+                    Some(name) => format!(
+                        "overloads of `{}` cannot be distinguished when called with {}",
+                        name, arguments
+                    ),
+                    None => format!(
+                        "constructors cannot be distinguished when called with {}",
+                        arguments
+                    ),
+                };
+                let last = entries.last().map(|it| it.member.span());
+                diagnostics.push(Diagnostic::new(
+                    DiagnosticKind::IndistinguishableOverloads,
+                    last.unwrap_or(Span::EMPTY),
+                    message,
+                ));
+                break;
+            }
+        }
+    }
+}
+
+/// Checks definitions, the members of each definition and the values of each
+/// enum have different names
+///
+/// Partial definitions, and operations which are both static or both not, may
+/// share their names. Duplicates among the members of a mixin are only
+/// reported once, with the mixin.
+fn duplicates<'a>(
+    model: &Model<'a>,
+    definitions: &'a [Definition<'a>],
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut names = Vec::new();
+    let mut partials = Vec::new();
+    for definition in definitions {
+        let (kind, identifier) = match definition {
+            Definition::Callback(it) => ("callback", &it.identifier),
+            Definition::CallbackInterface(it) => ("callback interface", &it.identifier),
+            Definition::Interface(it) => ("interface", &it.identifier),
+            Definition::InterfaceMixin(it) => ("interface mixin", &it.identifier),
+            Definition::Namespace(it) => ("namespace", &it.identifier),
+            Definition::Dictionary(it) => ("dictionary", &it.identifier),
+            Definition::Enum(it) => ("enum", &it.identifier),
+            Definition::Typedef(it) => ("typedef", &it.identifier),
+            Definition::PartialInterface(it) => {
+                partials.push(("interface", &it.identifier));
+                continue;
+            }
+            Definition::PartialInterfaceMixin(it) => {
+                partials.push(("interface mixin", &it.identifier));
+                continue;
+            }
+            Definition::PartialNamespace(it) => {
+                partials.push(("namespace", &it.identifier));
+                continue;
+            }
+            Definition::PartialDictionary(it) => {
+                partials.push(("dictionary", &it.identifier));
+                continue;
+            }
+            _ => continue,
+        };
+        if names.iter().any(|&(_, name)| name == identifier.0) {
+            diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Duplicate,
+                identifier.span(),
+                format!("`{}` is defined more than once", identifier.0),
+            ));
+        } else {
+            names.push((kind, identifier.0));
+        }
+    }
+    // A partial definition must be of the kind of the definition it extends,
+    // or else of the first partial definition with its name
+    for (kind, identifier) in partials {
+        match names.iter().find(|&&(_, name)| name == identifier.0) {
+            Some(&(other, _)) if other != kind => diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Duplicate,
+                identifier.span(),
+                format!(
+                    "partial {} `{}` shares its name with {} `{}`",
+                    kind, identifier.0, other, identifier.0
+                ),
+            )),
+            Some(_) => {}
+            None => names.push((kind, identifier.0)),
+        }
+    }
+
+    let mut seen = Vec::new();
+    for definition in definitions {
+        let (kind, name) = match definition {
+            Definition::CallbackInterface(it) => ("callback interface", it.identifier.0),
+            Definition::Interface(it) => ("interface", it.identifier.0),
+            Definition::PartialInterface(it) => ("interface", it.identifier.0),
+            Definition::InterfaceMixin(it) => ("interface mixin", it.identifier.0),
+            Definition::PartialInterfaceMixin(it) => ("interface mixin", it.identifier.0),
+            Definition::Namespace(it) => ("namespace", it.identifier.0),
+            Definition::PartialNamespace(it) => ("namespace", it.identifier.0),
+            Definition::Dictionary(it) => ("dictionary", it.identifier.0),
+            Definition::PartialDictionary(it) => ("dictionary", it.identifier.0),
+            Definition::Enum(it) => {
+                let mut values = Vec::new();
+                for value in &it.values.body.list {
+                    if values.contains(&value.0) {
+                        diagnostics.push(Diagnostic::new(
+                            DiagnosticKind::Duplicate,
+                            value.span(),
+                            format!(
+                                "\"{}\" is listed more than once in enum `{}`",
+                                value.0, it.identifier.0
+                            ),
+                        ));
+                    } else {
+                        values.push(value.0);
+                    }
+                }
+                continue;
+            }
+            _ => continue,
+        };
+        // Callback interfaces are not merged, each one is checked
+        if kind != "callback interface" {
+            if seen.contains(&(kind, name)) {
+                continue;
+            }
+            seen.push((kind, name));
+        }
+        let members = match (kind, definition) {
+            ("callback interface", Definition::CallbackInterface(it)) => it
+                .members
+                .body
+                .iter()
+                .map(|member| Member {
+                    node: MemberNode::Interface(member),
+                    source: definition,
+                })
+                .collect(),
+            ("interface", _) => model.interfaces[name].members.clone(),
+            ("interface mixin", _) => model.mixins[name].members.clone(),
+            ("namespace", _) => model.namespaces[name].members.clone(),
+            _ => model.dictionaries[name].members.clone(),
+        };
+
+        let mut named: Vec<&Member> = Vec::new();
+        for member in &members {
+            let identifier = match member.node.identifier() {
+                Some(identifier) => identifier,
+                None => continue,
+            };
+            let previous = match named.iter().find(|it| it.name() == Some(identifier.0)) {
+                Some(previous) => previous,
+                None => {
+                    named.push(member);
+                    continue;
+                }
+            };
+            // Only operations of the same kind overload each other, a static
+            // and a regular operation cannot share an identifier
+            let overloads = match (overload_kind(previous), overload_kind(member)) {
+                (Some((_, a)), Some((_, b))) => a == b,
+                _ => false,
+            };
+            if overloads
+                || (kind == "interface"
+                    && previous.mixin().is_some()
+                    && previous.mixin() == member.mixin())
+            {
+                continue;
+            }
+            diagnostics.push(Diagnostic::new(
+                DiagnosticKind::Duplicate,
+                identifier.span(),
+                format!(
+                    "`{}` is declared more than once in {} `{}`",
+                    identifier.0, kind, name
+                ),
+            ));
+        }
+    }
+}
+
+/// Finds the names which do not refer to what they should
+struct References<'a, 'b> {
+    model: &'b Model<'a>,
+    options: &'b Options,
+    globals: Vec<&'a str>,
+    diagnostics: &'b mut Vec<Diagnostic>,
+}
+
+impl<'a, 'b> References<'a, 'b> {
+    fn is_external(&self, name: &str) -> bool {
+        self.options.external.iter().any(|it| it == name)
+    }
+
+    fn report(&mut self, identifier: &Identifier<'a>, message: String) {
+        self.diagnostics.push(Diagnostic::new(
+            DiagnosticKind::UndefinedReference,
+            identifier.span(),
+            message,
+        ));
+    }
+
+    fn type_(&mut self, identifier: &Identifier<'a>) {
+        let name = identifier.0;
+        if self.model.classify(name) == IdentifierKind::Unknown && !self.is_external(name) {
+            self.report(identifier, format!("cannot find type `{}`", name));
+        }
+    }
+
+    /// Checks `[PutForwards]` names an attribute of the interface `type_` is
+    fn put_forwards(
+        &mut self,
+        attributes: &'a Option<ExtendedAttributeList<'a>>,
+        type_: &'a AttributedType<'a>,
+    ) {
+        let forward = each_attribute(attributes.as_ref()).find_map(|it| match it {
+            ExtendedAttribute::Ident(it) if it.lhs_identifier.0 == "PutForwards" => match it.rhs {
+                IdentifierOrString::Identifier(name) => Some(name),
+                IdentifierOrString::String(_) => None,
+            },
+            _ => None,
+        });
+        let forward = match forward {
+            Some(forward) => forward,
+            None => return,
+        };
+        // Types which are not interfaces are left to the other checks
+        let interface = match self.model.resolve_attributed(type_).kind {
+            ResolvedTypeKind::Identifier(name, IdentifierKind::Interface) => name,
+            _ => return,
+        };
+
+        let mut chain = vec![interface];
+        chain.extend(self.model.interface_ancestors(interface));
+        for name in chain {
+            let record = match self.model.interfaces.get(name) {
+                Some(record) => record,
+                // Inherited from an external interface
+                None => return,
+            };
+            let found = record.members.iter().any(|member| match member.node {
+                MemberNode::Interface(InterfaceMember::Attribute(it)) => {
+                    it.identifier.0 == forward.0
+                }
+                MemberNode::Mixin(MixinMember::Attribute(it)) => it.identifier.0 == forward.0,
+                _ => false,
+            });
+            if found {
+                return;
+            }
+        }
+
+        self.report(
+            &forward,
+            format!("cannot find attribute `{}` of `{}`", forward.0, interface),
+        );
+    }
+}
+
+impl<'a, 'b> Visit<'a> for References<'a, 'b> {
+    fn visit_non_any_type(&mut self, node: &'a NonAnyType<'a>) {
+        if let NonAnyType::Identifier(identifier) = node {
+            self.type_(&identifier.type_);
+        }
+        visit::visit_non_any_type(self, node);
+    }
+
+    fn visit_const_type(&mut self, node: &'a ConstType<'a>) {
+        if let ConstType::Identifier(identifier) = node {
+            self.type_(&identifier.type_);
+        }
+        visit::visit_const_type(self, node);
+    }
+
+    fn visit_inheritance(&mut self, node: &'a Inheritance<'a>) {
+        let name = node.identifier.0;
+        if describe(self.model, name).is_none() && !self.is_external(name) {
+            self.report(&node.identifier, format!("cannot find `{}`", name));
+        }
+    }
+
+    fn visit_includes_statement_definition(&mut self, node: &'a IncludesStatementDefinition<'a>) {
+        let (lhs, rhs) = (&node.lhs_identifier, &node.rhs_identifier);
+        if !self.model.interfaces.contains_key(lhs.0) && !self.is_external(lhs.0) {
+            self.report(lhs, format!("cannot find interface `{}`", lhs.0));
+        }
+        if !self.model.mixins.contains_key(rhs.0) && !self.is_external(rhs.0) {
+            self.report(rhs, format!("cannot find interface mixin `{}`", rhs.0));
+        }
+        visit::visit_includes_statement_definition(self, node);
+    }
+
+    fn visit_extended_attribute(&mut self, node: &'a ExtendedAttribute<'a>) {
+        let names = match node {
+            ExtendedAttribute::Ident(it) if it.lhs_identifier.0 == "Exposed" => match &it.rhs {
+                IdentifierOrString::Identifier(name) => vec![name],
+                IdentifierOrString::String(_) => vec![],
+            },
+            ExtendedAttribute::IdentList(it) if it.identifier.0 == "Exposed" => {
+                it.list.body.list.iter().collect()
+            }
+            _ => vec![],
+        };
+        for name in names {
+            if !self.globals.contains(&name.0) && !self.is_external(name.0) {
+                self.report(name, format!("cannot find global `{}`", name.0));
+            }
+        }
+        visit::visit_extended_attribute(self, node);
+    }
+
+    fn visit_attribute_interface_member(&mut self, node: &'a AttributeInterfaceMember<'a>) {
+        self.put_forwards(&node.attributes, &node.type_);
+        visit::visit_attribute_interface_member(self, node);
+    }
+
+    fn visit_attribute_mixin_member(&mut self, node: &'a AttributeMixinMember<'a>) {
+        self.put_forwards(&node.attributes, &node.type_);
+        visit::visit_attribute_mixin_member(self, node);
+    }
+}
+
+/// The types values are checked against
+#[derive(Clone, Copy)]
+enum Primitive {
+    Boolean,
+    /// The smallest and largest values
+    Integer(i128, i128),
+    /// Any integer, however large
+    BigInt,
+    Float {
+        single: bool,
+        unrestricted: bool,
+    },
+}
+
+impl Primitive {
+    fn of_integer(type_: &IntegerType) -> Self {
+        match type_ {
+            IntegerType::Short(it) if it.unsigned.is_some() => Primitive::integer::<u16>(),
+            IntegerType::Short(_) => Primitive::integer::<i16>(),
+            IntegerType::Long(it) if it.unsigned.is_some() => Primitive::integer::<u32>(),
+            IntegerType::Long(_) => Primitive::integer::<i32>(),
+            IntegerType::LongLong(it) if it.unsigned.is_some() => Primitive::integer::<u64>(),
+            IntegerType::LongLong(_) => Primitive::integer::<i64>(),
+        }
+    }
+
+    fn integer<T: Bounded>() -> Self {
+        Primitive::Integer(T::MIN, T::MAX)
+    }
+
+    fn of_floating_point(type_: &FloatingPointType) -> Self {
+        match type_ {
+            FloatingPointType::Float(it) => Primitive::Float {
+                single: true,
+                unrestricted: it.unrestricted.is_some(),
+            },
+            FloatingPointType::Double(it) => Primitive::Float {
+                single: false,
+                unrestricted: it.unrestricted.is_some(),
+            },
+        }
+    }
+
+    /// The primitive `type_` is, `None` if it is not a primitive type
+    fn of_non_any(type_: &NonAnyType) -> Option<Self> {
+        let primitive = match type_ {
+            NonAnyType::Integer(it) => Primitive::of_integer(&it.type_),
+            NonAnyType::FloatingPoint(it) => Primitive::of_floating_point(&it.type_),
+            NonAnyType::Boolean(_) => Primitive::Boolean,
+            NonAnyType::Byte(_) => Primitive::integer::<i8>(),
+            NonAnyType::Octet(_) => Primitive::integer::<u8>(),
+            NonAnyType::BigInt(_) => Primitive::BigInt,
+            _ => return None,
+        };
+        Some(primitive)
+    }
+
+    /// Checks `value` is a value of this type, or of its nullable version
+    /// if `nullable`
+    fn check(self, value: &ConstValue, nullable: bool) -> Result<(), DiagnosticKind> {
+        match (self, value) {
+            (_, ConstValue::Null(_)) if nullable => Ok(()),
+            (Primitive::Boolean, ConstValue::Boolean(_)) => Ok(()),
+            (Primitive::Integer(min, max), ConstValue::Integer(it)) => match it.value() {
+                Some(value) if min <= value && value <= max => Ok(()),
+                _ => Err(DiagnosticKind::OutOfRange),
+            },
+            (Primitive::BigInt, ConstValue::Integer(_)) => Ok(()),
+            (
+                Primitive::Float {
+                    single,
+                    unrestricted,
+                },
+                ConstValue::Integer(it),
+            ) => Primitive::check_float(it.float_value(), single, unrestricted),
+            (
+                Primitive::Float {
+                    single,
+                    unrestricted,
+                },
+                ConstValue::Float(it),
+            ) => match it.value() {
+                Some(value) => Primitive::check_float(value, single, unrestricted),
+                None => Err(DiagnosticKind::TypeMismatch),
+            },
+            _ => Err(DiagnosticKind::TypeMismatch),
+        }
+    }
+
+    /// Checks `value` is a `float` if `single`, or else a `double`
+    fn check_float(value: f64, single: bool, unrestricted: bool) -> Result<(), DiagnosticKind> {
+        if unrestricted || value.is_finite() && (!single || value.abs() <= f64::from(f32::MAX)) {
+            Ok(())
+        } else if value.is_nan() {
+            Err(DiagnosticKind::RestrictedNaN)
+        } else {
+            Err(DiagnosticKind::OutOfRange)
+        }
+    }
+}
+
+/// Integer types with bounds which fit in an `i128`
+trait Bounded {
+    const MIN: i128;
+    const MAX: i128;
+}
+
+macro_rules! bounded {
+    ($($type_:ty),*) => {
+        $(
+            impl Bounded for $type_ {
+                const MIN: i128 = <$type_>::MIN as i128;
+                const MAX: i128 = <$type_>::MAX as i128;
+            }
+        )*
+    };
+}
+
+bounded!(i8, u8, i16, u16, i32, u32, i64, u64);
+
+/// Adds the flattened member types of `type_` to `members`, and tells whether
+/// it includes a nullable type
+fn flatten<'a, 'c>(type_: &'c ResolvedType<'a>, members: &mut Vec<&'c ResolvedType<'a>>) -> bool {
+    match &type_.kind {
+        ResolvedTypeKind::Union(types) => types.iter().fold(type_.nullable, |nullable, it| {
+            flatten(it, members) || nullable
+        }),
+        _ => {
+            members.push(type_);
+            type_.nullable
+        }
+    }
+}
+
+/// Checks `value` is a value of one of the primitive types among `members`
+fn primitive(members: &[&ResolvedType], value: &ConstValue) -> Result<(), DiagnosticKind> {
+    let mut checked = Err(DiagnosticKind::TypeMismatch);
+    for member in members {
+        let primitive = match member.kind {
+            ResolvedTypeKind::Builtin(type_) => Primitive::of_non_any(type_),
+            _ => None,
+        };
+        match primitive.map(|it| it.check(value, false)) {
+            Some(Ok(())) => return Ok(()),
+            Some(Err(DiagnosticKind::TypeMismatch)) | None => {}
+            Some(Err(kind)) => checked = Err(kind),
+        }
+    }
+    checked
+}
+
+/// Finds the values which do not fit their types
+struct Values<'a, 'b> {
+    model: &'b Model<'a>,
+    diagnostics: &'b mut Vec<Diagnostic>,
+}
+
+impl<'a, 'b> Values<'a, 'b> {
+    fn report(&mut self, kind: DiagnosticKind, span: Span, value: String, type_: String) {
+        let message = match kind {
+            DiagnosticKind::OutOfRange => format!("`{}` is out of range for `{}`", value, type_),
+            DiagnosticKind::RestrictedNaN => {
+                format!(
+                    "`{}` cannot be a value of `{}`, which is not unrestricted",
+                    value, type_
+                )
+            }
+            _ => format!("`{}` cannot be a value of type `{}`", value, type_),
+        };
+        self.diagnostics.push(Diagnostic::new(kind, span, message));
+    }
+
+    fn constant(&mut self, type_: &'a ConstType<'a>, value: &'a ConstValue<'a>) {
+        let primitive = match type_ {
+            ConstType::Integer(it) => Some((Primitive::of_integer(&it.type_), it.q_mark.is_some())),
+            ConstType::FloatingPoint(it) => {
+                Some((Primitive::of_floating_point(&it.type_), it.q_mark.is_some()))
+            }
+            ConstType::Boolean(it) => Some((Primitive::Boolean, it.q_mark.is_some())),
+            ConstType::Byte(it) => Some((Primitive::integer::<i8>(), it.q_mark.is_some())),
+            ConstType::Octet(it) => Some((Primitive::integer::<u8>(), it.q_mark.is_some())),
+            ConstType::BigInt(it) => Some((Primitive::BigInt, it.q_mark.is_some())),
+            ConstType::Identifier(it) => {
+                let resolved = self.model.resolve_identifier(it.type_.0);
+                match resolved.kind {
+                    ResolvedTypeKind::Builtin(type_) => Primitive::of_non_any(type_)
+                        .map(|primitive| (primitive, resolved.nullable || it.q_mark.is_some())),
+                    // Undefined names are reported with the other references
+                    ResolvedTypeKind::Identifier(_, IdentifierKind::Unknown) => return,
+                    _ => None,
+                }
+            }
+        };
+        let checked = match primitive {
+            Some((primitive, nullable)) => primitive.check(value, nullable),
+            None => Err(DiagnosticKind::TypeMismatch),
+        };
+        if let Err(kind) = checked {
+            self.report(kind, value.span(), value.to_string(), type_.to_string());
+        }
+    }
+
+    fn default(&mut self, type_: &'a Type<'a>, default: &'a Option<Default<'a>>) {
+        let value = match default {
+            Some(default) => &default.value,
+            None => return,
+        };
+        let resolved = self.model.resolve(type_);
+        let mut members = Vec::new();
+        let nullable = flatten(&resolved, &mut members);
+        // Anything goes for `any`, and undefined names are reported with the
+        // other references
+        let unchecked = members.iter().any(|member| {
+            matches!(
+                member.kind,
+                ResolvedTypeKind::Any | ResolvedTypeKind::Identifier(_, IdentifierKind::Unknown)
+            )
+        });
+        if unchecked {
+            return;
+        }
+
+        let accepts = |accepts: &dyn Fn(&ResolvedTypeKind<'a>) -> bool| {
+            if members.iter().any(|member| accepts(&member.kind)) {
+                Ok(())
+            } else {
+                Err(DiagnosticKind::TypeMismatch)
+            }
+        };
+        let checked = match value {
+            DefaultValue::Null(_) if nullable => Ok(()),
+            DefaultValue::Null(_) => Err(DiagnosticKind::TypeMismatch),
+            DefaultValue::EmptyArray(_) => {
+                accepts(&|kind| matches!(kind, ResolvedTypeKind::Sequence(_)))
+            }
+            DefaultValue::EmptyDictionary(_) => accepts(&|kind| {
+                matches!(
+                    kind,
+                    ResolvedTypeKind::Identifier(_, IdentifierKind::Dictionary)
+                )
+            }),
+            DefaultValue::String(string) => accepts(&|kind| match kind {
+                ResolvedTypeKind::Builtin(NonAnyType::ByteString(_))
+                | ResolvedTypeKind::Builtin(NonAnyType::DOMString(_))
+                | ResolvedTypeKind::Builtin(NonAnyType::USVString(_)) => true,
+                ResolvedTypeKind::Identifier(name, IdentifierKind::Enum) => self
+                    .model
+                    .enums
+                    .get(name)
+                    .into_iter()
+                    .any(|it| it.values.body.list.iter().any(|value| value.0 == string.0)),
+                _ => false,
+            }),
+            DefaultValue::Boolean(it) => primitive(&members, &ConstValue::Boolean(*it)),
+            DefaultValue::Float(it) => primitive(&members, &ConstValue::Float(*it)),
+            DefaultValue::Integer(it) => primitive(&members, &ConstValue::Integer(*it)),
+        };
+        if let Err(kind) = checked {
+            self.report(kind, value.span(), value.to_string(), type_.to_string());
+        }
+    }
+}
+
+impl<'a, 'b> Visit<'a> for Values<'a, 'b> {
+    fn visit_const_member(&mut self, node: &'a ConstMember<'a>) {
+        self.constant(&node.const_type, &node.const_value);
+    }
+
+    fn visit_const_namespace_member(&mut self, node: &'a ConstNamespaceMember<'a>) {
+        self.constant(&node.const_type, &node.const_value);
+    }
+
+    fn visit_single_argument(&mut self, node: &'a SingleArgument<'a>) {
+        self.default(&node.type_.type_, &node.default);
+    }
+
+    fn visit_dictionary_member(&mut self, node: &'a DictionaryMember<'a>) {
+        self.default(&node.type_, &node.default);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn messages(input: &str, external: &[&str]) -> Vec<String> {
+        let parsed = crate::parse(input).unwrap();
+        let options = Options {
+            external: external.iter().map(|it| it.to_string()).collect(),
+        };
+        validate(&parsed, &options)
+            .into_iter()
+            .map(|it| it.message)
+            .collect()
+    }
+
+    #[test]
+    fn should_report_undefined_types() {
+        let input = "
+            typedef (Foo or sequence<Bar>) FooOrBar;
+            interface mixin Mixin {};
+            callback Callback = Baz (Callback c, Mixin m, record<DOMString, Qux> r);
+            dictionary Dict {
+                Enum e = \"a\";
+                FooOrBar f;
+            };
+            enum Enum { \"a\" };
+            namespace Ns { const Long l = 1; };
+        ";
+        assert_eq!(
+            messages(input, &["Qux"]),
+            [
+                "cannot find type `Foo`",
+                "cannot find type `Bar`",
+                "cannot find type `Baz`",
+                "cannot find type `Mixin`",
+                "cannot find type `Long`",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_report_undefined_inheritance_and_includes() {
+        let input = "
+            interface A : B {};
+            interface C : A {};
+            callback interface D : E { undefined f(); };
+            A includes F;
+            G includes A;
+            interface mixin H {};
+            I includes H;
+        ";
+        assert_eq!(
+            messages(input, &["E", "I"]),
+            [
+                "cannot find `B`",
+                "cannot find interface mixin `F`",
+                "cannot find interface `G`",
+                "cannot find interface mixin `A`",
+            ]
+        );
+    }
+
+    #[test]
+    fn should_report_undefined_attribute_references() {
+        let input = "
+            [Global=(Worker, ServiceWorker), Exposed=ServiceWorker]
+            interface ServiceWorkerGlobalScope {};
+            [Global, Exposed=Window]
+            interface Window {
+                [PutForwards=href] readonly attribute Location location;
+                [PutForwards=hash] readonly attribute Location other;
+                [PutForwards=nope] readonly attribute Location missing;
+            };
+            [Exposed=(Window, Worker, Nowhere)]
+            interface Location : Base {
+                attribute DOMString href;
+            };
+            [Exposed=*]
+            interface Base {};
+            interface mixin Hash { attribute DOMString hash; };
+            Base includes Hash;
+        ";
+        let parsed = crate::parse(input).unwrap();
+        let diagnostics = validate(&parsed, &Options::default());
+        let messages: Vec<_> = diagnostics.iter().map(|it| &*it.message).collect();
+        assert_eq!(
+            messages,
+            [
+                "cannot find attribute `nope` of `Location`",
+                "cannot find global `Nowhere`",
+            ]
+        );
+        assert_eq!(diagnostics[0].span.as_str(input).unwrap(), "nope");
+    }
+
+    #[test]
+    fn should_report_inheritance_kinds() {
+        let input = "
+            interface A : D {};
+            dictionary D : A {};
+            callback interface C : A { undefined f(); };
+            interface B : M {};
+            interface mixin M {};
+            interface E : Missing {};
+            dictionary F : D {};
+        ";
+        let parsed = crate::parse(input).unwrap();
+        let diagnostics = validate(&parsed, &Options::default());
+        let found: Vec<_> = diagnostics
+            .iter()
+            .map(|it| (it.kind, &*it.message))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (DiagnosticKind::UndefinedReference, "cannot find `Missing`"),
+                (
+                    DiagnosticKind::InheritanceKind,
+                    "interface `A` cannot inherit from dictionary `D`"
+                ),
+                (
+                    DiagnosticKind::InheritanceKind,
+                    "dictionary `D` cannot inherit from interface `A`"
+                ),
+                (
+                    DiagnosticKind::InheritanceKind,
+                    "callback interface `C` cannot inherit"
+                ),
+                (
+                    DiagnosticKind::InheritanceKind,
+                    "interface `B` cannot inherit from interface mixin `M`"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn should_report_inheritance_cycles() {
+        let input = "
+            interface A : A {};
+            interface B : C {};
+            interface C : D {};
+            interface D : B {};
+            interface E : B {};
+            dictionary F : G {};
+            dictionary G : F {};
+        ";
+        let parsed = crate::parse(input).unwrap();
+        let diagnostics = validate(&parsed, &Options::default());
+        let found: Vec<_> = diagnostics.iter().map(|it| &*it.message).collect();
+        assert_eq!(
+            found,
+            [
+                "interface `A` inherits from itself",
+                "interface `B` inherits from itself through `C`, `D`",
+                "interface `C` inherits from itself through `D`, `B`",
+                "interface `D` inherits from itself through `B`, `C`",
+                "dictionary `F` inherits from itself through `G`",
+                "dictionary `G` inherits from itself through `F`",
+            ]
+        );
+        assert!(diagnostics
+            .iter()
+            .all(|it| it.kind == DiagnosticKind::InheritanceCycle));
+        assert_eq!(diagnostics[0].span.as_str(input).unwrap(), "A");
+    }
+
+    #[test]
+    fn should_report_indistinguishable_overloads() {
+        let input = "
+            interface Foo {
+                constructor(long a);
+                constructor(double a);
+                undefined f(optional long a);
+                undefined f(DOMString a);
+                undefined g(long a);
+                static undefined g(long a);
+            };
+            partial interface Foo {
+                undefined f(optional Node a);
+            };
+            interface Node {};
+            interface mixin Mixin {
+                undefined h(long a);
+                undefined h(short a);
+            };
+            Foo includes Mixin;
+            Node includes Mixin;
+            namespace Ns {
+                undefined i(long... a);
+                undefined i(long a, optional DOMString b);
+            };
+        ";
+        let parsed = crate::parse(input).unwrap();
+        let diagnostics = validate(&parsed, &Options::default());
+        let found: Vec<_> = diagnostics.iter().map(|it| &*it.message).collect();
+        assert_eq!(
+            found,
+            [
+                "constructors cannot be distinguished when called with 1 argument",
+                "overloads of `f` cannot be distinguished when called with 0 arguments",
+                "overloads of `h` cannot be distinguished when called with 1 argument",
+                "overloads of `i` cannot be distinguished when called with 1 argument",
+                "`g` is declared more than once in interface `Foo`",
+            ]
+        );
+        assert_eq!(
+            diagnostics[0].span.as_str(input).unwrap(),
+            "constructor(double a);"
+        );
+        assert_eq!(
+            diagnostics[1].span.as_str(input),
+            Some("undefined f(optional Node a);")
+        );
+    }
+
+    #[test]
+    fn should_report_duplicates() {
+        let input = "
+            interface Foo {
+                attribute long a;
+                undefined b();
+                undefined b(long x);
+                const long c = 1;
+            };
+            partial interface Foo {
+                undefined c();
+            };
+            dictionary Foo {};
+            interface mixin Mixin {
+                attribute long a;
+                attribute long d;
+                attribute long d;
+            };
+            Foo includes Mixin;
+            callback interface Listener {
+                undefined handle();
+                attribute long handle;
+            };
+            enum Mode { \"a\", \"b\", \"a\" };
+            dictionary Dict { long x; };
+            partial dictionary Dict { long x; };
+            typedef long Mode;
+            partial dictionary Foo {};
+            partial namespace Ns {};
+            partial interface Ns {};
+        ";
+        let parsed = crate::parse(input).unwrap();
+        let diagnostics = validate(&parsed, &Options::default());
+        assert!(diagnostics
+            .iter()
+            .all(|it| it.kind == DiagnosticKind::Duplicate));
+        let found: Vec<_> = diagnostics.iter().map(|it| &*it.message).collect();
+        assert_eq!(
+            found,
+            [
+                "`Foo` is defined more than once",
+                "`Mode` is defined more than once",
+                "partial dictionary `Foo` shares its name with interface `Foo`",
+                "partial interface `Ns` shares its name with namespace `Ns`",
+                "`c` is declared more than once in interface `Foo`",
+                "`a` is declared more than once in interface `Foo`",
+                "`d` is declared more than once in interface mixin `Mixin`",
+                "`handle` is declared more than once in callback interface `Listener`",
+                "\"a\" is listed more than once in enum `Mode`",
+                "`x` is declared more than once in dictionary `Dict`",
+            ]
+        );
+        assert_eq!(diagnostics[4].span.start(input).unwrap().line, 9);
+    }
+
+    #[test]
+    fn should_report_static_and_regular_operations_sharing_a_name() {
+        let input = "
+            interface Foo {
+                static undefined f();
+                undefined f();
+                static undefined g();
+                static undefined g(long x);
+                getter long h(long x);
+                long h(DOMString x);
+            };
+        ";
+        let parsed = crate::parse(input).unwrap();
+        let diagnostics = validate(&parsed, &Options::default());
+        let found: Vec<_> = diagnostics
+            .iter()
+            .map(|it| (it.kind, &*it.message))
+            .collect();
+        assert_eq!(
+            found,
+            [(
+                DiagnosticKind::Duplicate,
+                "`f` is declared more than once in interface `Foo`"
+            )]
+        );
+        assert_eq!(diagnostics[0].span.start(input).unwrap().line, 4);
+    }
+
+    #[test]
+    fn should_report_constant_values() {
+        let input = "
+            typedef unsigned short? Short;
+            typedef DOMString Text;
+            interface Foo {
+                const octet a = 255;
+                const octet b = 0x100;
+                const byte c = -129;
+                const unsigned long long d = 0xFFFFFFFFFFFFFFFF;
+                const long long e = 0x8000000000000000;
+                const long f = 1.5;
+                const boolean g = 1;
+                const float h = 3.5e38;
+                const unrestricted float i = Infinity;
+                const double j = NaN;
+                const double k = -017;
+                const float fk = 0x1000000000000000000000000000000000;
+                const double dk = 0x1000000000000000000000000000000000;
+                const bigint big = 0x7FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF;
+                const bigint notbig = 1.0;
+                const long l = null;
+                const Short m = null;
+                const Short n = -1;
+                const Text o = 1;
+                const Missing p = 1;
+            };
+            namespace Ns { const boolean? q = null; };
+            interface mixin Mixin { const short r = 40000; };
+        ";
+        let parsed = crate::parse(input).unwrap();
+        let diagnostics = validate(&parsed, &Options::default());
+        let found: Vec<_> = diagnostics
+            .iter()
+            .filter(|it| it.kind != DiagnosticKind::UndefinedReference)
+            .map(|it| (it.kind, &*it.message))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    DiagnosticKind::OutOfRange,
+                    "`0x100` is out of range for `octet`"
+                ),
+                (
+                    DiagnosticKind::OutOfRange,
+                    "`-129` is out of range for `byte`"
+                ),
+                (
+                    DiagnosticKind::OutOfRange,
+                    "`0x8000000000000000` is out of range for `long long`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`1.5` cannot be a value of type `long`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`1` cannot be a value of type `boolean`"
+                ),
+                (
+                    DiagnosticKind::OutOfRange,
+                    "`3.5e38` is out of range for `float`"
+                ),
+                (
+                    DiagnosticKind::RestrictedNaN,
+                    "`NaN` cannot be a value of `double`, which is not unrestricted"
+                ),
+                (
+                    DiagnosticKind::OutOfRange,
+                    "`0x1000000000000000000000000000000000` is out of range for `float`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`1.0` cannot be a value of type `bigint`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`null` cannot be a value of type `long`"
+                ),
+                (
+                    DiagnosticKind::OutOfRange,
+                    "`-1` is out of range for `Short`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`1` cannot be a value of type `Text`"
+                ),
+                (
+                    DiagnosticKind::OutOfRange,
+                    "`40000` is out of range for `short`"
+                ),
+            ]
+        );
+        assert_eq!(diagnostics[1].span.as_str(input).unwrap(), "0x100");
+    }
+
+    #[test]
+    fn should_report_default_values() {
+        let input = "
+            dictionary Options {
+                boolean a = \"yes\";
+                long b = [];
+                sequence<long> c = [];
+                Mode d = \"fast\";
+                Mode e = \"slow\";
+                (Options or DOMString)? f = null;
+                octet g = 256;
+                (octet or DOMString) h = 1;
+                record<DOMString, long> i = {};
+                USVString j = null;
+            };
+            enum Mode { \"fast\" };
+            typedef Options? MaybeOptions;
+            interface Foo {
+                undefined f(optional Options a = {}, optional MaybeOptions b = {});
+                undefined g(optional long a = {}, optional any b = \"x\");
+                undefined h(optional (double or boolean) a = true, optional float b = 1.5);
+                undefined i(optional Missing a = 1, optional boolean b = 0);
+            };
+        ";
+        let parsed = crate::parse(input).unwrap();
+        let diagnostics = validate(&parsed, &Options::default());
+        let found: Vec<_> = diagnostics
+            .iter()
+            .filter(|it| it.kind != DiagnosticKind::UndefinedReference)
+            .map(|it| (it.kind, &*it.message))
+            .collect();
+        assert_eq!(
+            found,
+            [
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`\"yes\"` cannot be a value of type `boolean`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`[]` cannot be a value of type `long`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`\"slow\"` cannot be a value of type `Mode`"
+                ),
+                (
+                    DiagnosticKind::OutOfRange,
+                    "`256` is out of range for `octet`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`{}` cannot be a value of type `record<DOMString, long>`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`null` cannot be a value of type `USVString`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`{}` cannot be a value of type `long`"
+                ),
+                (
+                    DiagnosticKind::TypeMismatch,
+                    "`0` cannot be a value of type `boolean`"
+                ),
+            ]
+        );
+        assert_eq!(diagnostics[1].span.as_str(input).unwrap(), "\"yes\"");
+    }
+
+    #[test]
+    fn should_allow_unrestricted_integer_defaults() {
+        let input = "
+            dictionary Options {
+                unrestricted float a = 0x1000000000000000000000000000000000;
+                float b = 0x1000000000000000000000000000000000;
+            };
+            interface Foo {
+                undefined f(optional unrestricted float a = 0x1000000000000000000000000000000000);
+            };
+        ";
+        let parsed = crate::parse(input).unwrap();
+        let diagnostics = validate(&parsed, &Options::default());
+        let found: Vec<_> = diagnostics
+            .iter()
+            .map(|it| (it.kind, &*it.message))
+            .collect();
+        assert_eq!(
+            found,
+            [(
+                DiagnosticKind::OutOfRange,
+                "`0x1000000000000000000000000000000000` is out of range for `float`"
+            )]
+        );
+    }
+}
@@ -0,0 +1,1413 @@
+//! Walking the syntax tree by reference
+//!
+//! Each method of [`Visit`](trait.Visit.html) visits a type of node, walking
+//! into its children by default through the function of the same name. A
+//! visitor overrides the methods of the nodes it is interested in and calls
+//! that function to keep walking. Keywords and punctuation are not visited.
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::types::NonAnyType;
+//! use weedle::visit::{self, Visit};
+//!
+//! // Collects the names of the types used
+//! struct TypeNames<'a>(Vec<&'a str>);
+//!
+//! impl<'a> Visit<'a> for TypeNames<'a> {
+//!     fn visit_non_any_type(&mut self, node: &'a NonAnyType<'a>) {
+//!         if let NonAnyType::Identifier(identifier) = node {
+//!             self.0.push(identifier.type_.0);
+//!         }
+//!         visit::visit_non_any_type(self, node);
+//!     }
+//! }
+//!
+//! let parsed = weedle::parse("
+//!     interface Node {
+//!         readonly attribute Node? parentNode;
+//!         sequence<Element> children(optional ElementFilter filter);
+//!     };
+//! ").unwrap();
+//!
+//! let mut names = TypeNames(Vec::new());
+//! for definition in &parsed {
+//!     names.visit_definition(definition);
+//! }
+//! assert_eq!(names.0, ["Node", "Element", "ElementFilter"]);
+//! ```
+
+use crate::argument::{
+    Argument, ArgumentList, ArgumentName, ArgumentNameKeyword, SingleArgument, VariadicArgument,
+};
+use crate::attribute::{
+    ExtendedAttribute, ExtendedAttributeArgList, ExtendedAttributeIdent,
+    ExtendedAttributeIdentList, ExtendedAttributeList, ExtendedAttributeNamedArgList,
+    ExtendedAttributeNoArgs, ExtendedAttributeWildCard, IdentifierList, IdentifierOrString,
+};
+use crate::common::{Default, Identifier};
+use crate::dictionary::DictionaryMember;
+use crate::interface::{
+    AsyncIterableInterfaceMember, AsyncIterableKeyword, AttributeInterfaceMember, ConstMember,
+    ConstructorInterfaceMember, DoubleTypedAsyncIterable, DoubleTypedIterable, Inheritance,
+    InterfaceMember, IterableInterfaceMember, MaplikeInterfaceMember, OperationInterfaceMember,
+    SeparateAsyncIterable, SetlikeInterfaceMember, SingleTypedAsyncIterable, SingleTypedIterable,
+    Special, StringifierMember, StringifierOrInheritOrStatic, StringifierOrStatic,
+};
+use crate::literal::{
+    BooleanLit, ConstValue, DecLit, DefaultValue, EmptyArrayLit, EmptyDictionaryLit, FloatLit,
+    FloatValueLit, HexLit, IntegerLit, OctLit, StringLit,
+};
+use crate::mixin::{AttributeMixinMember, MixinMember, OperationMixinMember};
+use crate::namespace::{
+    AttributeNamespaceMember, ConstNamespaceMember, NamespaceMember, OperationNamespaceMember,
+};
+use crate::types::{
+    AttributedNonAnyType, AttributedType, ConstType, DoubleType, FloatType, FloatingPointType,
+    FrozenArrayType, IntegerType, LongLongType, LongType, NonAnyType, ObservableArrayType,
+    PromiseType, RecordKeyType, RecordType, ReturnType, SequenceType, ShortType, SingleType, Type,
+    UnionMemberType, UnionType,
+};
+use crate::{
+    CallbackDefinition, CallbackInterfaceDefinition, Definition, DictionaryDefinition,
+    EnumDefinition, EnumValueList, ImplementsDefinition, IncludesStatementDefinition,
+    InterfaceDefinition, InterfaceMixinDefinition, NamespaceDefinition,
+    PartialDictionaryDefinition, PartialInterfaceDefinition, PartialInterfaceMixinDefinition,
+    PartialNamespaceDefinition, TypedefDefinition,
+};
+
+/// Visits the nodes of a syntax tree borrowed for `'a`
+pub trait Visit<'a> {
+    fn visit_argument(&mut self, node: &'a Argument<'a>) {
+        visit_argument(self, node)
+    }
+
+    fn visit_argument_list(&mut self, node: &'a ArgumentList<'a>) {
+        visit_argument_list(self, node)
+    }
+
+    fn visit_argument_name(&mut self, node: &'a ArgumentName<'a>) {
+        visit_argument_name(self, node)
+    }
+
+    fn visit_argument_name_keyword(&mut self, node: &'a ArgumentNameKeyword) {
+        visit_argument_name_keyword(self, node)
+    }
+
+    fn visit_async_iterable_interface_member(
+        &mut self,
+        node: &'a AsyncIterableInterfaceMember<'a>,
+    ) {
+        visit_async_iterable_interface_member(self, node)
+    }
+
+    fn visit_async_iterable_keyword(&mut self, node: &'a AsyncIterableKeyword) {
+        visit_async_iterable_keyword(self, node)
+    }
+
+    fn visit_attribute_interface_member(&mut self, node: &'a AttributeInterfaceMember<'a>) {
+        visit_attribute_interface_member(self, node)
+    }
+
+    fn visit_attribute_mixin_member(&mut self, node: &'a AttributeMixinMember<'a>) {
+        visit_attribute_mixin_member(self, node)
+    }
+
+    fn visit_attribute_namespace_member(&mut self, node: &'a AttributeNamespaceMember<'a>) {
+        visit_attribute_namespace_member(self, node)
+    }
+
+    fn visit_attributed_non_any_type(&mut self, node: &'a AttributedNonAnyType<'a>) {
+        visit_attributed_non_any_type(self, node)
+    }
+
+    fn visit_attributed_type(&mut self, node: &'a AttributedType<'a>) {
+        visit_attributed_type(self, node)
+    }
+
+    fn visit_boolean_lit(&mut self, node: &'a BooleanLit) {
+        visit_boolean_lit(self, node)
+    }
+
+    fn visit_callback_definition(&mut self, node: &'a CallbackDefinition<'a>) {
+        visit_callback_definition(self, node)
+    }
+
+    fn visit_callback_interface_definition(&mut self, node: &'a CallbackInterfaceDefinition<'a>) {
+        visit_callback_interface_definition(self, node)
+    }
+
+    fn visit_const_member(&mut self, node: &'a ConstMember<'a>) {
+        visit_const_member(self, node)
+    }
+
+    fn visit_const_namespace_member(&mut self, node: &'a ConstNamespaceMember<'a>) {
+        visit_const_namespace_member(self, node)
+    }
+
+    fn visit_const_type(&mut self, node: &'a ConstType<'a>) {
+        visit_const_type(self, node)
+    }
+
+    fn visit_const_value(&mut self, node: &'a ConstValue<'a>) {
+        visit_const_value(self, node)
+    }
+
+    fn visit_constructor_interface_member(&mut self, node: &'a ConstructorInterfaceMember<'a>) {
+        visit_constructor_interface_member(self, node)
+    }
+
+    fn visit_dec_lit(&mut self, node: &'a DecLit<'a>) {
+        visit_dec_lit(self, node)
+    }
+
+    fn visit_default(&mut self, node: &'a Default<'a>) {
+        visit_default(self, node)
+    }
+
+    fn visit_default_value(&mut self, node: &'a DefaultValue<'a>) {
+        visit_default_value(self, node)
+    }
+
+    fn visit_definition(&mut self, node: &'a Definition<'a>) {
+        visit_definition(self, node)
+    }
+
+    fn visit_dictionary_definition(&mut self, node: &'a DictionaryDefinition<'a>) {
+        visit_dictionary_definition(self, node)
+    }
+
+    fn visit_dictionary_member(&mut self, node: &'a DictionaryMember<'a>) {
+        visit_dictionary_member(self, node)
+    }
+
+    fn visit_double_type(&mut self, node: &'a DoubleType) {
+        visit_double_type(self, node)
+    }
+
+    fn visit_double_typed_async_iterable(&mut self, node: &'a DoubleTypedAsyncIterable<'a>) {
+        visit_double_typed_async_iterable(self, node)
+    }
+
+    fn visit_double_typed_iterable(&mut self, node: &'a DoubleTypedIterable<'a>) {
+        visit_double_typed_iterable(self, node)
+    }
+
+    fn visit_empty_array_lit(&mut self, node: &'a EmptyArrayLit) {
+        visit_empty_array_lit(self, node)
+    }
+
+    fn visit_empty_dictionary_lit(&mut self, node: &'a EmptyDictionaryLit) {
+        visit_empty_dictionary_lit(self, node)
+    }
+
+    fn visit_enum_definition(&mut self, node: &'a EnumDefinition<'a>) {
+        visit_enum_definition(self, node)
+    }
+
+    fn visit_enum_value_list(&mut self, node: &'a EnumValueList<'a>) {
+        visit_enum_value_list(self, node)
+    }
+
+    fn visit_extended_attribute(&mut self, node: &'a ExtendedAttribute<'a>) {
+        visit_extended_attribute(self, node)
+    }
+
+    fn visit_extended_attribute_arg_list(&mut self, node: &'a ExtendedAttributeArgList<'a>) {
+        visit_extended_attribute_arg_list(self, node)
+    }
+
+    fn visit_extended_attribute_ident(&mut self, node: &'a ExtendedAttributeIdent<'a>) {
+        visit_extended_attribute_ident(self, node)
+    }
+
+    fn visit_extended_attribute_ident_list(&mut self, node: &'a ExtendedAttributeIdentList<'a>) {
+        visit_extended_attribute_ident_list(self, node)
+    }
+
+    fn visit_extended_attribute_list(&mut self, node: &'a ExtendedAttributeList<'a>) {
+        visit_extended_attribute_list(self, node)
+    }
+
+    fn visit_extended_attribute_named_arg_list(
+        &mut self,
+        node: &'a ExtendedAttributeNamedArgList<'a>,
+    ) {
+        visit_extended_attribute_named_arg_list(self, node)
+    }
+
+    fn visit_extended_attribute_no_args(&mut self, node: &'a ExtendedAttributeNoArgs<'a>) {
+        visit_extended_attribute_no_args(self, node)
+    }
+
+    fn visit_extended_attribute_wild_card(&mut self, node: &'a ExtendedAttributeWildCard<'a>) {
+        visit_extended_attribute_wild_card(self, node)
+    }
+
+    fn visit_float_lit(&mut self, node: &'a FloatLit<'a>) {
+        visit_float_lit(self, node)
+    }
+
+    fn visit_float_type(&mut self, node: &'a FloatType) {
+        visit_float_type(self, node)
+    }
+
+    fn visit_float_value_lit(&mut self, node: &'a FloatValueLit<'a>) {
+        visit_float_value_lit(self, node)
+    }
+
+    fn visit_floating_point_type(&mut self, node: &'a FloatingPointType) {
+        visit_floating_point_type(self, node)
+    }
+
+    fn visit_frozen_array_type(&mut self, node: &'a FrozenArrayType<'a>) {
+        visit_frozen_array_type(self, node)
+    }
+
+    fn visit_hex_lit(&mut self, node: &'a HexLit<'a>) {
+        visit_hex_lit(self, node)
+    }
+
+    fn visit_identifier(&mut self, node: &'a Identifier<'a>) {
+        visit_identifier(self, node)
+    }
+
+    fn visit_identifier_list(&mut self, node: &'a IdentifierList<'a>) {
+        visit_identifier_list(self, node)
+    }
+
+    fn visit_identifier_or_string(&mut self, node: &'a IdentifierOrString<'a>) {
+        visit_identifier_or_string(self, node)
+    }
+
+    fn visit_implements_definition(&mut self, node: &'a ImplementsDefinition<'a>) {
+        visit_implements_definition(self, node)
+    }
+
+    fn visit_includes_statement_definition(&mut self, node: &'a IncludesStatementDefinition<'a>) {
+        visit_includes_statement_definition(self, node)
+    }
+
+    fn visit_inheritance(&mut self, node: &'a Inheritance<'a>) {
+        visit_inheritance(self, node)
+    }
+
+    fn visit_integer_lit(&mut self, node: &'a IntegerLit<'a>) {
+        visit_integer_lit(self, node)
+    }
+
+    fn visit_integer_type(&mut self, node: &'a IntegerType) {
+        visit_integer_type(self, node)
+    }
+
+    fn visit_interface_definition(&mut self, node: &'a InterfaceDefinition<'a>) {
+        visit_interface_definition(self, node)
+    }
+
+    fn visit_interface_member(&mut self, node: &'a InterfaceMember<'a>) {
+        visit_interface_member(self, node)
+    }
+
+    fn visit_interface_mixin_definition(&mut self, node: &'a InterfaceMixinDefinition<'a>) {
+        visit_interface_mixin_definition(self, node)
+    }
+
+    fn visit_iterable_interface_member(&mut self, node: &'a IterableInterfaceMember<'a>) {
+        visit_iterable_interface_member(self, node)
+    }
+
+    fn visit_long_long_type(&mut self, node: &'a LongLongType) {
+        visit_long_long_type(self, node)
+    }
+
+    fn visit_long_type(&mut self, node: &'a LongType) {
+        visit_long_type(self, node)
+    }
+
+    fn visit_maplike_interface_member(&mut self, node: &'a MaplikeInterfaceMember<'a>) {
+        visit_maplike_interface_member(self, node)
+    }
+
+    fn visit_mixin_member(&mut self, node: &'a MixinMember<'a>) {
+        visit_mixin_member(self, node)
+    }
+
+    fn visit_namespace_definition(&mut self, node: &'a NamespaceDefinition<'a>) {
+        visit_namespace_definition(self, node)
+    }
+
+    fn visit_namespace_member(&mut self, node: &'a NamespaceMember<'a>) {
+        visit_namespace_member(self, node)
+    }
+
+    fn visit_non_any_type(&mut self, node: &'a NonAnyType<'a>) {
+        visit_non_any_type(self, node)
+    }
+
+    fn visit_observable_array_type(&mut self, node: &'a ObservableArrayType<'a>) {
+        visit_observable_array_type(self, node)
+    }
+
+    fn visit_oct_lit(&mut self, node: &'a OctLit<'a>) {
+        visit_oct_lit(self, node)
+    }
+
+    fn visit_operation_interface_member(&mut self, node: &'a OperationInterfaceMember<'a>) {
+        visit_operation_interface_member(self, node)
+    }
+
+    fn visit_operation_mixin_member(&mut self, node: &'a OperationMixinMember<'a>) {
+        visit_operation_mixin_member(self, node)
+    }
+
+    fn visit_operation_namespace_member(&mut self, node: &'a OperationNamespaceMember<'a>) {
+        visit_operation_namespace_member(self, node)
+    }
+
+    fn visit_partial_dictionary_definition(&mut self, node: &'a PartialDictionaryDefinition<'a>) {
+        visit_partial_dictionary_definition(self, node)
+    }
+
+    fn visit_partial_interface_definition(&mut self, node: &'a PartialInterfaceDefinition<'a>) {
+        visit_partial_interface_definition(self, node)
+    }
+
+    fn visit_partial_interface_mixin_definition(
+        &mut self,
+        node: &'a PartialInterfaceMixinDefinition<'a>,
+    ) {
+        visit_partial_interface_mixin_definition(self, node)
+    }
+
+    fn visit_partial_namespace_definition(&mut self, node: &'a PartialNamespaceDefinition<'a>) {
+        visit_partial_namespace_definition(self, node)
+    }
+
+    fn visit_promise_type(&mut self, node: &'a PromiseType<'a>) {
+        visit_promise_type(self, node)
+    }
+
+    fn visit_record_key_type(&mut self, node: &'a RecordKeyType<'a>) {
+        visit_record_key_type(self, node)
+    }
+
+    fn visit_record_type(&mut self, node: &'a RecordType<'a>) {
+        visit_record_type(self, node)
+    }
+
+    fn visit_return_type(&mut self, node: &'a ReturnType<'a>) {
+        visit_return_type(self, node)
+    }
+
+    fn visit_separate_async_iterable(&mut self, node: &'a SeparateAsyncIterable) {
+        visit_separate_async_iterable(self, node)
+    }
+
+    fn visit_sequence_type(&mut self, node: &'a SequenceType<'a>) {
+        visit_sequence_type(self, node)
+    }
+
+    fn visit_setlike_interface_member(&mut self, node: &'a SetlikeInterfaceMember<'a>) {
+        visit_setlike_interface_member(self, node)
+    }
+
+    fn visit_short_type(&mut self, node: &'a ShortType) {
+        visit_short_type(self, node)
+    }
+
+    fn visit_single_argument(&mut self, node: &'a SingleArgument<'a>) {
+        visit_single_argument(self, node)
+    }
+
+    fn visit_single_type(&mut self, node: &'a SingleType<'a>) {
+        visit_single_type(self, node)
+    }
+
+    fn visit_single_typed_async_iterable(&mut self, node: &'a SingleTypedAsyncIterable<'a>) {
+        visit_single_typed_async_iterable(self, node)
+    }
+
+    fn visit_single_typed_iterable(&mut self, node: &'a SingleTypedIterable<'a>) {
+        visit_single_typed_iterable(self, node)
+    }
+
+    fn visit_special(&mut self, node: &'a Special) {
+        visit_special(self, node)
+    }
+
+    fn visit_string_lit(&mut self, node: &'a StringLit<'a>) {
+        visit_string_lit(self, node)
+    }
+
+    fn visit_stringifier_member(&mut self, node: &'a StringifierMember<'a>) {
+        visit_stringifier_member(self, node)
+    }
+
+    fn visit_stringifier_or_inherit_or_static(&mut self, node: &'a StringifierOrInheritOrStatic) {
+        visit_stringifier_or_inherit_or_static(self, node)
+    }
+
+    fn visit_stringifier_or_static(&mut self, node: &'a StringifierOrStatic) {
+        visit_stringifier_or_static(self, node)
+    }
+
+    fn visit_type(&mut self, node: &'a Type<'a>) {
+        visit_type(self, node)
+    }
+
+    fn visit_typedef_definition(&mut self, node: &'a TypedefDefinition<'a>) {
+        visit_typedef_definition(self, node)
+    }
+
+    fn visit_union_member_type(&mut self, node: &'a UnionMemberType<'a>) {
+        visit_union_member_type(self, node)
+    }
+
+    fn visit_union_type(&mut self, node: &'a UnionType<'a>) {
+        visit_union_type(self, node)
+    }
+
+    fn visit_variadic_argument(&mut self, node: &'a VariadicArgument<'a>) {
+        visit_variadic_argument(self, node)
+    }
+}
+
+pub fn visit_argument<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a Argument<'a>) {
+    match node {
+        Argument::Single(it) => v.visit_single_argument(it),
+        Argument::Variadic(it) => v.visit_variadic_argument(it),
+    }
+}
+
+pub fn visit_argument_list<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a ArgumentList<'a>) {
+    for it in &node.list {
+        v.visit_argument(it);
+    }
+}
+
+pub fn visit_argument_name<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a ArgumentName<'a>) {
+    match node {
+        ArgumentName::Keyword(it) => v.visit_argument_name_keyword(it),
+        ArgumentName::Identifier(it) => v.visit_identifier(it),
+    }
+}
+
+pub fn visit_argument_name_keyword<'a, V: Visit<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &'a ArgumentNameKeyword,
+) {
+}
+
+pub fn visit_async_iterable_interface_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a AsyncIterableInterfaceMember<'a>,
+) {
+    match node {
+        AsyncIterableInterfaceMember::Single(it) => v.visit_single_typed_async_iterable(it),
+        AsyncIterableInterfaceMember::Double(it) => v.visit_double_typed_async_iterable(it),
+    }
+}
+
+pub fn visit_async_iterable_keyword<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a AsyncIterableKeyword,
+) {
+    match node {
+        AsyncIterableKeyword::Separate(it) => v.visit_separate_async_iterable(it),
+        AsyncIterableKeyword::Joined(_) => {}
+    }
+}
+
+pub fn visit_attribute_interface_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a AttributeInterfaceMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    if let Some(it) = &node.modifier {
+        v.visit_stringifier_or_inherit_or_static(it);
+    }
+    v.visit_attributed_type(&node.type_);
+    v.visit_identifier(&node.identifier);
+}
+
+pub fn visit_attribute_mixin_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a AttributeMixinMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_attributed_type(&node.type_);
+    v.visit_identifier(&node.identifier);
+}
+
+pub fn visit_attribute_namespace_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a AttributeNamespaceMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_attributed_type(&node.type_);
+    v.visit_identifier(&node.identifier);
+}
+
+pub fn visit_attributed_non_any_type<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a AttributedNonAnyType<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_non_any_type(&node.type_);
+}
+
+pub fn visit_attributed_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a AttributedType<'a>) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_type(&node.type_);
+}
+
+pub fn visit_boolean_lit<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a BooleanLit) {}
+
+pub fn visit_callback_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a CallbackDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    v.visit_return_type(&node.return_type);
+    v.visit_argument_list(&node.arguments.body);
+}
+
+pub fn visit_callback_interface_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a CallbackInterfaceDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    if let Some(it) = &node.inheritance {
+        v.visit_inheritance(it);
+    }
+    for it in &node.members.body {
+        v.visit_interface_member(it);
+    }
+}
+
+pub fn visit_const_member<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a ConstMember<'a>) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_const_type(&node.const_type);
+    v.visit_identifier(&node.identifier);
+    v.visit_const_value(&node.const_value);
+}
+
+pub fn visit_const_namespace_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ConstNamespaceMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_const_type(&node.const_type);
+    v.visit_identifier(&node.identifier);
+    v.visit_const_value(&node.const_value);
+}
+
+pub fn visit_const_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a ConstType<'a>) {
+    match node {
+        ConstType::Integer(it) => v.visit_integer_type(&it.type_),
+        ConstType::FloatingPoint(it) => v.visit_floating_point_type(&it.type_),
+        ConstType::Boolean(_) => {}
+        ConstType::Byte(_) => {}
+        ConstType::Octet(_) => {}
+        ConstType::BigInt(_) => {}
+        ConstType::Identifier(it) => v.visit_identifier(&it.type_),
+    }
+}
+
+pub fn visit_const_value<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a ConstValue<'a>) {
+    match node {
+        ConstValue::Boolean(it) => v.visit_boolean_lit(it),
+        ConstValue::Float(it) => v.visit_float_lit(it),
+        ConstValue::Integer(it) => v.visit_integer_lit(it),
+        ConstValue::Null(_) => {}
+    }
+}
+
+pub fn visit_constructor_interface_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ConstructorInterfaceMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_argument_list(&node.args.body);
+}
+
+pub fn visit_dec_lit<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a DecLit<'a>) {}
+
+pub fn visit_default<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a Default<'a>) {
+    v.visit_default_value(&node.value);
+}
+
+pub fn visit_default_value<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a DefaultValue<'a>) {
+    match node {
+        DefaultValue::Boolean(it) => v.visit_boolean_lit(it),
+        DefaultValue::EmptyArray(it) => v.visit_empty_array_lit(it),
+        DefaultValue::EmptyDictionary(it) => v.visit_empty_dictionary_lit(it),
+        DefaultValue::Float(it) => v.visit_float_lit(it),
+        DefaultValue::Integer(it) => v.visit_integer_lit(it),
+        DefaultValue::Null(_) => {}
+        DefaultValue::String(it) => v.visit_string_lit(it),
+    }
+}
+
+pub fn visit_definition<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a Definition<'a>) {
+    match node {
+        Definition::Callback(it) => v.visit_callback_definition(it),
+        Definition::CallbackInterface(it) => v.visit_callback_interface_definition(it),
+        Definition::Interface(it) => v.visit_interface_definition(it),
+        Definition::InterfaceMixin(it) => v.visit_interface_mixin_definition(it),
+        Definition::Namespace(it) => v.visit_namespace_definition(it),
+        Definition::Dictionary(it) => v.visit_dictionary_definition(it),
+        Definition::PartialInterface(it) => v.visit_partial_interface_definition(it),
+        Definition::PartialInterfaceMixin(it) => v.visit_partial_interface_mixin_definition(it),
+        Definition::PartialDictionary(it) => v.visit_partial_dictionary_definition(it),
+        Definition::PartialNamespace(it) => v.visit_partial_namespace_definition(it),
+        Definition::Enum(it) => v.visit_enum_definition(it),
+        Definition::Typedef(it) => v.visit_typedef_definition(it),
+        Definition::IncludesStatement(it) => v.visit_includes_statement_definition(it),
+        Definition::Implements(it) => v.visit_implements_definition(it),
+    }
+}
+
+pub fn visit_dictionary_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a DictionaryDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    if let Some(it) = &node.inheritance {
+        v.visit_inheritance(it);
+    }
+    for it in &node.members.body {
+        v.visit_dictionary_member(it);
+    }
+}
+
+pub fn visit_dictionary_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a DictionaryMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_type(&node.type_);
+    v.visit_identifier(&node.identifier);
+    if let Some(it) = &node.default {
+        v.visit_default(it);
+    }
+}
+
+pub fn visit_double_type<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a DoubleType) {}
+
+pub fn visit_double_typed_async_iterable<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a DoubleTypedAsyncIterable<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_async_iterable_keyword(&node.async_iterable);
+    v.visit_attributed_type(&node.generics.body.0);
+    v.visit_attributed_type(&node.generics.body.2);
+    if let Some(it) = &node.args {
+        v.visit_argument_list(&it.body);
+    }
+}
+
+pub fn visit_double_typed_iterable<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a DoubleTypedIterable<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_attributed_type(&node.generics.body.0);
+    v.visit_attributed_type(&node.generics.body.2);
+}
+
+pub fn visit_empty_array_lit<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a EmptyArrayLit) {}
+
+pub fn visit_empty_dictionary_lit<'a, V: Visit<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &'a EmptyDictionaryLit,
+) {
+}
+
+pub fn visit_enum_definition<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a EnumDefinition<'a>) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    v.visit_enum_value_list(&node.values.body);
+}
+
+pub fn visit_enum_value_list<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a EnumValueList<'a>) {
+    for it in &node.list {
+        v.visit_string_lit(it);
+    }
+}
+
+pub fn visit_extended_attribute<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ExtendedAttribute<'a>,
+) {
+    match node {
+        ExtendedAttribute::ArgList(it) => v.visit_extended_attribute_arg_list(it),
+        ExtendedAttribute::NamedArgList(it) => v.visit_extended_attribute_named_arg_list(it),
+        ExtendedAttribute::IdentList(it) => v.visit_extended_attribute_ident_list(it),
+        ExtendedAttribute::Ident(it) => v.visit_extended_attribute_ident(it),
+        ExtendedAttribute::Wildcard(it) => v.visit_extended_attribute_wild_card(it),
+        ExtendedAttribute::NoArgs(it) => v.visit_extended_attribute_no_args(it),
+    }
+}
+
+pub fn visit_extended_attribute_arg_list<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ExtendedAttributeArgList<'a>,
+) {
+    v.visit_identifier(&node.identifier);
+    v.visit_argument_list(&node.args.body);
+}
+
+pub fn visit_extended_attribute_ident<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ExtendedAttributeIdent<'a>,
+) {
+    v.visit_identifier(&node.lhs_identifier);
+    v.visit_identifier_or_string(&node.rhs);
+}
+
+pub fn visit_extended_attribute_ident_list<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ExtendedAttributeIdentList<'a>,
+) {
+    v.visit_identifier(&node.identifier);
+    v.visit_identifier_list(&node.list.body);
+}
+
+pub fn visit_extended_attribute_list<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ExtendedAttributeList<'a>,
+) {
+    for it in &node.body.list {
+        v.visit_extended_attribute(it);
+    }
+}
+
+pub fn visit_extended_attribute_named_arg_list<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ExtendedAttributeNamedArgList<'a>,
+) {
+    v.visit_identifier(&node.lhs_identifier);
+    v.visit_identifier(&node.rhs_identifier);
+    v.visit_argument_list(&node.args.body);
+}
+
+pub fn visit_extended_attribute_no_args<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ExtendedAttributeNoArgs<'a>,
+) {
+    v.visit_identifier(&node.0);
+}
+
+pub fn visit_extended_attribute_wild_card<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ExtendedAttributeWildCard<'a>,
+) {
+    v.visit_identifier(&node.lhs_identifier);
+}
+
+pub fn visit_float_lit<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a FloatLit<'a>) {
+    match node {
+        FloatLit::Value(it) => v.visit_float_value_lit(it),
+        FloatLit::NegInfinity(_) => {}
+        FloatLit::Infinity(_) => {}
+        FloatLit::NaN(_) => {}
+    }
+}
+
+pub fn visit_float_type<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a FloatType) {}
+
+pub fn visit_float_value_lit<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a FloatValueLit<'a>) {}
+
+pub fn visit_floating_point_type<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a FloatingPointType,
+) {
+    match node {
+        FloatingPointType::Float(it) => v.visit_float_type(it),
+        FloatingPointType::Double(it) => v.visit_double_type(it),
+    }
+}
+
+pub fn visit_frozen_array_type<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a FrozenArrayType<'a>,
+) {
+    v.visit_type(&node.generics.body);
+}
+
+pub fn visit_hex_lit<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a HexLit<'a>) {}
+
+pub fn visit_identifier<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a Identifier<'a>) {}
+
+pub fn visit_identifier_list<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a IdentifierList<'a>) {
+    for it in &node.list {
+        v.visit_identifier(it);
+    }
+}
+
+pub fn visit_identifier_or_string<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a IdentifierOrString<'a>,
+) {
+    match node {
+        IdentifierOrString::Identifier(it) => v.visit_identifier(it),
+        IdentifierOrString::String(it) => v.visit_string_lit(it),
+    }
+}
+
+pub fn visit_implements_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ImplementsDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.lhs_identifier);
+    v.visit_identifier(&node.rhs_identifier);
+}
+
+pub fn visit_includes_statement_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a IncludesStatementDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.lhs_identifier);
+    v.visit_identifier(&node.rhs_identifier);
+}
+
+pub fn visit_inheritance<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a Inheritance<'a>) {
+    v.visit_identifier(&node.identifier);
+}
+
+pub fn visit_integer_lit<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a IntegerLit<'a>) {
+    match node {
+        IntegerLit::Dec(it) => v.visit_dec_lit(it),
+        IntegerLit::Hex(it) => v.visit_hex_lit(it),
+        IntegerLit::Oct(it) => v.visit_oct_lit(it),
+    }
+}
+
+pub fn visit_integer_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a IntegerType) {
+    match node {
+        IntegerType::LongLong(it) => v.visit_long_long_type(it),
+        IntegerType::Long(it) => v.visit_long_type(it),
+        IntegerType::Short(it) => v.visit_short_type(it),
+    }
+}
+
+pub fn visit_interface_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a InterfaceDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    if let Some(it) = &node.inheritance {
+        v.visit_inheritance(it);
+    }
+    for it in &node.members.body {
+        v.visit_interface_member(it);
+    }
+}
+
+pub fn visit_interface_member<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a InterfaceMember<'a>) {
+    match node {
+        InterfaceMember::Const(it) => v.visit_const_member(it),
+        InterfaceMember::Attribute(it) => v.visit_attribute_interface_member(it),
+        InterfaceMember::Constructor(it) => v.visit_constructor_interface_member(it),
+        InterfaceMember::Operation(it) => v.visit_operation_interface_member(it),
+        InterfaceMember::Iterable(it) => v.visit_iterable_interface_member(it),
+        InterfaceMember::AsyncIterable(it) => v.visit_async_iterable_interface_member(it),
+        InterfaceMember::Maplike(it) => v.visit_maplike_interface_member(it),
+        InterfaceMember::Setlike(it) => v.visit_setlike_interface_member(it),
+        InterfaceMember::Stringifier(it) => v.visit_stringifier_member(it),
+    }
+}
+
+pub fn visit_interface_mixin_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a InterfaceMixinDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    for it in &node.members.body {
+        v.visit_mixin_member(it);
+    }
+}
+
+pub fn visit_iterable_interface_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a IterableInterfaceMember<'a>,
+) {
+    match node {
+        IterableInterfaceMember::Single(it) => v.visit_single_typed_iterable(it),
+        IterableInterfaceMember::Double(it) => v.visit_double_typed_iterable(it),
+    }
+}
+
+pub fn visit_long_long_type<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a LongLongType) {}
+
+pub fn visit_long_type<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a LongType) {}
+
+pub fn visit_maplike_interface_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a MaplikeInterfaceMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_attributed_type(&node.generics.body.0);
+    v.visit_attributed_type(&node.generics.body.2);
+}
+
+pub fn visit_mixin_member<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a MixinMember<'a>) {
+    match node {
+        MixinMember::Const(it) => v.visit_const_member(it),
+        MixinMember::Operation(it) => v.visit_operation_mixin_member(it),
+        MixinMember::Attribute(it) => v.visit_attribute_mixin_member(it),
+        MixinMember::Stringifier(it) => v.visit_stringifier_member(it),
+    }
+}
+
+pub fn visit_namespace_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a NamespaceDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    for it in &node.members.body {
+        v.visit_namespace_member(it);
+    }
+}
+
+pub fn visit_namespace_member<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a NamespaceMember<'a>) {
+    match node {
+        NamespaceMember::Const(it) => v.visit_const_namespace_member(it),
+        NamespaceMember::Operation(it) => v.visit_operation_namespace_member(it),
+        NamespaceMember::Attribute(it) => v.visit_attribute_namespace_member(it),
+    }
+}
+
+pub fn visit_non_any_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a NonAnyType<'a>) {
+    match node {
+        NonAnyType::Promise(it) => v.visit_promise_type(it),
+        NonAnyType::Integer(it) => v.visit_integer_type(&it.type_),
+        NonAnyType::FloatingPoint(it) => v.visit_floating_point_type(&it.type_),
+        NonAnyType::Boolean(_) => {}
+        NonAnyType::Byte(_) => {}
+        NonAnyType::Octet(_) => {}
+        NonAnyType::BigInt(_) => {}
+        NonAnyType::ByteString(_) => {}
+        NonAnyType::DOMString(_) => {}
+        NonAnyType::USVString(_) => {}
+        NonAnyType::Sequence(it) => v.visit_sequence_type(&it.type_),
+        NonAnyType::Object(_) => {}
+        NonAnyType::Symbol(_) => {}
+        NonAnyType::Error(_) => {}
+        NonAnyType::ArrayBuffer(_) => {}
+        NonAnyType::SharedArrayBuffer(_) => {}
+        NonAnyType::DataView(_) => {}
+        NonAnyType::Int8Array(_) => {}
+        NonAnyType::Int16Array(_) => {}
+        NonAnyType::Int32Array(_) => {}
+        NonAnyType::Uint8Array(_) => {}
+        NonAnyType::Uint16Array(_) => {}
+        NonAnyType::Uint32Array(_) => {}
+        NonAnyType::Uint8ClampedArray(_) => {}
+        NonAnyType::BigInt64Array(_) => {}
+        NonAnyType::BigUint64Array(_) => {}
+        NonAnyType::Float16Array(_) => {}
+        NonAnyType::Float32Array(_) => {}
+        NonAnyType::Float64Array(_) => {}
+        NonAnyType::ArrayBufferView(_) => {}
+        NonAnyType::BufferSource(_) => {}
+        NonAnyType::FrozenArrayType(it) => v.visit_frozen_array_type(&it.type_),
+        NonAnyType::ObservableArrayType(it) => v.visit_observable_array_type(&it.type_),
+        NonAnyType::RecordType(it) => v.visit_record_type(&it.type_),
+        NonAnyType::Identifier(it) => v.visit_identifier(&it.type_),
+    }
+}
+
+pub fn visit_observable_array_type<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a ObservableArrayType<'a>,
+) {
+    v.visit_type(&node.generics.body);
+}
+
+pub fn visit_oct_lit<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a OctLit<'a>) {}
+
+pub fn visit_operation_interface_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a OperationInterfaceMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    if let Some(it) = &node.modifier {
+        v.visit_stringifier_or_static(it);
+    }
+    if let Some(it) = &node.special {
+        v.visit_special(it);
+    }
+    v.visit_return_type(&node.return_type);
+    if let Some(it) = &node.identifier {
+        v.visit_identifier(it);
+    }
+    v.visit_argument_list(&node.args.body);
+}
+
+pub fn visit_operation_mixin_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a OperationMixinMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_return_type(&node.return_type);
+    if let Some(it) = &node.identifier {
+        v.visit_identifier(it);
+    }
+    v.visit_argument_list(&node.args.body);
+}
+
+pub fn visit_operation_namespace_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a OperationNamespaceMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_return_type(&node.return_type);
+    if let Some(it) = &node.identifier {
+        v.visit_identifier(it);
+    }
+    v.visit_argument_list(&node.args.body);
+}
+
+pub fn visit_partial_dictionary_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a PartialDictionaryDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    for it in &node.members.body {
+        v.visit_dictionary_member(it);
+    }
+}
+
+pub fn visit_partial_interface_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a PartialInterfaceDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    for it in &node.members.body {
+        v.visit_interface_member(it);
+    }
+}
+
+pub fn visit_partial_interface_mixin_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a PartialInterfaceMixinDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    for it in &node.members.body {
+        v.visit_mixin_member(it);
+    }
+}
+
+pub fn visit_partial_namespace_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a PartialNamespaceDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_identifier(&node.identifier);
+    for it in &node.members.body {
+        v.visit_namespace_member(it);
+    }
+}
+
+pub fn visit_promise_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a PromiseType<'a>) {
+    v.visit_return_type(&node.generics.body);
+}
+
+pub fn visit_record_key_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a RecordKeyType<'a>) {
+    match node {
+        RecordKeyType::Byte(_) => {}
+        RecordKeyType::DOM(_) => {}
+        RecordKeyType::USV(_) => {}
+        RecordKeyType::NonAny(it) => v.visit_non_any_type(it),
+    }
+}
+
+pub fn visit_record_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a RecordType<'a>) {
+    v.visit_record_key_type(&node.generics.body.0);
+    v.visit_type(&node.generics.body.2);
+}
+
+pub fn visit_return_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a ReturnType<'a>) {
+    match node {
+        ReturnType::Undefined(_) => {}
+        ReturnType::Type(it) => v.visit_type(it),
+    }
+}
+
+pub fn visit_separate_async_iterable<'a, V: Visit<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &'a SeparateAsyncIterable,
+) {
+}
+
+pub fn visit_sequence_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a SequenceType<'a>) {
+    v.visit_type(&node.generics.body);
+}
+
+pub fn visit_setlike_interface_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a SetlikeInterfaceMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_attributed_type(&node.generics.body);
+}
+
+pub fn visit_short_type<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a ShortType) {}
+
+pub fn visit_single_argument<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a SingleArgument<'a>) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_attributed_type(&node.type_);
+    v.visit_argument_name(&node.identifier);
+    if let Some(it) = &node.default {
+        v.visit_default(it);
+    }
+}
+
+pub fn visit_single_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a SingleType<'a>) {
+    match node {
+        SingleType::Any(_) => {}
+        SingleType::NonAny(it) => v.visit_non_any_type(it),
+    }
+}
+
+pub fn visit_single_typed_async_iterable<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a SingleTypedAsyncIterable<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_async_iterable_keyword(&node.async_iterable);
+    v.visit_attributed_type(&node.generics.body);
+    if let Some(it) = &node.args {
+        v.visit_argument_list(&it.body);
+    }
+}
+
+pub fn visit_single_typed_iterable<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a SingleTypedIterable<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_attributed_type(&node.generics.body);
+}
+
+pub fn visit_special<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a Special) {}
+
+pub fn visit_string_lit<'a, V: Visit<'a> + ?Sized>(_v: &mut V, _node: &'a StringLit<'a>) {}
+
+pub fn visit_stringifier_member<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a StringifierMember<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+}
+
+pub fn visit_stringifier_or_inherit_or_static<'a, V: Visit<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &'a StringifierOrInheritOrStatic,
+) {
+}
+
+pub fn visit_stringifier_or_static<'a, V: Visit<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &'a StringifierOrStatic,
+) {
+}
+
+pub fn visit_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a Type<'a>) {
+    match node {
+        Type::Single(it) => v.visit_single_type(it),
+        Type::Union(it) => v.visit_union_type(&it.type_),
+    }
+}
+
+pub fn visit_typedef_definition<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a TypedefDefinition<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_attributed_type(&node.type_);
+    v.visit_identifier(&node.identifier);
+}
+
+pub fn visit_union_member_type<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a UnionMemberType<'a>,
+) {
+    match node {
+        UnionMemberType::Single(it) => v.visit_attributed_non_any_type(it),
+        UnionMemberType::Union(it) => v.visit_union_type(&it.type_),
+    }
+}
+
+pub fn visit_union_type<'a, V: Visit<'a> + ?Sized>(v: &mut V, node: &'a UnionType<'a>) {
+    for it in &node.body.list {
+        v.visit_union_member_type(it);
+    }
+}
+
+pub fn visit_variadic_argument<'a, V: Visit<'a> + ?Sized>(
+    v: &mut V,
+    node: &'a VariadicArgument<'a>,
+) {
+    if let Some(it) = &node.attributes {
+        v.visit_extended_attribute_list(it);
+    }
+    v.visit_type(&node.type_);
+    v.visit_argument_name(&node.identifier);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Default)]
+    struct Identifiers<'a>(Vec<&'a str>);
+
+    impl<'a> Visit<'a> for Identifiers<'a> {
+        fn visit_identifier(&mut self, node: &'a Identifier<'a>) {
+            self.0.push(node.0);
+        }
+    }
+
+    #[test]
+    fn should_visit_in_order() {
+        let parsed = crate::parse(
+            "[Exposed=Window] interface Foo : Bar {
+                attribute (Baz or sequence<Qux>)? a;
+                undefined b(optional record<DOMString, Quux> c = {});
+            };",
+        )
+        .unwrap();
+        let mut identifiers = Identifiers::default();
+        identifiers.visit_definition(&parsed[0]);
+        assert_eq!(
+            identifiers.0,
+            ["Exposed", "Window", "Foo", "Bar", "Baz", "Qux", "a", "b", "Quux", "c"]
+        );
+    }
+
+    #[test]
+    fn should_skip_children_not_walked_into() {
+        struct SkipArguments<'a>(Identifiers<'a>);
+
+        impl<'a> Visit<'a> for SkipArguments<'a> {
+            fn visit_identifier(&mut self, node: &'a Identifier<'a>) {
+                self.0.visit_identifier(node);
+            }
+
+            fn visit_argument(&mut self, _: &'a Argument<'a>) {}
+        }
+
+        let parsed = crate::parse("callback Foo = undefined (long a, Bar b);").unwrap();
+        let mut visitor = SkipArguments(Identifiers::default());
+        visitor.visit_definition(&parsed[0]);
+        assert_eq!(visitor.0 .0, ["Foo"]);
+    }
+
+    #[test]
+    fn should_visit_type_aliases() {
+        #[derive(Default)]
+        struct Lengths(Vec<usize>);
+
+        impl<'a> Visit<'a> for Lengths {
+            fn visit_argument_list(&mut self, node: &'a ArgumentList<'a>) {
+                self.0.push(node.list.len());
+                visit_argument_list(self, node);
+            }
+
+            fn visit_union_type(&mut self, node: &'a UnionType<'a>) {
+                self.0.push(node.body.list.len());
+            }
+        }
+
+        let parsed = crate::parse(
+            "interface Foo { undefined f(long a, (A or B or C) b, optional long c); };",
+        )
+        .unwrap();
+        let mut lengths = Lengths::default();
+        lengths.visit_definition(&parsed[0]);
+        assert_eq!(lengths.0, [3, 3]);
+    }
+}
@@ -0,0 +1,1478 @@
+//! Walking the syntax tree by mutable reference
+//!
+//! Works like the [`visit`](../visit/index.html) module, with each method of
+//! [`VisitMut`](trait.VisitMut.html) free to change the node it visits in
+//! place.
+//!
+//! ### Example
+//!
+//! ```
+//! use weedle::attribute::{ExtendedAttribute, ExtendedAttributeNoArgs};
+//! use weedle::interface::InterfaceMember;
+//! use weedle::print::ToWebIdl;
+//! use weedle::visit_mut::{self, VisitMut};
+//! use weedle::InterfaceDefinition;
+//!
+//! // Removes the members marked with `[ChromeOnly]`
+//! struct StripChromeOnly;
+//!
+//! impl<'a> VisitMut<'a> for StripChromeOnly {
+//!     fn visit_interface_definition_mut(&mut self, node: &mut InterfaceDefinition<'a>) {
+//!         node.members.body.retain(|member| {
+//!             let attributes = match member {
+//!                 InterfaceMember::Attribute(attribute) => &attribute.attributes,
+//!                 InterfaceMember::Operation(operation) => &operation.attributes,
+//!                 _ => return true,
+//!             };
+//!             !attributes.iter().flat_map(|list| &list.body.list).any(|attribute| {
+//!                 matches!(
+//!                     attribute,
+//!                     ExtendedAttribute::NoArgs(ExtendedAttributeNoArgs(name))
+//!                         if name.0 == "ChromeOnly"
+//!                 )
+//!             })
+//!         });
+//!         visit_mut::visit_interface_definition_mut(self, node);
+//!     }
+//! }
+//!
+//! let mut parsed = weedle::parse("
+//!     interface Window {
+//!         [ChromeOnly] readonly attribute long secret;
+//!         readonly attribute long length;
+//!     };
+//! ").unwrap();
+//!
+//! StripChromeOnly.visit_definition_mut(&mut parsed[0]);
+//! assert_eq!(
+//!     parsed[0].to_webidl(),
+//!     "interface Window {\n    readonly attribute long length;\n};",
+//! );
+//! ```
+
+use crate::argument::{
+    Argument, ArgumentList, ArgumentName, ArgumentNameKeyword, SingleArgument, VariadicArgument,
+};
+use crate::attribute::{
+    ExtendedAttribute, ExtendedAttributeArgList, ExtendedAttributeIdent,
+    ExtendedAttributeIdentList, ExtendedAttributeList, ExtendedAttributeNamedArgList,
+    ExtendedAttributeNoArgs, ExtendedAttributeWildCard, IdentifierList, IdentifierOrString,
+};
+use crate::common::{Default, Identifier};
+use crate::dictionary::DictionaryMember;
+use crate::interface::{
+    AsyncIterableInterfaceMember, AsyncIterableKeyword, AttributeInterfaceMember, ConstMember,
+    ConstructorInterfaceMember, DoubleTypedAsyncIterable, DoubleTypedIterable, Inheritance,
+    InterfaceMember, IterableInterfaceMember, MaplikeInterfaceMember, OperationInterfaceMember,
+    SeparateAsyncIterable, SetlikeInterfaceMember, SingleTypedAsyncIterable, SingleTypedIterable,
+    Special, StringifierMember, StringifierOrInheritOrStatic, StringifierOrStatic,
+};
+use crate::literal::{
+    BooleanLit, ConstValue, DecLit, DefaultValue, EmptyArrayLit, EmptyDictionaryLit, FloatLit,
+    FloatValueLit, HexLit, IntegerLit, OctLit, StringLit,
+};
+use crate::mixin::{AttributeMixinMember, MixinMember, OperationMixinMember};
+use crate::namespace::{
+    AttributeNamespaceMember, ConstNamespaceMember, NamespaceMember, OperationNamespaceMember,
+};
+use crate::types::{
+    AttributedNonAnyType, AttributedType, ConstType, DoubleType, FloatType, FloatingPointType,
+    FrozenArrayType, IntegerType, LongLongType, LongType, NonAnyType, ObservableArrayType,
+    PromiseType, RecordKeyType, RecordType, ReturnType, SequenceType, ShortType, SingleType, Type,
+    UnionMemberType, UnionType,
+};
+use crate::{
+    CallbackDefinition, CallbackInterfaceDefinition, Definition, DictionaryDefinition,
+    EnumDefinition, EnumValueList, ImplementsDefinition, IncludesStatementDefinition,
+    InterfaceDefinition, InterfaceMixinDefinition, NamespaceDefinition,
+    PartialDictionaryDefinition, PartialInterfaceDefinition, PartialInterfaceMixinDefinition,
+    PartialNamespaceDefinition, TypedefDefinition,
+};
+
+/// Visits the nodes of a syntax tree borrowed mutably
+pub trait VisitMut<'a> {
+    fn visit_argument_mut(&mut self, node: &mut Argument<'a>) {
+        visit_argument_mut(self, node)
+    }
+
+    fn visit_argument_list_mut(&mut self, node: &mut ArgumentList<'a>) {
+        visit_argument_list_mut(self, node)
+    }
+
+    fn visit_argument_name_mut(&mut self, node: &mut ArgumentName<'a>) {
+        visit_argument_name_mut(self, node)
+    }
+
+    fn visit_argument_name_keyword_mut(&mut self, node: &mut ArgumentNameKeyword) {
+        visit_argument_name_keyword_mut(self, node)
+    }
+
+    fn visit_async_iterable_interface_member_mut(
+        &mut self,
+        node: &mut AsyncIterableInterfaceMember<'a>,
+    ) {
+        visit_async_iterable_interface_member_mut(self, node)
+    }
+
+    fn visit_async_iterable_keyword_mut(&mut self, node: &mut AsyncIterableKeyword) {
+        visit_async_iterable_keyword_mut(self, node)
+    }
+
+    fn visit_attribute_interface_member_mut(&mut self, node: &mut AttributeInterfaceMember<'a>) {
+        visit_attribute_interface_member_mut(self, node)
+    }
+
+    fn visit_attribute_mixin_member_mut(&mut self, node: &mut AttributeMixinMember<'a>) {
+        visit_attribute_mixin_member_mut(self, node)
+    }
+
+    fn visit_attribute_namespace_member_mut(&mut self, node: &mut AttributeNamespaceMember<'a>) {
+        visit_attribute_namespace_member_mut(self, node)
+    }
+
+    fn visit_attributed_non_any_type_mut(&mut self, node: &mut AttributedNonAnyType<'a>) {
+        visit_attributed_non_any_type_mut(self, node)
+    }
+
+    fn visit_attributed_type_mut(&mut self, node: &mut AttributedType<'a>) {
+        visit_attributed_type_mut(self, node)
+    }
+
+    fn visit_boolean_lit_mut(&mut self, node: &mut BooleanLit) {
+        visit_boolean_lit_mut(self, node)
+    }
+
+    fn visit_callback_definition_mut(&mut self, node: &mut CallbackDefinition<'a>) {
+        visit_callback_definition_mut(self, node)
+    }
+
+    fn visit_callback_interface_definition_mut(
+        &mut self,
+        node: &mut CallbackInterfaceDefinition<'a>,
+    ) {
+        visit_callback_interface_definition_mut(self, node)
+    }
+
+    fn visit_const_member_mut(&mut self, node: &mut ConstMember<'a>) {
+        visit_const_member_mut(self, node)
+    }
+
+    fn visit_const_namespace_member_mut(&mut self, node: &mut ConstNamespaceMember<'a>) {
+        visit_const_namespace_member_mut(self, node)
+    }
+
+    fn visit_const_type_mut(&mut self, node: &mut ConstType<'a>) {
+        visit_const_type_mut(self, node)
+    }
+
+    fn visit_const_value_mut(&mut self, node: &mut ConstValue<'a>) {
+        visit_const_value_mut(self, node)
+    }
+
+    fn visit_constructor_interface_member_mut(
+        &mut self,
+        node: &mut ConstructorInterfaceMember<'a>,
+    ) {
+        visit_constructor_interface_member_mut(self, node)
+    }
+
+    fn visit_dec_lit_mut(&mut self, node: &mut DecLit<'a>) {
+        visit_dec_lit_mut(self, node)
+    }
+
+    fn visit_default_mut(&mut self, node: &mut Default<'a>) {
+        visit_default_mut(self, node)
+    }
+
+    fn visit_default_value_mut(&mut self, node: &mut DefaultValue<'a>) {
+        visit_default_value_mut(self, node)
+    }
+
+    fn visit_definition_mut(&mut self, node: &mut Definition<'a>) {
+        visit_definition_mut(self, node)
+    }
+
+    fn visit_dictionary_definition_mut(&mut self, node: &mut DictionaryDefinition<'a>) {
+        visit_dictionary_definition_mut(self, node)
+    }
+
+    fn visit_dictionary_member_mut(&mut self, node: &mut DictionaryMember<'a>) {
+        visit_dictionary_member_mut(self, node)
+    }
+
+    fn visit_double_type_mut(&mut self, node: &mut DoubleType) {
+        visit_double_type_mut(self, node)
+    }
+
+    fn visit_double_typed_async_iterable_mut(&mut self, node: &mut DoubleTypedAsyncIterable<'a>) {
+        visit_double_typed_async_iterable_mut(self, node)
+    }
+
+    fn visit_double_typed_iterable_mut(&mut self, node: &mut DoubleTypedIterable<'a>) {
+        visit_double_typed_iterable_mut(self, node)
+    }
+
+    fn visit_empty_array_lit_mut(&mut self, node: &mut EmptyArrayLit) {
+        visit_empty_array_lit_mut(self, node)
+    }
+
+    fn visit_empty_dictionary_lit_mut(&mut self, node: &mut EmptyDictionaryLit) {
+        visit_empty_dictionary_lit_mut(self, node)
+    }
+
+    fn visit_enum_definition_mut(&mut self, node: &mut EnumDefinition<'a>) {
+        visit_enum_definition_mut(self, node)
+    }
+
+    fn visit_enum_value_list_mut(&mut self, node: &mut EnumValueList<'a>) {
+        visit_enum_value_list_mut(self, node)
+    }
+
+    fn visit_extended_attribute_mut(&mut self, node: &mut ExtendedAttribute<'a>) {
+        visit_extended_attribute_mut(self, node)
+    }
+
+    fn visit_extended_attribute_arg_list_mut(&mut self, node: &mut ExtendedAttributeArgList<'a>) {
+        visit_extended_attribute_arg_list_mut(self, node)
+    }
+
+    fn visit_extended_attribute_ident_mut(&mut self, node: &mut ExtendedAttributeIdent<'a>) {
+        visit_extended_attribute_ident_mut(self, node)
+    }
+
+    fn visit_extended_attribute_ident_list_mut(
+        &mut self,
+        node: &mut ExtendedAttributeIdentList<'a>,
+    ) {
+        visit_extended_attribute_ident_list_mut(self, node)
+    }
+
+    fn visit_extended_attribute_list_mut(&mut self, node: &mut ExtendedAttributeList<'a>) {
+        visit_extended_attribute_list_mut(self, node)
+    }
+
+    fn visit_extended_attribute_named_arg_list_mut(
+        &mut self,
+        node: &mut ExtendedAttributeNamedArgList<'a>,
+    ) {
+        visit_extended_attribute_named_arg_list_mut(self, node)
+    }
+
+    fn visit_extended_attribute_no_args_mut(&mut self, node: &mut ExtendedAttributeNoArgs<'a>) {
+        visit_extended_attribute_no_args_mut(self, node)
+    }
+
+    fn visit_extended_attribute_wild_card_mut(&mut self, node: &mut ExtendedAttributeWildCard<'a>) {
+        visit_extended_attribute_wild_card_mut(self, node)
+    }
+
+    fn visit_float_lit_mut(&mut self, node: &mut FloatLit<'a>) {
+        visit_float_lit_mut(self, node)
+    }
+
+    fn visit_float_type_mut(&mut self, node: &mut FloatType) {
+        visit_float_type_mut(self, node)
+    }
+
+    fn visit_float_value_lit_mut(&mut self, node: &mut FloatValueLit<'a>) {
+        visit_float_value_lit_mut(self, node)
+    }
+
+    fn visit_floating_point_type_mut(&mut self, node: &mut FloatingPointType) {
+        visit_floating_point_type_mut(self, node)
+    }
+
+    fn visit_frozen_array_type_mut(&mut self, node: &mut FrozenArrayType<'a>) {
+        visit_frozen_array_type_mut(self, node)
+    }
+
+    fn visit_hex_lit_mut(&mut self, node: &mut HexLit<'a>) {
+        visit_hex_lit_mut(self, node)
+    }
+
+    fn visit_identifier_mut(&mut self, node: &mut Identifier<'a>) {
+        visit_identifier_mut(self, node)
+    }
+
+    fn visit_identifier_list_mut(&mut self, node: &mut IdentifierList<'a>) {
+        visit_identifier_list_mut(self, node)
+    }
+
+    fn visit_identifier_or_string_mut(&mut self, node: &mut IdentifierOrString<'a>) {
+        visit_identifier_or_string_mut(self, node)
+    }
+
+    fn visit_implements_definition_mut(&mut self, node: &mut ImplementsDefinition<'a>) {
+        visit_implements_definition_mut(self, node)
+    }
+
+    fn visit_includes_statement_definition_mut(
+        &mut self,
+        node: &mut IncludesStatementDefinition<'a>,
+    ) {
+        visit_includes_statement_definition_mut(self, node)
+    }
+
+    fn visit_inheritance_mut(&mut self, node: &mut Inheritance<'a>) {
+        visit_inheritance_mut(self, node)
+    }
+
+    fn visit_integer_lit_mut(&mut self, node: &mut IntegerLit<'a>) {
+        visit_integer_lit_mut(self, node)
+    }
+
+    fn visit_integer_type_mut(&mut self, node: &mut IntegerType) {
+        visit_integer_type_mut(self, node)
+    }
+
+    fn visit_interface_definition_mut(&mut self, node: &mut InterfaceDefinition<'a>) {
+        visit_interface_definition_mut(self, node)
+    }
+
+    fn visit_interface_member_mut(&mut self, node: &mut InterfaceMember<'a>) {
+        visit_interface_member_mut(self, node)
+    }
+
+    fn visit_interface_mixin_definition_mut(&mut self, node: &mut InterfaceMixinDefinition<'a>) {
+        visit_interface_mixin_definition_mut(self, node)
+    }
+
+    fn visit_iterable_interface_member_mut(&mut self, node: &mut IterableInterfaceMember<'a>) {
+        visit_iterable_interface_member_mut(self, node)
+    }
+
+    fn visit_long_long_type_mut(&mut self, node: &mut LongLongType) {
+        visit_long_long_type_mut(self, node)
+    }
+
+    fn visit_long_type_mut(&mut self, node: &mut LongType) {
+        visit_long_type_mut(self, node)
+    }
+
+    fn visit_maplike_interface_member_mut(&mut self, node: &mut MaplikeInterfaceMember<'a>) {
+        visit_maplike_interface_member_mut(self, node)
+    }
+
+    fn visit_mixin_member_mut(&mut self, node: &mut MixinMember<'a>) {
+        visit_mixin_member_mut(self, node)
+    }
+
+    fn visit_namespace_definition_mut(&mut self, node: &mut NamespaceDefinition<'a>) {
+        visit_namespace_definition_mut(self, node)
+    }
+
+    fn visit_namespace_member_mut(&mut self, node: &mut NamespaceMember<'a>) {
+        visit_namespace_member_mut(self, node)
+    }
+
+    fn visit_non_any_type_mut(&mut self, node: &mut NonAnyType<'a>) {
+        visit_non_any_type_mut(self, node)
+    }
+
+    fn visit_observable_array_type_mut(&mut self, node: &mut ObservableArrayType<'a>) {
+        visit_observable_array_type_mut(self, node)
+    }
+
+    fn visit_oct_lit_mut(&mut self, node: &mut OctLit<'a>) {
+        visit_oct_lit_mut(self, node)
+    }
+
+    fn visit_operation_interface_member_mut(&mut self, node: &mut OperationInterfaceMember<'a>) {
+        visit_operation_interface_member_mut(self, node)
+    }
+
+    fn visit_operation_mixin_member_mut(&mut self, node: &mut OperationMixinMember<'a>) {
+        visit_operation_mixin_member_mut(self, node)
+    }
+
+    fn visit_operation_namespace_member_mut(&mut self, node: &mut OperationNamespaceMember<'a>) {
+        visit_operation_namespace_member_mut(self, node)
+    }
+
+    fn visit_partial_dictionary_definition_mut(
+        &mut self,
+        node: &mut PartialDictionaryDefinition<'a>,
+    ) {
+        visit_partial_dictionary_definition_mut(self, node)
+    }
+
+    fn visit_partial_interface_definition_mut(
+        &mut self,
+        node: &mut PartialInterfaceDefinition<'a>,
+    ) {
+        visit_partial_interface_definition_mut(self, node)
+    }
+
+    fn visit_partial_interface_mixin_definition_mut(
+        &mut self,
+        node: &mut PartialInterfaceMixinDefinition<'a>,
+    ) {
+        visit_partial_interface_mixin_definition_mut(self, node)
+    }
+
+    fn visit_partial_namespace_definition_mut(
+        &mut self,
+        node: &mut PartialNamespaceDefinition<'a>,
+    ) {
+        visit_partial_namespace_definition_mut(self, node)
+    }
+
+    fn visit_promise_type_mut(&mut self, node: &mut PromiseType<'a>) {
+        visit_promise_type_mut(self, node)
+    }
+
+    fn visit_record_key_type_mut(&mut self, node: &mut RecordKeyType<'a>) {
+        visit_record_key_type_mut(self, node)
+    }
+
+    fn visit_record_type_mut(&mut self, node: &mut RecordType<'a>) {
+        visit_record_type_mut(self, node)
+    }
+
+    fn visit_return_type_mut(&mut self, node: &mut ReturnType<'a>) {
+        visit_return_type_mut(self, node)
+    }
+
+    fn visit_separate_async_iterable_mut(&mut self, node: &mut SeparateAsyncIterable) {
+        visit_separate_async_iterable_mut(self, node)
+    }
+
+    fn visit_sequence_type_mut(&mut self, node: &mut SequenceType<'a>) {
+        visit_sequence_type_mut(self, node)
+    }
+
+    fn visit_setlike_interface_member_mut(&mut self, node: &mut SetlikeInterfaceMember<'a>) {
+        visit_setlike_interface_member_mut(self, node)
+    }
+
+    fn visit_short_type_mut(&mut self, node: &mut ShortType) {
+        visit_short_type_mut(self, node)
+    }
+
+    fn visit_single_argument_mut(&mut self, node: &mut SingleArgument<'a>) {
+        visit_single_argument_mut(self, node)
+    }
+
+    fn visit_single_type_mut(&mut self, node: &mut SingleType<'a>) {
+        visit_single_type_mut(self, node)
+    }
+
+    fn visit_single_typed_async_iterable_mut(&mut self, node: &mut SingleTypedAsyncIterable<'a>) {
+        visit_single_typed_async_iterable_mut(self, node)
+    }
+
+    fn visit_single_typed_iterable_mut(&mut self, node: &mut SingleTypedIterable<'a>) {
+        visit_single_typed_iterable_mut(self, node)
+    }
+
+    fn visit_special_mut(&mut self, node: &mut Special) {
+        visit_special_mut(self, node)
+    }
+
+    fn visit_string_lit_mut(&mut self, node: &mut StringLit<'a>) {
+        visit_string_lit_mut(self, node)
+    }
+
+    fn visit_stringifier_member_mut(&mut self, node: &mut StringifierMember<'a>) {
+        visit_stringifier_member_mut(self, node)
+    }
+
+    fn visit_stringifier_or_inherit_or_static_mut(
+        &mut self,
+        node: &mut StringifierOrInheritOrStatic,
+    ) {
+        visit_stringifier_or_inherit_or_static_mut(self, node)
+    }
+
+    fn visit_stringifier_or_static_mut(&mut self, node: &mut StringifierOrStatic) {
+        visit_stringifier_or_static_mut(self, node)
+    }
+
+    fn visit_type_mut(&mut self, node: &mut Type<'a>) {
+        visit_type_mut(self, node)
+    }
+
+    fn visit_typedef_definition_mut(&mut self, node: &mut TypedefDefinition<'a>) {
+        visit_typedef_definition_mut(self, node)
+    }
+
+    fn visit_union_member_type_mut(&mut self, node: &mut UnionMemberType<'a>) {
+        visit_union_member_type_mut(self, node)
+    }
+
+    fn visit_union_type_mut(&mut self, node: &mut UnionType<'a>) {
+        visit_union_type_mut(self, node)
+    }
+
+    fn visit_variadic_argument_mut(&mut self, node: &mut VariadicArgument<'a>) {
+        visit_variadic_argument_mut(self, node)
+    }
+}
+
+pub fn visit_argument_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Argument<'a>) {
+    match node {
+        Argument::Single(it) => v.visit_single_argument_mut(it),
+        Argument::Variadic(it) => v.visit_variadic_argument_mut(it),
+    }
+}
+
+pub fn visit_argument_list_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ArgumentList<'a>,
+) {
+    for it in &mut node.list {
+        v.visit_argument_mut(it);
+    }
+}
+
+pub fn visit_argument_name_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ArgumentName<'a>,
+) {
+    match node {
+        ArgumentName::Keyword(it) => v.visit_argument_name_keyword_mut(it),
+        ArgumentName::Identifier(it) => v.visit_identifier_mut(it),
+    }
+}
+
+pub fn visit_argument_name_keyword_mut<'a, V: VisitMut<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &mut ArgumentNameKeyword,
+) {
+}
+
+pub fn visit_async_iterable_interface_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut AsyncIterableInterfaceMember<'a>,
+) {
+    match node {
+        AsyncIterableInterfaceMember::Single(it) => v.visit_single_typed_async_iterable_mut(it),
+        AsyncIterableInterfaceMember::Double(it) => v.visit_double_typed_async_iterable_mut(it),
+    }
+}
+
+pub fn visit_async_iterable_keyword_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut AsyncIterableKeyword,
+) {
+    match node {
+        AsyncIterableKeyword::Separate(it) => v.visit_separate_async_iterable_mut(it),
+        AsyncIterableKeyword::Joined(_) => {}
+    }
+}
+
+pub fn visit_attribute_interface_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut AttributeInterfaceMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    if let Some(it) = &mut node.modifier {
+        v.visit_stringifier_or_inherit_or_static_mut(it);
+    }
+    v.visit_attributed_type_mut(&mut node.type_);
+    v.visit_identifier_mut(&mut node.identifier);
+}
+
+pub fn visit_attribute_mixin_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut AttributeMixinMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_attributed_type_mut(&mut node.type_);
+    v.visit_identifier_mut(&mut node.identifier);
+}
+
+pub fn visit_attribute_namespace_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut AttributeNamespaceMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_attributed_type_mut(&mut node.type_);
+    v.visit_identifier_mut(&mut node.identifier);
+}
+
+pub fn visit_attributed_non_any_type_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut AttributedNonAnyType<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_non_any_type_mut(&mut node.type_);
+}
+
+pub fn visit_attributed_type_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut AttributedType<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_type_mut(&mut node.type_);
+}
+
+pub fn visit_boolean_lit_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut BooleanLit) {}
+
+pub fn visit_callback_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut CallbackDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    v.visit_return_type_mut(&mut node.return_type);
+    v.visit_argument_list_mut(&mut node.arguments.body);
+}
+
+pub fn visit_callback_interface_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut CallbackInterfaceDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    if let Some(it) = &mut node.inheritance {
+        v.visit_inheritance_mut(it);
+    }
+    for it in &mut node.members.body {
+        v.visit_interface_member_mut(it);
+    }
+}
+
+pub fn visit_const_member_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut ConstMember<'a>) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_const_type_mut(&mut node.const_type);
+    v.visit_identifier_mut(&mut node.identifier);
+    v.visit_const_value_mut(&mut node.const_value);
+}
+
+pub fn visit_const_namespace_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ConstNamespaceMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_const_type_mut(&mut node.const_type);
+    v.visit_identifier_mut(&mut node.identifier);
+    v.visit_const_value_mut(&mut node.const_value);
+}
+
+pub fn visit_const_type_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut ConstType<'a>) {
+    match node {
+        ConstType::Integer(it) => v.visit_integer_type_mut(&mut it.type_),
+        ConstType::FloatingPoint(it) => v.visit_floating_point_type_mut(&mut it.type_),
+        ConstType::Boolean(_) => {}
+        ConstType::Byte(_) => {}
+        ConstType::Octet(_) => {}
+        ConstType::BigInt(_) => {}
+        ConstType::Identifier(it) => v.visit_identifier_mut(&mut it.type_),
+    }
+}
+
+pub fn visit_const_value_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut ConstValue<'a>) {
+    match node {
+        ConstValue::Boolean(it) => v.visit_boolean_lit_mut(it),
+        ConstValue::Float(it) => v.visit_float_lit_mut(it),
+        ConstValue::Integer(it) => v.visit_integer_lit_mut(it),
+        ConstValue::Null(_) => {}
+    }
+}
+
+pub fn visit_constructor_interface_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ConstructorInterfaceMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_argument_list_mut(&mut node.args.body);
+}
+
+pub fn visit_dec_lit_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut DecLit<'a>) {}
+
+pub fn visit_default_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Default<'a>) {
+    v.visit_default_value_mut(&mut node.value);
+}
+
+pub fn visit_default_value_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut DefaultValue<'a>,
+) {
+    match node {
+        DefaultValue::Boolean(it) => v.visit_boolean_lit_mut(it),
+        DefaultValue::EmptyArray(it) => v.visit_empty_array_lit_mut(it),
+        DefaultValue::EmptyDictionary(it) => v.visit_empty_dictionary_lit_mut(it),
+        DefaultValue::Float(it) => v.visit_float_lit_mut(it),
+        DefaultValue::Integer(it) => v.visit_integer_lit_mut(it),
+        DefaultValue::Null(_) => {}
+        DefaultValue::String(it) => v.visit_string_lit_mut(it),
+    }
+}
+
+pub fn visit_definition_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Definition<'a>) {
+    match node {
+        Definition::Callback(it) => v.visit_callback_definition_mut(it),
+        Definition::CallbackInterface(it) => v.visit_callback_interface_definition_mut(it),
+        Definition::Interface(it) => v.visit_interface_definition_mut(it),
+        Definition::InterfaceMixin(it) => v.visit_interface_mixin_definition_mut(it),
+        Definition::Namespace(it) => v.visit_namespace_definition_mut(it),
+        Definition::Dictionary(it) => v.visit_dictionary_definition_mut(it),
+        Definition::PartialInterface(it) => v.visit_partial_interface_definition_mut(it),
+        Definition::PartialInterfaceMixin(it) => v.visit_partial_interface_mixin_definition_mut(it),
+        Definition::PartialDictionary(it) => v.visit_partial_dictionary_definition_mut(it),
+        Definition::PartialNamespace(it) => v.visit_partial_namespace_definition_mut(it),
+        Definition::Enum(it) => v.visit_enum_definition_mut(it),
+        Definition::Typedef(it) => v.visit_typedef_definition_mut(it),
+        Definition::IncludesStatement(it) => v.visit_includes_statement_definition_mut(it),
+        Definition::Implements(it) => v.visit_implements_definition_mut(it),
+    }
+}
+
+pub fn visit_dictionary_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut DictionaryDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    if let Some(it) = &mut node.inheritance {
+        v.visit_inheritance_mut(it);
+    }
+    for it in &mut node.members.body {
+        v.visit_dictionary_member_mut(it);
+    }
+}
+
+pub fn visit_dictionary_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut DictionaryMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_type_mut(&mut node.type_);
+    v.visit_identifier_mut(&mut node.identifier);
+    if let Some(it) = &mut node.default {
+        v.visit_default_mut(it);
+    }
+}
+
+pub fn visit_double_type_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut DoubleType) {}
+
+pub fn visit_double_typed_async_iterable_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut DoubleTypedAsyncIterable<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_async_iterable_keyword_mut(&mut node.async_iterable);
+    v.visit_attributed_type_mut(&mut node.generics.body.0);
+    v.visit_attributed_type_mut(&mut node.generics.body.2);
+    if let Some(it) = &mut node.args {
+        v.visit_argument_list_mut(&mut it.body);
+    }
+}
+
+pub fn visit_double_typed_iterable_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut DoubleTypedIterable<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_attributed_type_mut(&mut node.generics.body.0);
+    v.visit_attributed_type_mut(&mut node.generics.body.2);
+}
+
+pub fn visit_empty_array_lit_mut<'a, V: VisitMut<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &mut EmptyArrayLit,
+) {
+}
+
+pub fn visit_empty_dictionary_lit_mut<'a, V: VisitMut<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &mut EmptyDictionaryLit,
+) {
+}
+
+pub fn visit_enum_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut EnumDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    v.visit_enum_value_list_mut(&mut node.values.body);
+}
+
+pub fn visit_enum_value_list_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut EnumValueList<'a>,
+) {
+    for it in &mut node.list {
+        v.visit_string_lit_mut(it);
+    }
+}
+
+pub fn visit_extended_attribute_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ExtendedAttribute<'a>,
+) {
+    match node {
+        ExtendedAttribute::ArgList(it) => v.visit_extended_attribute_arg_list_mut(it),
+        ExtendedAttribute::NamedArgList(it) => v.visit_extended_attribute_named_arg_list_mut(it),
+        ExtendedAttribute::IdentList(it) => v.visit_extended_attribute_ident_list_mut(it),
+        ExtendedAttribute::Ident(it) => v.visit_extended_attribute_ident_mut(it),
+        ExtendedAttribute::Wildcard(it) => v.visit_extended_attribute_wild_card_mut(it),
+        ExtendedAttribute::NoArgs(it) => v.visit_extended_attribute_no_args_mut(it),
+    }
+}
+
+pub fn visit_extended_attribute_arg_list_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ExtendedAttributeArgList<'a>,
+) {
+    v.visit_identifier_mut(&mut node.identifier);
+    v.visit_argument_list_mut(&mut node.args.body);
+}
+
+pub fn visit_extended_attribute_ident_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ExtendedAttributeIdent<'a>,
+) {
+    v.visit_identifier_mut(&mut node.lhs_identifier);
+    v.visit_identifier_or_string_mut(&mut node.rhs);
+}
+
+pub fn visit_extended_attribute_ident_list_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ExtendedAttributeIdentList<'a>,
+) {
+    v.visit_identifier_mut(&mut node.identifier);
+    v.visit_identifier_list_mut(&mut node.list.body);
+}
+
+pub fn visit_extended_attribute_list_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ExtendedAttributeList<'a>,
+) {
+    for it in &mut node.body.list {
+        v.visit_extended_attribute_mut(it);
+    }
+}
+
+pub fn visit_extended_attribute_named_arg_list_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ExtendedAttributeNamedArgList<'a>,
+) {
+    v.visit_identifier_mut(&mut node.lhs_identifier);
+    v.visit_identifier_mut(&mut node.rhs_identifier);
+    v.visit_argument_list_mut(&mut node.args.body);
+}
+
+pub fn visit_extended_attribute_no_args_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ExtendedAttributeNoArgs<'a>,
+) {
+    v.visit_identifier_mut(&mut node.0);
+}
+
+pub fn visit_extended_attribute_wild_card_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ExtendedAttributeWildCard<'a>,
+) {
+    v.visit_identifier_mut(&mut node.lhs_identifier);
+}
+
+pub fn visit_float_lit_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut FloatLit<'a>) {
+    match node {
+        FloatLit::Value(it) => v.visit_float_value_lit_mut(it),
+        FloatLit::NegInfinity(_) => {}
+        FloatLit::Infinity(_) => {}
+        FloatLit::NaN(_) => {}
+    }
+}
+
+pub fn visit_float_type_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut FloatType) {}
+
+pub fn visit_float_value_lit_mut<'a, V: VisitMut<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &mut FloatValueLit<'a>,
+) {
+}
+
+pub fn visit_floating_point_type_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut FloatingPointType,
+) {
+    match node {
+        FloatingPointType::Float(it) => v.visit_float_type_mut(it),
+        FloatingPointType::Double(it) => v.visit_double_type_mut(it),
+    }
+}
+
+pub fn visit_frozen_array_type_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut FrozenArrayType<'a>,
+) {
+    v.visit_type_mut(&mut node.generics.body);
+}
+
+pub fn visit_hex_lit_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut HexLit<'a>) {}
+
+pub fn visit_identifier_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut Identifier<'a>) {}
+
+pub fn visit_identifier_list_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut IdentifierList<'a>,
+) {
+    for it in &mut node.list {
+        v.visit_identifier_mut(it);
+    }
+}
+
+pub fn visit_identifier_or_string_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut IdentifierOrString<'a>,
+) {
+    match node {
+        IdentifierOrString::Identifier(it) => v.visit_identifier_mut(it),
+        IdentifierOrString::String(it) => v.visit_string_lit_mut(it),
+    }
+}
+
+pub fn visit_implements_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ImplementsDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.lhs_identifier);
+    v.visit_identifier_mut(&mut node.rhs_identifier);
+}
+
+pub fn visit_includes_statement_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut IncludesStatementDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.lhs_identifier);
+    v.visit_identifier_mut(&mut node.rhs_identifier);
+}
+
+pub fn visit_inheritance_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Inheritance<'a>) {
+    v.visit_identifier_mut(&mut node.identifier);
+}
+
+pub fn visit_integer_lit_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut IntegerLit<'a>) {
+    match node {
+        IntegerLit::Dec(it) => v.visit_dec_lit_mut(it),
+        IntegerLit::Hex(it) => v.visit_hex_lit_mut(it),
+        IntegerLit::Oct(it) => v.visit_oct_lit_mut(it),
+    }
+}
+
+pub fn visit_integer_type_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut IntegerType) {
+    match node {
+        IntegerType::LongLong(it) => v.visit_long_long_type_mut(it),
+        IntegerType::Long(it) => v.visit_long_type_mut(it),
+        IntegerType::Short(it) => v.visit_short_type_mut(it),
+    }
+}
+
+pub fn visit_interface_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut InterfaceDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    if let Some(it) = &mut node.inheritance {
+        v.visit_inheritance_mut(it);
+    }
+    for it in &mut node.members.body {
+        v.visit_interface_member_mut(it);
+    }
+}
+
+pub fn visit_interface_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut InterfaceMember<'a>,
+) {
+    match node {
+        InterfaceMember::Const(it) => v.visit_const_member_mut(it),
+        InterfaceMember::Attribute(it) => v.visit_attribute_interface_member_mut(it),
+        InterfaceMember::Constructor(it) => v.visit_constructor_interface_member_mut(it),
+        InterfaceMember::Operation(it) => v.visit_operation_interface_member_mut(it),
+        InterfaceMember::Iterable(it) => v.visit_iterable_interface_member_mut(it),
+        InterfaceMember::AsyncIterable(it) => v.visit_async_iterable_interface_member_mut(it),
+        InterfaceMember::Maplike(it) => v.visit_maplike_interface_member_mut(it),
+        InterfaceMember::Setlike(it) => v.visit_setlike_interface_member_mut(it),
+        InterfaceMember::Stringifier(it) => v.visit_stringifier_member_mut(it),
+    }
+}
+
+pub fn visit_interface_mixin_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut InterfaceMixinDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    for it in &mut node.members.body {
+        v.visit_mixin_member_mut(it);
+    }
+}
+
+pub fn visit_iterable_interface_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut IterableInterfaceMember<'a>,
+) {
+    match node {
+        IterableInterfaceMember::Single(it) => v.visit_single_typed_iterable_mut(it),
+        IterableInterfaceMember::Double(it) => v.visit_double_typed_iterable_mut(it),
+    }
+}
+
+pub fn visit_long_long_type_mut<'a, V: VisitMut<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &mut LongLongType,
+) {
+}
+
+pub fn visit_long_type_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut LongType) {}
+
+pub fn visit_maplike_interface_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut MaplikeInterfaceMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_attributed_type_mut(&mut node.generics.body.0);
+    v.visit_attributed_type_mut(&mut node.generics.body.2);
+}
+
+pub fn visit_mixin_member_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut MixinMember<'a>) {
+    match node {
+        MixinMember::Const(it) => v.visit_const_member_mut(it),
+        MixinMember::Operation(it) => v.visit_operation_mixin_member_mut(it),
+        MixinMember::Attribute(it) => v.visit_attribute_mixin_member_mut(it),
+        MixinMember::Stringifier(it) => v.visit_stringifier_member_mut(it),
+    }
+}
+
+pub fn visit_namespace_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut NamespaceDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    for it in &mut node.members.body {
+        v.visit_namespace_member_mut(it);
+    }
+}
+
+pub fn visit_namespace_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut NamespaceMember<'a>,
+) {
+    match node {
+        NamespaceMember::Const(it) => v.visit_const_namespace_member_mut(it),
+        NamespaceMember::Operation(it) => v.visit_operation_namespace_member_mut(it),
+        NamespaceMember::Attribute(it) => v.visit_attribute_namespace_member_mut(it),
+    }
+}
+
+pub fn visit_non_any_type_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut NonAnyType<'a>) {
+    match node {
+        NonAnyType::Promise(it) => v.visit_promise_type_mut(it),
+        NonAnyType::Integer(it) => v.visit_integer_type_mut(&mut it.type_),
+        NonAnyType::FloatingPoint(it) => v.visit_floating_point_type_mut(&mut it.type_),
+        NonAnyType::Boolean(_) => {}
+        NonAnyType::Byte(_) => {}
+        NonAnyType::Octet(_) => {}
+        NonAnyType::BigInt(_) => {}
+        NonAnyType::ByteString(_) => {}
+        NonAnyType::DOMString(_) => {}
+        NonAnyType::USVString(_) => {}
+        NonAnyType::Sequence(it) => v.visit_sequence_type_mut(&mut it.type_),
+        NonAnyType::Object(_) => {}
+        NonAnyType::Symbol(_) => {}
+        NonAnyType::Error(_) => {}
+        NonAnyType::ArrayBuffer(_) => {}
+        NonAnyType::SharedArrayBuffer(_) => {}
+        NonAnyType::DataView(_) => {}
+        NonAnyType::Int8Array(_) => {}
+        NonAnyType::Int16Array(_) => {}
+        NonAnyType::Int32Array(_) => {}
+        NonAnyType::Uint8Array(_) => {}
+        NonAnyType::Uint16Array(_) => {}
+        NonAnyType::Uint32Array(_) => {}
+        NonAnyType::Uint8ClampedArray(_) => {}
+        NonAnyType::BigInt64Array(_) => {}
+        NonAnyType::BigUint64Array(_) => {}
+        NonAnyType::Float16Array(_) => {}
+        NonAnyType::Float32Array(_) => {}
+        NonAnyType::Float64Array(_) => {}
+        NonAnyType::ArrayBufferView(_) => {}
+        NonAnyType::BufferSource(_) => {}
+        NonAnyType::FrozenArrayType(it) => v.visit_frozen_array_type_mut(&mut it.type_),
+        NonAnyType::ObservableArrayType(it) => v.visit_observable_array_type_mut(&mut it.type_),
+        NonAnyType::RecordType(it) => v.visit_record_type_mut(&mut it.type_),
+        NonAnyType::Identifier(it) => v.visit_identifier_mut(&mut it.type_),
+    }
+}
+
+pub fn visit_observable_array_type_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut ObservableArrayType<'a>,
+) {
+    v.visit_type_mut(&mut node.generics.body);
+}
+
+pub fn visit_oct_lit_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut OctLit<'a>) {}
+
+pub fn visit_operation_interface_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut OperationInterfaceMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    if let Some(it) = &mut node.modifier {
+        v.visit_stringifier_or_static_mut(it);
+    }
+    if let Some(it) = &mut node.special {
+        v.visit_special_mut(it);
+    }
+    v.visit_return_type_mut(&mut node.return_type);
+    if let Some(it) = &mut node.identifier {
+        v.visit_identifier_mut(it);
+    }
+    v.visit_argument_list_mut(&mut node.args.body);
+}
+
+pub fn visit_operation_mixin_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut OperationMixinMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_return_type_mut(&mut node.return_type);
+    if let Some(it) = &mut node.identifier {
+        v.visit_identifier_mut(it);
+    }
+    v.visit_argument_list_mut(&mut node.args.body);
+}
+
+pub fn visit_operation_namespace_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut OperationNamespaceMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_return_type_mut(&mut node.return_type);
+    if let Some(it) = &mut node.identifier {
+        v.visit_identifier_mut(it);
+    }
+    v.visit_argument_list_mut(&mut node.args.body);
+}
+
+pub fn visit_partial_dictionary_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut PartialDictionaryDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    for it in &mut node.members.body {
+        v.visit_dictionary_member_mut(it);
+    }
+}
+
+pub fn visit_partial_interface_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut PartialInterfaceDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    for it in &mut node.members.body {
+        v.visit_interface_member_mut(it);
+    }
+}
+
+pub fn visit_partial_interface_mixin_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut PartialInterfaceMixinDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    for it in &mut node.members.body {
+        v.visit_mixin_member_mut(it);
+    }
+}
+
+pub fn visit_partial_namespace_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut PartialNamespaceDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_identifier_mut(&mut node.identifier);
+    for it in &mut node.members.body {
+        v.visit_namespace_member_mut(it);
+    }
+}
+
+pub fn visit_promise_type_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut PromiseType<'a>) {
+    v.visit_return_type_mut(&mut node.generics.body);
+}
+
+pub fn visit_record_key_type_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut RecordKeyType<'a>,
+) {
+    match node {
+        RecordKeyType::Byte(_) => {}
+        RecordKeyType::DOM(_) => {}
+        RecordKeyType::USV(_) => {}
+        RecordKeyType::NonAny(it) => v.visit_non_any_type_mut(it),
+    }
+}
+
+pub fn visit_record_type_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut RecordType<'a>) {
+    v.visit_record_key_type_mut(&mut node.generics.body.0);
+    v.visit_type_mut(&mut node.generics.body.2);
+}
+
+pub fn visit_return_type_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut ReturnType<'a>) {
+    match node {
+        ReturnType::Undefined(_) => {}
+        ReturnType::Type(it) => v.visit_type_mut(it),
+    }
+}
+
+pub fn visit_separate_async_iterable_mut<'a, V: VisitMut<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &mut SeparateAsyncIterable,
+) {
+}
+
+pub fn visit_sequence_type_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut SequenceType<'a>,
+) {
+    v.visit_type_mut(&mut node.generics.body);
+}
+
+pub fn visit_setlike_interface_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut SetlikeInterfaceMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_attributed_type_mut(&mut node.generics.body);
+}
+
+pub fn visit_short_type_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut ShortType) {}
+
+pub fn visit_single_argument_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut SingleArgument<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_attributed_type_mut(&mut node.type_);
+    v.visit_argument_name_mut(&mut node.identifier);
+    if let Some(it) = &mut node.default {
+        v.visit_default_mut(it);
+    }
+}
+
+pub fn visit_single_type_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut SingleType<'a>) {
+    match node {
+        SingleType::Any(_) => {}
+        SingleType::NonAny(it) => v.visit_non_any_type_mut(it),
+    }
+}
+
+pub fn visit_single_typed_async_iterable_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut SingleTypedAsyncIterable<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_async_iterable_keyword_mut(&mut node.async_iterable);
+    v.visit_attributed_type_mut(&mut node.generics.body);
+    if let Some(it) = &mut node.args {
+        v.visit_argument_list_mut(&mut it.body);
+    }
+}
+
+pub fn visit_single_typed_iterable_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut SingleTypedIterable<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_attributed_type_mut(&mut node.generics.body);
+}
+
+pub fn visit_special_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut Special) {}
+
+pub fn visit_string_lit_mut<'a, V: VisitMut<'a> + ?Sized>(_v: &mut V, _node: &mut StringLit<'a>) {}
+
+pub fn visit_stringifier_member_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut StringifierMember<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+}
+
+pub fn visit_stringifier_or_inherit_or_static_mut<'a, V: VisitMut<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &mut StringifierOrInheritOrStatic,
+) {
+}
+
+pub fn visit_stringifier_or_static_mut<'a, V: VisitMut<'a> + ?Sized>(
+    _v: &mut V,
+    _node: &mut StringifierOrStatic,
+) {
+}
+
+pub fn visit_type_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut Type<'a>) {
+    match node {
+        Type::Single(it) => v.visit_single_type_mut(it),
+        Type::Union(it) => v.visit_union_type_mut(&mut it.type_),
+    }
+}
+
+pub fn visit_typedef_definition_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut TypedefDefinition<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_attributed_type_mut(&mut node.type_);
+    v.visit_identifier_mut(&mut node.identifier);
+}
+
+pub fn visit_union_member_type_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut UnionMemberType<'a>,
+) {
+    match node {
+        UnionMemberType::Single(it) => v.visit_attributed_non_any_type_mut(it),
+        UnionMemberType::Union(it) => v.visit_union_type_mut(&mut it.type_),
+    }
+}
+
+pub fn visit_union_type_mut<'a, V: VisitMut<'a> + ?Sized>(v: &mut V, node: &mut UnionType<'a>) {
+    for it in &mut node.body.list {
+        v.visit_union_member_type_mut(it);
+    }
+}
+
+pub fn visit_variadic_argument_mut<'a, V: VisitMut<'a> + ?Sized>(
+    v: &mut V,
+    node: &mut VariadicArgument<'a>,
+) {
+    if let Some(it) = &mut node.attributes {
+        v.visit_extended_attribute_list_mut(it);
+    }
+    v.visit_type_mut(&mut node.type_);
+    v.visit_argument_name_mut(&mut node.identifier);
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::print::ToWebIdl;
+
+    struct Rename;
+
+    impl<'a> VisitMut<'a> for Rename {
+        fn visit_identifier_mut(&mut self, node: &mut Identifier<'a>) {
+            if node.0 == "Foo" {
+                node.0 = "Bar";
+            }
+        }
+    }
+
+    #[test]
+    fn should_visit_every_identifier() {
+        let mut parsed = crate::parse(
+            "typedef (Foo or sequence<Foo?>) Foo;
+            callback Foo = Promise<Foo> (record<DOMString, Foo> foo);",
+        )
+        .unwrap();
+        for definition in &mut parsed {
+            Rename.visit_definition_mut(definition);
+        }
+        assert_eq!(
+            parsed.to_webidl(),
+            "typedef (Bar or sequence<Bar?>) Bar;\n\n\
+             callback Bar = Promise<Bar>(record<DOMString, Bar> foo);"
+        );
+    }
+
+    #[test]
+    fn should_visit_type_aliases() {
+        struct FirstAttribute;
+
+        impl<'a> VisitMut<'a> for FirstAttribute {
+            fn visit_extended_attribute_list_mut(&mut self, node: &mut ExtendedAttributeList<'a>) {
+                node.body.list.truncate(1);
+            }
+        }
+
+        let mut parsed =
+            crate::parse("[A, B] interface Foo { [C, D] attribute long x; };").unwrap();
+        for definition in &mut parsed {
+            FirstAttribute.visit_definition_mut(definition);
+        }
+        assert_eq!(
+            parsed.to_webidl(),
+            "[A]\ninterface Foo {\n    [C] attribute long x;\n};"
+        );
+    }
+}
@@ -0,0 +1,809 @@
+//! Conversion to the JSON syntax tree of the `webidl2` JavaScript package
+//!
+//! [`to_json`](fn.to_json.html) gives the definitions in the shape
+//! `webidl2.parse` returns them, so tools reading that shape can read the
+//! output of weedle as is. Nested types carry the `type` of the type they are
+//! part of, such as `"attribute-type"`.
+//!
+//! ### Example
+//!
+//! ```
+//! use serde_json::json;
+//!
+//! let parsed = weedle::parse("
+//!     interface Node : EventTarget {
+//!         readonly attribute Node? parentNode;
+//!     };
+//! ").unwrap();
+//!
+//! assert_eq!(
+//!     weedle::webidl2::to_json(&parsed),
+//!     json!([{
+//!         "type": "interface",
+//!         "name": "Node",
+//!         "partial": false,
+//!         "inheritance": "EventTarget",
+//!         "extAttrs": [],
+//!         "members": [{
+//!             "type": "attribute",
+//!             "name": "parentNode",
+//!             "special": "",
+//!             "readonly": true,
+//!             "extAttrs": [],
+//!             "idlType": {
+//!                 "type": "attribute-type",
+//!                 "generic": "",
+//!                 "idlType": "Node",
+//!                 "nullable": true,
+//!                 "union": false,
+//!                 "extAttrs": [],
+//!             },
+//!         }],
+//!     }]),
+//! );
+//! ```
+
+use serde_json::{json, Value};
+
+use crate::argument::{Argument, ArgumentList};
+use crate::attribute::{ExtendedAttribute, ExtendedAttributeList, IdentifierOrString};
+use crate::common::{Default, Identifier};
+use crate::dictionary::DictionaryMember;
+use crate::interface::{
+    AsyncIterableInterfaceMember, ConstMember, Inheritance, InterfaceMember,
+    IterableInterfaceMember, Special, StringifierOrInheritOrStatic, StringifierOrStatic,
+};
+use crate::literal::{ConstValue, DefaultValue, FloatLit, IntegerLit};
+use crate::mixin::MixinMember;
+use crate::namespace::NamespaceMember;
+use crate::print::ToWebIdl;
+use crate::types::{
+    AttributedType, ConstType, NonAnyType, RecordKeyType, ReturnType, SingleType, Type,
+    UnionMemberType, UnionType,
+};
+use crate::Definition;
+
+/// Converts the definitions to the JSON `webidl2.parse` gives for them
+pub fn to_json(definitions: &[Definition]) -> Value {
+    definitions.iter().map(definition).collect()
+}
+
+fn definition(definition: &Definition) -> Value {
+    match definition {
+        Definition::Callback(d) => json!({
+            "type": "callback",
+            "name": d.identifier.0,
+            "idlType": return_type(&d.return_type, "return-type"),
+            "arguments": arguments(&d.arguments.body.list),
+            "extAttrs": ext_attrs(&d.attributes),
+        }),
+        Definition::CallbackInterface(d) => container(
+            "callback interface",
+            d.identifier,
+            false,
+            &d.inheritance,
+            &d.attributes,
+            d.members.body.iter().map(interface_member).collect(),
+        ),
+        Definition::Interface(d) => container(
+            "interface",
+            d.identifier,
+            false,
+            &d.inheritance,
+            &d.attributes,
+            d.members.body.iter().map(interface_member).collect(),
+        ),
+        Definition::InterfaceMixin(d) => container(
+            "interface mixin",
+            d.identifier,
+            false,
+            &None,
+            &d.attributes,
+            d.members.body.iter().map(mixin_member).collect(),
+        ),
+        Definition::Namespace(d) => container(
+            "namespace",
+            d.identifier,
+            false,
+            &None,
+            &d.attributes,
+            d.members.body.iter().map(namespace_member).collect(),
+        ),
+        Definition::Dictionary(d) => container(
+            "dictionary",
+            d.identifier,
+            false,
+            &d.inheritance,
+            &d.attributes,
+            d.members.body.iter().map(dictionary_member).collect(),
+        ),
+        Definition::PartialInterface(d) => container(
+            "interface",
+            d.identifier,
+            true,
+            &None,
+            &d.attributes,
+            d.members.body.iter().map(interface_member).collect(),
+        ),
+        Definition::PartialInterfaceMixin(d) => container(
+            "interface mixin",
+            d.identifier,
+            true,
+            &None,
+            &d.attributes,
+            d.members.body.iter().map(mixin_member).collect(),
+        ),
+        Definition::PartialDictionary(d) => container(
+            "dictionary",
+            d.identifier,
+            true,
+            &None,
+            &d.attributes,
+            d.members.body.iter().map(dictionary_member).collect(),
+        ),
+        Definition::PartialNamespace(d) => container(
+            "namespace",
+            d.identifier,
+            true,
+            &None,
+            &d.attributes,
+            d.members.body.iter().map(namespace_member).collect(),
+        ),
+        Definition::Enum(d) => json!({
+            "type": "enum",
+            "name": d.identifier.0,
+            "values": d.values.body.list.iter().map(|value| json!({
+                "type": "enum-value",
+                "value": value.0,
+            })).collect::<Vec<_>>(),
+            "extAttrs": ext_attrs(&d.attributes),
+        }),
+        Definition::Typedef(d) => json!({
+            "type": "typedef",
+            "name": d.identifier.0,
+            "idlType": attributed_type(&d.type_, "typedef-type"),
+            "extAttrs": ext_attrs(&d.attributes),
+        }),
+        Definition::IncludesStatement(d) => json!({
+            "type": "includes",
+            "target": d.lhs_identifier.0,
+            "includes": d.rhs_identifier.0,
+            "extAttrs": ext_attrs(&d.attributes),
+        }),
+        Definition::Implements(d) => json!({
+            "type": "implements",
+            "target": d.lhs_identifier.0,
+            "implements": d.rhs_identifier.0,
+            "extAttrs": ext_attrs(&d.attributes),
+        }),
+    }
+}
+
+fn container(
+    type_: &str,
+    name: Identifier,
+    partial: bool,
+    inheritance: &Option<Inheritance>,
+    attributes: &Option<ExtendedAttributeList>,
+    members: Vec<Value>,
+) -> Value {
+    json!({
+        "type": type_,
+        "name": name.0,
+        "partial": partial,
+        "inheritance": inheritance.as_ref().map(|inheritance| inheritance.identifier.0),
+        "members": members,
+        "extAttrs": ext_attrs(attributes),
+    })
+}
+
+fn interface_member(member: &InterfaceMember) -> Value {
+    match member {
+        InterfaceMember::Const(m) => const_member(m),
+        InterfaceMember::Attribute(m) => attribute(
+            m.modifier.as_ref().map_or("", |modifier| match modifier {
+                StringifierOrInheritOrStatic::Stringifier(_) => "stringifier",
+                StringifierOrInheritOrStatic::Inherit(_) => "inherit",
+                StringifierOrInheritOrStatic::Static(_) => "static",
+            }),
+            m.readonly.is_some(),
+            &m.type_,
+            m.identifier,
+            &m.attributes,
+        ),
+        InterfaceMember::Constructor(m) => json!({
+            "type": "constructor",
+            "arguments": arguments(&m.args.body.list),
+            "extAttrs": ext_attrs(&m.attributes),
+        }),
+        InterfaceMember::Operation(m) => {
+            let special = match (&m.modifier, &m.special) {
+                (Some(StringifierOrStatic::Stringifier(_)), _) => "stringifier",
+                (Some(StringifierOrStatic::Static(_)), _) => "static",
+                (None, Some(Special::Getter(_))) => "getter",
+                (None, Some(Special::Setter(_))) => "setter",
+                (None, Some(Special::Deleter(_))) => "deleter",
+                (None, Some(Special::LegacyCaller(_))) => "legacycaller",
+                (None, None) => "",
+            };
+            operation(
+                special,
+                Some(&m.return_type),
+                m.identifier,
+                &m.args.body.list,
+                &m.attributes,
+            )
+        }
+        InterfaceMember::Iterable(IterableInterfaceMember::Single(m)) => declaration(
+            "iterable",
+            vec![attributed_type(&m.generics.body, "")],
+            false,
+            None,
+            &m.attributes,
+        ),
+        InterfaceMember::Iterable(IterableInterfaceMember::Double(m)) => declaration(
+            "iterable",
+            vec![
+                attributed_type(&m.generics.body.0, ""),
+                attributed_type(&m.generics.body.2, ""),
+            ],
+            false,
+            None,
+            &m.attributes,
+        ),
+        InterfaceMember::AsyncIterable(AsyncIterableInterfaceMember::Single(m)) => declaration(
+            "async_iterable",
+            vec![attributed_type(&m.generics.body, "")],
+            false,
+            m.args.as_ref().map(|args| &args.body),
+            &m.attributes,
+        ),
+        InterfaceMember::AsyncIterable(AsyncIterableInterfaceMember::Double(m)) => declaration(
+            "async_iterable",
+            vec![
+                attributed_type(&m.generics.body.0, ""),
+                attributed_type(&m.generics.body.2, ""),
+            ],
+            false,
+            m.args.as_ref().map(|args| &args.body),
+            &m.attributes,
+        ),
+        InterfaceMember::Maplike(m) => declaration(
+            "maplike",
+            vec![
+                attributed_type(&m.generics.body.0, ""),
+                attributed_type(&m.generics.body.2, ""),
+            ],
+            m.readonly.is_some(),
+            None,
+            &m.attributes,
+        ),
+        InterfaceMember::Setlike(m) => declaration(
+            "setlike",
+            vec![attributed_type(&m.generics.body, "")],
+            m.readonly.is_some(),
+            None,
+            &m.attributes,
+        ),
+        InterfaceMember::Stringifier(m) => operation("stringifier", None, None, &[], &m.attributes),
+    }
+}
+
+fn mixin_member(member: &MixinMember) -> Value {
+    match member {
+        MixinMember::Const(m) => const_member(m),
+        MixinMember::Operation(m) => operation(
+            if m.stringifier.is_some() {
+                "stringifier"
+            } else {
+                ""
+            },
+            Some(&m.return_type),
+            m.identifier,
+            &m.args.body.list,
+            &m.attributes,
+        ),
+        MixinMember::Attribute(m) => attribute(
+            if m.stringifier.is_some() {
+                "stringifier"
+            } else {
+                ""
+            },
+            m.readonly.is_some(),
+            &m.type_,
+            m.identifier,
+            &m.attributes,
+        ),
+        MixinMember::Stringifier(m) => operation("stringifier", None, None, &[], &m.attributes),
+    }
+}
+
+fn namespace_member(member: &NamespaceMember) -> Value {
+    match member {
+        NamespaceMember::Const(m) => json!({
+            "type": "const",
+            "name": m.identifier.0,
+            "idlType": const_type(&m.const_type),
+            "value": const_value(&m.const_value),
+            "extAttrs": ext_attrs(&m.attributes),
+        }),
+        NamespaceMember::Operation(m) => operation(
+            "",
+            Some(&m.return_type),
+            m.identifier,
+            &m.args.body.list,
+            &m.attributes,
+        ),
+        NamespaceMember::Attribute(m) => attribute("", true, &m.type_, m.identifier, &m.attributes),
+    }
+}
+
+fn dictionary_member(member: &DictionaryMember) -> Value {
+    json!({
+        "type": "field",
+        "name": member.identifier.0,
+        "required": member.required.is_some(),
+        "idlType": idl_type(&member.type_, "dictionary-type", &None),
+        "default": default(&member.default),
+        "extAttrs": ext_attrs(&member.attributes),
+    })
+}
+
+fn const_member(member: &ConstMember) -> Value {
+    json!({
+        "type": "const",
+        "name": member.identifier.0,
+        "idlType": const_type(&member.const_type),
+        "value": const_value(&member.const_value),
+        "extAttrs": ext_attrs(&member.attributes),
+    })
+}
+
+fn attribute(
+    special: &str,
+    readonly: bool,
+    type_: &AttributedType,
+    name: Identifier,
+    attributes: &Option<ExtendedAttributeList>,
+) -> Value {
+    json!({
+        "type": "attribute",
+        "name": name.0,
+        "special": special,
+        "readonly": readonly,
+        "idlType": attributed_type(type_, "attribute-type"),
+        "extAttrs": ext_attrs(attributes),
+    })
+}
+
+fn operation(
+    special: &str,
+    return_type: Option<&ReturnType>,
+    name: Option<Identifier>,
+    args: &[Argument],
+    attributes: &Option<ExtendedAttributeList>,
+) -> Value {
+    json!({
+        "type": "operation",
+        "name": name.map_or("", |name| name.0),
+        "special": special,
+        "idlType": return_type.map(|return_type| self::return_type(return_type, "return-type")),
+        "arguments": arguments(args),
+        "extAttrs": ext_attrs(attributes),
+    })
+}
+
+// Iterable, async iterable, maplike and setlike declarations
+fn declaration(
+    type_: &str,
+    idl_types: Vec<Value>,
+    readonly: bool,
+    args: Option<&ArgumentList>,
+    attributes: &Option<ExtendedAttributeList>,
+) -> Value {
+    json!({
+        "type": type_,
+        "idlType": idl_types,
+        "readonly": readonly,
+        "async": type_ == "async_iterable",
+        "arguments": args.map_or_else(Vec::new, |args| arguments(&args.list)),
+        "extAttrs": ext_attrs(attributes),
+    })
+}
+
+fn arguments(args: &[Argument]) -> Vec<Value> {
+    args.iter()
+        .map(|argument| match argument {
+            Argument::Single(a) => json!({
+                "type": "argument",
+                "name": a.identifier.name(),
+                "idlType": attributed_type(&a.type_, "argument-type"),
+                "default": default(&a.default),
+                "optional": a.optional.is_some(),
+                "variadic": false,
+                "extAttrs": ext_attrs(&a.attributes),
+            }),
+            Argument::Variadic(a) => json!({
+                "type": "argument",
+                "name": a.identifier.name(),
+                "idlType": idl_type(&a.type_, "argument-type", &None),
+                "default": null,
+                "optional": false,
+                "variadic": true,
+                "extAttrs": ext_attrs(&a.attributes),
+            }),
+        })
+        .collect()
+}
+
+fn ext_attrs(attributes: &Option<ExtendedAttributeList>) -> Vec<Value> {
+    let attributes = match attributes {
+        Some(attributes) => &attributes.body.list,
+        None => return Vec::new(),
+    };
+    attributes
+        .iter()
+        .map(|attribute| {
+            let (name, rhs, args) = match attribute {
+                ExtendedAttribute::NoArgs(a) => (a.0, Value::Null, None),
+                ExtendedAttribute::ArgList(a) => (a.identifier, Value::Null, Some(&a.args.body)),
+                ExtendedAttribute::NamedArgList(a) => (
+                    a.lhs_identifier,
+                    json!({ "type": "identifier", "value": a.rhs_identifier.0 }),
+                    Some(&a.args.body),
+                ),
+                ExtendedAttribute::IdentList(a) => (
+                    a.identifier,
+                    json!({
+                        "type": "identifier-list",
+                        "value": a.list.body.list.iter().map(|identifier| json!({
+                            "value": identifier.0,
+                        })).collect::<Vec<_>>(),
+                    }),
+                    None,
+                ),
+                ExtendedAttribute::Ident(a) => (
+                    a.lhs_identifier,
+                    match a.rhs {
+                        IdentifierOrString::Identifier(identifier) => {
+                            json!({ "type": "identifier", "value": identifier.0 })
+                        }
+                        IdentifierOrString::String(string) => {
+                            json!({ "type": "string", "value": format!("\"{}\"", string.0) })
+                        }
+                    },
+                    None,
+                ),
+                ExtendedAttribute::Wildcard(a) => {
+                    (a.lhs_identifier, json!({ "type": "*", "value": "*" }), None)
+                }
+            };
+            json!({
+                "type": "extended-attribute",
+                "name": name.0,
+                "rhs": rhs,
+                "arguments": args.map_or_else(Vec::new, |args| arguments(&args.list)),
+            })
+        })
+        .collect()
+}
+
+fn attributed_type(type_: &AttributedType, kind: &str) -> Value {
+    idl_type(&type_.type_, kind, &type_.attributes)
+}
+
+fn return_type(type_: &ReturnType, kind: &str) -> Value {
+    match type_ {
+        ReturnType::Undefined(_) => leaf("undefined", false, kind, &None),
+        ReturnType::Type(type_) => idl_type(type_, kind, &None),
+    }
+}
+
+fn idl_type(type_: &Type, kind: &str, attributes: &Option<ExtendedAttributeList>) -> Value {
+    match type_ {
+        Type::Single(SingleType::Any(_)) => leaf("any", false, kind, attributes),
+        Type::Single(SingleType::NonAny(type_)) => non_any_type(type_, kind, attributes),
+        Type::Union(union) => union_type(&union.type_, union.q_mark.is_some(), kind, attributes),
+    }
+}
+
+fn union_type(
+    union: &UnionType,
+    nullable: bool,
+    kind: &str,
+    attributes: &Option<ExtendedAttributeList>,
+) -> Value {
+    let members: Vec<_> = union
+        .body
+        .list
+        .iter()
+        .map(|member| match member {
+            UnionMemberType::Single(single) => {
+                non_any_type(&single.type_, kind, &single.attributes)
+            }
+            UnionMemberType::Union(union) => {
+                union_type(&union.type_, union.q_mark.is_some(), kind, &None)
+            }
+        })
+        .collect();
+    json!({
+        "type": kind_json(kind),
+        "generic": "",
+        "idlType": members,
+        "nullable": nullable,
+        "union": true,
+        "extAttrs": ext_attrs(attributes),
+    })
+}
+
+fn non_any_type(
+    type_: &NonAnyType,
+    kind: &str,
+    attributes: &Option<ExtendedAttributeList>,
+) -> Value {
+    let (generic, nullable, idl_types) = match type_ {
+        NonAnyType::Promise(p) => ("Promise", false, vec![return_type(&p.generics.body, kind)]),
+        NonAnyType::Sequence(s) => (
+            "sequence",
+            s.q_mark.is_some(),
+            vec![idl_type(&s.type_.generics.body, kind, &None)],
+        ),
+        NonAnyType::FrozenArrayType(f) => (
+            "FrozenArray",
+            f.q_mark.is_some(),
+            vec![idl_type(&f.type_.generics.body, kind, &None)],
+        ),
+        NonAnyType::ObservableArrayType(o) => (
+            "ObservableArray",
+            o.q_mark.is_some(),
+            vec![idl_type(&o.type_.generics.body, kind, &None)],
+        ),
+        NonAnyType::RecordType(r) => {
+            let (key, _, value) = &r.type_.generics.body;
+            let key = match &**key {
+                RecordKeyType::NonAny(key) => non_any_type(key, kind, &None),
+                key => leaf(&key.to_webidl(), false, kind, &None),
+            };
+            (
+                "record",
+                r.q_mark.is_some(),
+                vec![key, idl_type(value, kind, &None)],
+            )
+        }
+        _ => {
+            let text = type_.to_webidl();
+            return match text.strip_suffix('?') {
+                Some(name) => leaf(name, true, kind, attributes),
+                None => leaf(&text, false, kind, attributes),
+            };
+        }
+    };
+    json!({
+        "type": kind_json(kind),
+        "generic": generic,
+        "idlType": idl_types,
+        "nullable": nullable,
+        "union": false,
+        "extAttrs": ext_attrs(attributes),
+    })
+}
+
+fn const_type(type_: &ConstType) -> Value {
+    let text = type_.to_webidl();
+    match text.strip_suffix('?') {
+        Some(name) => leaf(name, true, "const-type", &None),
+        None => leaf(&text, false, "const-type", &None),
+    }
+}
+
+fn leaf(
+    name: &str,
+    nullable: bool,
+    kind: &str,
+    attributes: &Option<ExtendedAttributeList>,
+) -> Value {
+    json!({
+        "type": kind_json(kind),
+        "generic": "",
+        "idlType": name,
+        "nullable": nullable,
+        "union": false,
+        "extAttrs": ext_attrs(attributes),
+    })
+}
+
+// The types of iterable declarations and the like have no type name
+fn kind_json(kind: &str) -> Value {
+    if kind.is_empty() {
+        Value::Null
+    } else {
+        Value::from(kind)
+    }
+}
+
+fn default(default: &Option<Default>) -> Value {
+    let value = match default {
+        Some(default) => &default.value,
+        None => return Value::Null,
+    };
+    match value {
+        DefaultValue::Boolean(b) => json!({ "type": "boolean", "value": b.0 }),
+        DefaultValue::EmptyArray(_) => json!({ "type": "sequence", "value": [] }),
+        DefaultValue::EmptyDictionary(_) => json!({ "type": "dictionary" }),
+        DefaultValue::Float(f) => float(f),
+        DefaultValue::Integer(i) => integer(i),
+        DefaultValue::Null(_) => json!({ "type": "null" }),
+        DefaultValue::String(s) => json!({ "type": "string", "value": s.0 }),
+    }
+}
+
+fn const_value(value: &ConstValue) -> Value {
+    match value {
+        ConstValue::Boolean(b) => json!({ "type": "boolean", "value": b.0 }),
+        ConstValue::Float(f) => float(f),
+        ConstValue::Integer(i) => integer(i),
+        ConstValue::Null(_) => json!({ "type": "null" }),
+    }
+}
+
+fn integer(integer: &IntegerLit) -> Value {
+    let value = match integer {
+        IntegerLit::Dec(i) => i.0,
+        IntegerLit::Hex(i) => i.0,
+        IntegerLit::Oct(i) => i.0,
+    };
+    json!({ "type": "number", "value": value })
+}
+
+fn float(float: &FloatLit) -> Value {
+    match float {
+        FloatLit::Value(f) => json!({ "type": "number", "value": f.0 }),
+        FloatLit::NegInfinity(_) => json!({ "type": "Infinity", "negative": true }),
+        FloatLit::Infinity(_) => json!({ "type": "Infinity", "negative": false }),
+        FloatLit::NaN(_) => json!({ "type": "NaN" }),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn convert(input: &str) -> Value {
+        to_json(&crate::parse(input).unwrap())
+    }
+
+    #[test]
+    fn should_convert_operations() {
+        let json =
+            convert("interface Foo { getter (long or Node)? item([Clamp] long i, any... rest); };");
+        assert_eq!(
+            json[0]["members"][0],
+            json!({
+                "type": "operation",
+                "name": "item",
+                "special": "getter",
+                "extAttrs": [],
+                "idlType": {
+                    "type": "return-type",
+                    "generic": "",
+                    "nullable": true,
+                    "union": true,
+                    "extAttrs": [],
+                    "idlType": [
+                        {
+                            "type": "return-type",
+                            "generic": "",
+                            "idlType": "long",
+                            "nullable": false,
+                            "union": false,
+                            "extAttrs": [],
+                        },
+                        {
+                            "type": "return-type",
+                            "generic": "",
+                            "idlType": "Node",
+                            "nullable": false,
+                            "union": false,
+                            "extAttrs": [],
+                        },
+                    ],
+                },
+                "arguments": [
+                    {
+                        "type": "argument",
+                        "name": "i",
+                        "default": null,
+                        "optional": false,
+                        "variadic": false,
+                        "extAttrs": [{
+                            "type": "extended-attribute",
+                            "name": "Clamp",
+                            "rhs": null,
+                            "arguments": [],
+                        }],
+                        "idlType": {
+                            "type": "argument-type",
+                            "generic": "",
+                            "idlType": "long",
+                            "nullable": false,
+                            "union": false,
+                            "extAttrs": [],
+                        },
+                    },
+                    {
+                        "type": "argument",
+                        "name": "rest",
+                        "default": null,
+                        "optional": false,
+                        "variadic": true,
+                        "extAttrs": [],
+                        "idlType": {
+                            "type": "argument-type",
+                            "generic": "",
+                            "idlType": "any",
+                            "nullable": false,
+                            "union": false,
+                            "extAttrs": [],
+                        },
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn should_convert_generics() {
+        let json = convert("dictionary D { record<DOMString, sequence<long?>> r; };");
+        let idl_type = &json[0]["members"][0]["idlType"];
+        assert_eq!(idl_type["generic"], "record");
+        assert_eq!(idl_type["idlType"][0]["idlType"], "DOMString");
+        assert_eq!(idl_type["idlType"][1]["generic"], "sequence");
+        assert_eq!(idl_type["idlType"][1]["idlType"][0]["idlType"], "long");
+        assert_eq!(idl_type["idlType"][1]["idlType"][0]["nullable"], true);
+    }
+
+    #[test]
+    fn should_convert_extended_attributes() {
+        let json = convert(
+            "[Exposed=(Window,Worker), LegacyFactoryFunction=Image(long w), Reflect=\"x\", \
+             Global=*] interface Foo {};",
+        );
+        let rhs: Vec<_> = json[0]["extAttrs"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|attribute| attribute["rhs"].clone())
+            .collect();
+        assert_eq!(
+            rhs,
+            [
+                json!({ "type": "identifier-list", "value": [{ "value": "Window" }, { "value": "Worker" }] }),
+                json!({ "type": "identifier", "value": "Image" }),
+                json!({ "type": "string", "value": "\"x\"" }),
+                json!({ "type": "*", "value": "*" }),
+            ]
+        );
+        assert_eq!(json[0]["extAttrs"][1]["arguments"][0]["name"], "w");
+    }
+
+    #[test]
+    fn should_convert_values() {
+        let json = convert(
+            "interface Foo { const long a = 0x1F; const double b = -Infinity; };
+             dictionary D { sequence<long> s = []; DOMString? n = null; };",
+        );
+        assert_eq!(
+            json[0]["members"][0]["value"],
+            json!({ "type": "number", "value": "0x1F" })
+        );
+        assert_eq!(
+            json[0]["members"][1]["value"],
+            json!({ "type": "Infinity", "negative": true })
+        );
+        assert_eq!(
+            json[1]["members"][0]["default"],
+            json!({ "type": "sequence", "value": [] })
+        );
+        assert_eq!(json[1]["members"][1]["default"], json!({ "type": "null" }));
+    }
+}
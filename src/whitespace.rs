@@ -5,7 +5,7 @@ pub(crate) fn sp(input: &str) -> IResult<&str, &str> {
         input,
         many0!(alt!(
             // ignores line comments
-            do_parse!(tag!("//") >> take_until!("\n") >> char!('\n') >> (()))
+            do_parse!(tag!("//") >> take_while!(|c| c != '\n') >> opt!(char!('\n')) >> (()))
             |
             // ignores whitespace
             map!(take_while1!(|c| c == '\t' || c == '\n' || c == '\r' || c == ' '), |_| ())
@@ -16,16 +16,41 @@ pub(crate) fn sp(input: &str) -> IResult<&str, &str> {
     )
 }
 
-/// ws! also ignores line & block comments
+/// Like `sp`, but stops at the end of the line, which is where the trivia
+/// after a token is split between it and the next token
+pub(crate) fn sp_trailing(input: &str) -> IResult<&str, &str> {
+    recognize!(
+        input,
+        do_parse!(
+            many0!(alt!(
+                // ignores whitespace on the same line
+                map!(take_while1!(|c| c == '\t' || c == '\r' || c == ' '), |_| ())
+                |
+                // ignores block comments, even over several lines
+                do_parse!(tag!("/*") >> take_until!("*/") >> tag!("*/") >> (()))
+            )) >>
+            // ends with the line, or with a comment up to its end
+            opt!(alt!(
+                do_parse!(tag!("//") >> take_while!(|c| c != '\n') >> opt!(char!('\n')) >> (()))
+                |
+                map!(char!('\n'), |_| ())
+            )) >>
+            (())
+        )
+    )
+}
+
+/// ws! also ignores line & block comments. It returns the span of what was
+/// parsed, which records the whitespace and comments around it.
 macro_rules! ws (
     ($i:expr, $($args:tt)*) => ({
         use $crate::whitespace::sp;
 
         do_parse!($i,
-            sp >>
-            s: $($args)* >>
-            sp >>
-            (s)
+            leading: sp >>
+            s: spanned!($($args)*) >>
+            trailing: sp >>
+            ((s.0, s.1.with_trivia(leading, trailing)))
         )
     });
 );
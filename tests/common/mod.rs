@@ -0,0 +1,16 @@
+use std::fs;
+
+/// The definitions under `tests/defs` checked as a whole by several tests
+pub const FIXTURES: &[&str] = &[
+    "dom",
+    "html",
+    "interface-constructor",
+    "mediacapture-streams",
+    "streams",
+    "webgpu",
+];
+
+/// Reads the definitions of the fixture `name`
+pub fn read_fixture(name: &str) -> String {
+    fs::read_to_string(format!("./tests/defs/{}.webidl", name)).unwrap()
+}
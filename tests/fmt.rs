@@ -0,0 +1,84 @@
+extern crate weedle;
+
+mod common;
+
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use common::{read_fixture, FIXTURES};
+use weedle::print::{format, Options};
+
+fn options() -> Options {
+    Options {
+        wrap_width: Some(80),
+        ..Options::default()
+    }
+}
+
+#[test]
+fn should_format_idempotently() {
+    for name in FIXTURES {
+        let content = read_fixture(name);
+        let formatted = format(&content, &options()).unwrap();
+
+        assert_eq!(
+            weedle::parse(&formatted).unwrap(),
+            weedle::parse(&content).unwrap()
+        );
+        assert_eq!(format(&formatted, &options()).unwrap(), formatted);
+    }
+}
+
+#[test]
+fn should_keep_every_comment() {
+    for name in FIXTURES {
+        let content = read_fixture(name);
+        let formatted = format(&content, &options()).unwrap();
+
+        let count = |text: &str| text.matches("//").count() + text.matches("/*").count();
+        assert_eq!(count(&formatted), count(&content), "{}", name);
+    }
+}
+
+fn run_fmt(args: &[&str], input: &str) -> (i32, String) {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_weedle"))
+        .arg("fmt")
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::null())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    let output = child.wait_with_output().unwrap();
+    (
+        output.status.code().unwrap(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn should_format_standard_input() {
+    let (code, output) = run_fmt(&["--tabs"], "interface Foo{attribute long bar; // Bar\n};");
+    assert_eq!(code, 0);
+    assert_eq!(
+        output,
+        "interface Foo {\n\tattribute long bar; // Bar\n};\n"
+    );
+}
+
+#[test]
+fn should_check_formatting() {
+    let formatted = "typedef long Foo;\n";
+    assert_eq!(run_fmt(&["--check"], formatted), (0, String::new()));
+    assert_eq!(
+        run_fmt(&["--check"], "typedef  long Foo;"),
+        (1, String::new())
+    );
+    assert_eq!(run_fmt(&["--check"], "typedef long;").0, 2);
+}
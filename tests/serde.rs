@@ -0,0 +1,102 @@
+#![cfg(feature = "serde")]
+
+mod common;
+
+use common::{read_fixture, FIXTURES};
+use serde_json::json;
+use weedle::Definitions;
+
+#[test]
+fn should_round_trip_through_json() {
+    for name in FIXTURES {
+        let content = read_fixture(name);
+        let parsed = weedle::parse(&content).unwrap();
+
+        let json = serde_json::to_string(&parsed).unwrap();
+        let deserialized: Definitions = serde_json::from_str(&json).unwrap();
+        assert_eq!(deserialized, parsed);
+    }
+}
+
+#[test]
+fn should_serialize_to_documented_shape() {
+    let parsed =
+        weedle::parse("[Exposed=Window] interface Foo : Bar { attribute long? x; };").unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&parsed).unwrap(),
+        json!([{
+            "Interface": {
+                "attributes": {
+                    "open_bracket": "[",
+                    "body": [{
+                        "Ident": {
+                            "lhs_identifier": "Exposed",
+                            "assign": "=",
+                            "rhs": { "Identifier": "Window" },
+                        },
+                    }],
+                    "close_bracket": "]",
+                },
+                "interface": "interface",
+                "identifier": "Foo",
+                "inheritance": { "colon": ":", "identifier": "Bar" },
+                "members": {
+                    "open_brace": "{",
+                    "body": [{
+                        "Attribute": {
+                            "attributes": null,
+                            "modifier": null,
+                            "readonly": null,
+                            "attribute": "attribute",
+                            "type_": {
+                                "attributes": null,
+                                "type_": {
+                                    "Single": {
+                                        "NonAny": {
+                                            "Integer": {
+                                                "type_": {
+                                                    "Long": { "unsigned": null, "long": "long" },
+                                                },
+                                                "q_mark": "?",
+                                            },
+                                        },
+                                    },
+                                },
+                            },
+                            "identifier": "x",
+                            "semi_colon": ";",
+                        },
+                    }],
+                    "close_brace": "}",
+                },
+                "semi_colon": ";",
+            },
+        }])
+    );
+}
+
+#[test]
+fn should_reject_wrong_tokens() {
+    let json = r#"{ "colon": ";", "identifier": "Bar" }"#;
+    let err = serde_json::from_str::<weedle::interface::Inheritance>(json).unwrap_err();
+
+    assert!(err.to_string().contains("expected `:`"), "{}", err);
+}
+
+#[test]
+fn should_keep_escapes() {
+    let parsed = weedle::parse("typedef long _interface;").unwrap();
+
+    let json = serde_json::to_string(&parsed).unwrap();
+    assert!(json.contains(r#""identifier":"_interface""#), "{}", json);
+
+    let deserialized: Definitions = serde_json::from_str(&json).unwrap();
+    match &deserialized[0] {
+        weedle::Definition::Typedef(it) => {
+            assert_eq!(it.identifier.0, "interface");
+            assert!(it.identifier.is_escaped());
+        }
+        _ => unreachable!(),
+    }
+}
@@ -1,8 +1,11 @@
 extern crate weedle;
 
+mod common;
+
 use std::fs;
 use std::io::Read;
 
+use common::{read_fixture, FIXTURES};
 use weedle::*;
 
 fn read_file(path: &str) -> String {
@@ -113,3 +116,27 @@ fn interface_constructor() {
         _ => unreachable!(),
     }
 }
+
+#[test]
+fn should_round_trip_losslessly() {
+    for name in FIXTURES {
+        let content = read_fixture(name);
+        let tree = weedle::lossless::SyntaxTree::parse(&content).unwrap();
+
+        assert_eq!(tree.to_string(), content);
+    }
+}
+
+#[test]
+fn should_reparse_printed_definitions() {
+    use weedle::print::ToWebIdl;
+
+    for name in FIXTURES {
+        let content = read_fixture(name);
+        let parsed = weedle::parse(&content).unwrap();
+        let printed = parsed.to_webidl();
+
+        assert_eq!(weedle::parse(&printed).unwrap(), parsed);
+        assert_eq!(weedle::parse(&printed).unwrap().to_webidl(), printed);
+    }
+}